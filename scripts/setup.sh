@@ -56,7 +56,9 @@ else
     sleep 3
     
     # Run migrations
-    surreal sql --conn http://localhost:8000 --user root --pass root --ns book_rec --db main migrations/001_initial_schema.surql
+    for migration in migrations/*.surql; do
+        surreal sql --conn http://localhost:8000 --user root --pass root --ns book_rec --db main "$migration"
+    done
     
     echo "🎉 Database setup complete!"
 fi
@@ -0,0 +1,49 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+use chrono::{DateTime, Utc};
+
+use crate::application::dtos::book_dtos::BookResponse;
+use crate::domain::{
+    entities::{book::Book, user::{User, UserProfile}},
+    services::rating_service::RatingPrior,
+};
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DeletedBookResponse {
+    #[serde(flatten)]
+    pub book: BookResponse,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl DeletedBookResponse {
+    pub fn new(book: Book, rating_prior: &RatingPrior) -> Self {
+        Self {
+            deleted_at: book.deleted_at,
+            book: BookResponse::new(book, rating_prior),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DeletedUserResponse {
+    #[serde(flatten)]
+    pub user: UserProfile,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl From<User> for DeletedUserResponse {
+    fn from(user: User) -> Self {
+        Self {
+            user: user.to_profile(),
+            deleted_at: user.deleted_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PurgeResponse {
+    pub books_purged: usize,
+    pub users_purged: usize,
+    /// Records deleted before this time were purged
+    pub deleted_before: DateTime<Utc>,
+}
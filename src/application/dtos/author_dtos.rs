@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::application::dtos::book_dtos::BookResponse;
+use crate::domain::entities::author::{Author, ContributorRole};
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AuthorSummary {
+    pub id: String,
+    pub name: String,
+    pub aliases: Vec<String>,
+    pub bio: Option<String>,
+    pub photo_url: Option<String>,
+}
+
+impl From<Author> for AuthorSummary {
+    fn from(author: Author) -> Self {
+        Self {
+            id: author.id.to_string(),
+            name: author.name,
+            aliases: author.aliases,
+            bio: author.bio,
+            photo_url: author.photo_url,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BibliographyEntry {
+    pub role: ContributorRole,
+    pub book: BookResponse,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AuthorResponse {
+    pub id: String,
+    pub name: String,
+    pub aliases: Vec<String>,
+    pub bio: Option<String>,
+    pub photo_url: Option<String>,
+    pub average_rating: f64, // Weighted by ratings count across written books
+    pub ratings_count: i32,
+    pub bibliography: Vec<BibliographyEntry>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateAuthorRequest {
+    #[validate(length(min = 1, max = 200))]
+    pub name: Option<String>,
+    
+    #[validate(length(max = 20))]
+    pub aliases: Option<Vec<String>>,
+    
+    #[validate(length(max = 5000))]
+    pub bio: Option<String>,
+    
+    #[validate(url)]
+    pub photo_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AddContributorRequest {
+    pub author_id: Uuid,
+    pub role: ContributorRole,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ContributorResponse {
+    pub author_id: String,
+    pub name: String,
+    pub role: ContributorRole,
+    pub position: i32,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AuthorBackfillResponse {
+    pub books_scanned: usize,
+    pub books_linked: usize,
+}
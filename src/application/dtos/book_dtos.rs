@@ -1,13 +1,30 @@
 use serde::{Deserialize, Serialize};
-use validator::Validate;
+use validator::{Validate, ValidationError};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::domain::services::{
+    book_service::{BookService, MIN_PUBLICATION_YEAR},
+    localization_service::LocalizationService,
+    rating_service::RatingPrior,
+};
+use crate::domain::entities::{
+    book::{Book, BookRating, CoverImage, CoverPlaceholder, ReviewSort},
+    recommendation::ReadingProgress,
+    review::ReviewStatus,
+    work::EditionFormat,
+    revision::{BookRevision, FieldChange},
+};
+
 #[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct CreateBookRequest {
     #[validate(length(min = 1, max = 500))]
     pub title: String,
     
+    #[serde(default)]
+    #[validate(length(max = 500))]
+    pub subtitle: Option<String>,
+    
     #[validate(length(min = 1, max = 200))]
     pub author: String,
     
@@ -21,7 +38,7 @@ pub struct CreateBookRequest {
     
     pub tags: Vec<String>,
     
-    #[validate(range(min = 1000, max = 2024))]
+    #[validate(custom(function = "validate_publication_year"))]
     pub publication_year: i32,
     
     #[validate(length(min = 1, max = 200))]
@@ -34,10 +51,22 @@ pub struct CreateBookRequest {
     pub page_count: i32,
 }
 
+/// The latest accepted year moves with the calendar, so it can't be a `range` bound
+fn validate_publication_year(year: i32) -> Result<(), ValidationError> {
+    if year < MIN_PUBLICATION_YEAR || year > BookService::latest_publication_year() {
+        return Err(ValidationError::new("range"));
+    }
+    Ok(())
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 pub struct BookResponse {
     pub id: String,
     pub title: String,
+    pub subtitle: Option<String>,
+    /// Locale of the translation served, `None` for the original metadata
+    pub locale: Option<String>,
+    pub available_locales: Vec<String>,
     pub author: String,
     pub isbn: Option<String>,
     pub description: String,
@@ -47,12 +76,142 @@ pub struct BookResponse {
     pub publisher: String,
     pub language: String,
     pub page_count: i32,
-    pub cover_image_url: Option<String>,
+    pub cover_images: CoverImageSet,
+    pub work_id: Option<String>,
+    pub format: EditionFormat,
     pub average_rating: f64,
     pub ratings_count: i32,
+    /// Number of 1★ to 5★ ratings
+    pub rating_histogram: Vec<i32>,
+    /// Average pulled towards the configured prior while a book has few ratings; use it for ranking
+    pub weighted_rating: f64,
+    /// The caller's progress through the book, when signed in and they have reading sessions
+    pub reading_progress: Option<ReadingProgress>,
     pub created_at: String,
 }
 
+impl BookResponse {
+    /// `weighted_rating` is pulled towards `rating_prior`
+    pub fn new(book: Book, rating_prior: &RatingPrior) -> Self {
+        let cover_images = CoverImageSet::from(&book);
+        let weighted_rating = rating_prior.weighted_rating(book.average_rating, book.ratings_count);
+        let mut rating_histogram = book.rating_histogram;
+        rating_histogram.resize(5, 0);
+        Self {
+            id: book.id.to_string(),
+            title: book.title,
+            subtitle: book.subtitle,
+            locale: None,
+            available_locales: book.translations.into_iter().map(|t| t.locale).collect(),
+            author: book.author,
+            isbn: book.isbn,
+            description: book.description,
+            genre: book.genre,
+            tags: book.tags,
+            publication_year: book.publication_year,
+            publisher: book.publisher,
+            language: book.language,
+            page_count: book.page_count,
+            cover_images,
+            work_id: book.work_id.map(|id| id.to_string()),
+            format: book.format,
+            average_rating: book.average_rating,
+            ratings_count: book.ratings_count,
+            rating_histogram,
+            weighted_rating,
+            reading_progress: None,
+            created_at: book.created_at.to_rfc3339(),
+        }
+    }
+
+    /// Serve the translation that best matches the reader's preferred locales
+    pub fn localized(mut book: Book, preferred_locales: &[String], rating_prior: &RatingPrior) -> Self {
+        let locale = LocalizationService::localize(&mut book, preferred_locales);
+        Self {
+            locale,
+            ..Self::new(book, rating_prior)
+        }
+    }
+}
+
+/// Every stored rendition of a book's cover
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CoverImageSet {
+    /// JPEG renditions and any smaller WebP ones, smallest first
+    pub variants: Vec<CoverImage>,
+    /// Cover supplied by a publisher feed or uploaded before processing existed
+    pub original_url: Option<String>,
+    /// BlurHash and average color to show while the cover loads
+    pub placeholder: Option<CoverPlaceholder>,
+}
+
+impl From<&Book> for CoverImageSet {
+    fn from(book: &Book) -> Self {
+        let mut variants = book.cover_images.clone();
+        variants.sort_by_key(|image| image.width);
+
+        Self {
+            variants,
+            original_url: book.cover_image_url.clone(),
+            placeholder: book.cover_placeholder.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CoverUploadResponse {
+    pub cover_images: CoverImageSet,
+    /// Other books whose cover looks the same, a hint the upload may be the wrong cover
+    pub similar_covers: Vec<SimilarCoverResponse>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SimilarCoverResponse {
+    pub book: DuplicateBookSummary,
+    /// Differing bits between the 64-bit cover hashes, 0 means identical
+    pub distance: u32,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CoverDuplicatesQuery {
+    pub max_distance: Option<u32>,
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CoverDuplicateResponse {
+    pub book: DuplicateBookSummary,
+    pub other: DuplicateBookSummary,
+    pub distance: u32,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CoverPlaceholderBackfillResponse {
+    pub books_scanned: usize,
+    pub placeholders_added: usize,
+    pub failures: usize,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RatingStatsRecomputeResponse {
+    pub books_scanned: usize,
+    pub books_repaired: usize, // Books whose stored stats had drifted from their ratings
+    pub works_recomputed: usize,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct BookTranslationRequest {
+    #[validate(length(min = 1, max = 500))]
+    pub title: String,
+    
+    #[validate(length(max = 500))]
+    pub subtitle: Option<String>,
+    
+    #[validate(length(min = 10, max = 5000))]
+    pub description: Option<String>,
+}
+
 #[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct BookRatingRequest {
     #[validate(range(min = 1.0, max = 5.0))]
@@ -70,6 +229,55 @@ pub struct BookRatingResponse {
     pub rating: f64,
     pub review: Option<String>,
     pub created_at: String,
+    pub updated_at: Option<String>,
+    pub status: ReviewStatus,
+    pub helpful_count: i32,
+    pub not_helpful_count: i32,
+    /// Why the moderation filters held or rejected the review
+    pub moderation_reasons: Vec<String>,
+}
+
+impl From<BookRating> for BookRatingResponse {
+    fn from(rating: BookRating) -> Self {
+        Self {
+            id: rating.id.to_string(),
+            book_id: rating.book_id.to_string(),
+            user_id: rating.user_id.to_string(),
+            rating: rating.rating,
+            review: rating.review,
+            created_at: rating.created_at.to_rfc3339(),
+            updated_at: rating.updated_at.map(|at| at.to_rfc3339()),
+            status: rating.status,
+            helpful_count: rating.helpful_count,
+            not_helpful_count: rating.not_helpful_count,
+            moderation_reasons: rating.moderation_reasons,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ReviewQuery {
+    #[serde(default)]
+    pub sort: ReviewSort,
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SimilarBooksQuery {
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SimilarBookResponse {
+    pub book: BookResponse,
+    /// Overall similarity, 0.0 to 1.0
+    pub score: f64,
+    /// Genre, tag, author and description overlap
+    pub content_score: f64,
+    /// Agreement between readers who rated both books
+    pub co_rating_score: f64,
+    pub explanation: String,
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
@@ -79,4 +287,99 @@ pub struct BookSearchQuery {
     pub genre: Option<String>,
     pub limit: Option<usize>,
     pub offset: Option<usize>,
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OnixIngestResponse {
+    pub created: usize,
+    pub updated: usize,
+    pub deleted: usize,
+    pub skipped: usize,
+    pub errors: Vec<OnixIngestError>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OnixIngestError {
+    pub record_reference: String,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DuplicateScanResponse {
+    pub books_scanned: usize,
+    pub candidates_found: usize,
+    pub candidates_queued: usize,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DuplicateBookSummary {
+    pub id: String,
+    pub title: String,
+    pub author: String,
+    pub isbn: Option<String>,
+    pub publisher: String,
+    pub publication_year: i32,
+    pub ratings_count: i32,
+    pub created_at: String,
+}
+
+impl From<Book> for DuplicateBookSummary {
+    fn from(book: Book) -> Self {
+        Self {
+            id: book.id.to_string(),
+            title: book.title,
+            author: book.author,
+            isbn: book.isbn,
+            publisher: book.publisher,
+            publication_year: book.publication_year,
+            ratings_count: book.ratings_count,
+            created_at: book.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DuplicateCandidateResponse {
+    pub id: String,
+    pub book: DuplicateBookSummary,
+    pub duplicate: DuplicateBookSummary,
+    pub similarity: f64,
+    pub reasons: Vec<String>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct MergeBooksRequest {
+    pub surviving_book_id: Uuid,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MergeBooksResponse {
+    pub surviving_book_id: String,
+    pub merged_book_id: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct PaginationQuery {
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BookRevisionResponse {
+    pub revision_number: i32,
+    pub changed_by: Option<String>,
+    pub changed_at: chrono::DateTime<chrono::Utc>,
+    pub changes: Vec<FieldChange>,
+}
+
+impl From<BookRevision> for BookRevisionResponse {
+    fn from(revision: BookRevision) -> Self {
+        Self {
+            revision_number: revision.revision_number,
+            changed_by: revision.changed_by.map(|id| id.to_string()),
+            changed_at: revision.changed_at,
+            changes: revision.changes,
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use utoipa::ToSchema;
+
+use crate::domain::entities::challenge::{Challenge, ChallengeProgress, ChallengeRule};
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateChallengeRequest {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    #[validate(length(max = 2000))]
+    #[serde(default)]
+    pub description: String,
+    /// Every rule must be met to complete the challenge
+    #[validate(length(min = 1, max = 10))]
+    pub rules: Vec<ChallengeRule>,
+    pub starts_at: chrono::DateTime<chrono::Utc>,
+    pub ends_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ChallengeQuery {
+    /// Only challenges running now
+    #[serde(default)]
+    pub active: bool,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct LeaderboardQuery {
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ChallengeResponse {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub rules: Vec<ChallengeRule>,
+    pub starts_at: chrono::DateTime<chrono::Utc>,
+    pub ends_at: chrono::DateTime<chrono::Utc>,
+    pub active: bool,
+    pub participants_count: usize,
+    pub joined: bool,
+    /// The requesting user's progress, once they have joined
+    pub my_progress: Option<ChallengeProgress>,
+}
+
+impl ChallengeResponse {
+    pub fn new(
+        challenge: Challenge,
+        participants_count: usize,
+        my_progress: Option<ChallengeProgress>,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        Self {
+            id: challenge.id.to_string(),
+            active: challenge.is_active(now),
+            name: challenge.name,
+            description: challenge.description,
+            rules: challenge.rules,
+            starts_at: challenge.starts_at,
+            ends_at: challenge.ends_at,
+            participants_count,
+            joined: my_progress.is_some(),
+            my_progress,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LeaderboardEntry {
+    pub rank: usize,
+    pub user_id: String,
+    pub username: String,
+    pub progress: f64,
+    pub books_counted: i32,
+    pub completed: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LeaderboardResponse {
+    pub challenge_id: String,
+    pub participants_count: usize,
+    pub entries: Vec<LeaderboardEntry>,
+}
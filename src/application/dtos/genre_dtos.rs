@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::entities::genre::Genre;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateGenreRequest {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    
+    /// Defaults to a slug derived from `name`
+    #[validate(length(min = 1, max = 100))]
+    pub slug: Option<String>,
+    
+    pub parent_id: Option<Uuid>,
+    
+    #[serde(default)]
+    #[validate(length(max = 50))]
+    pub synonyms: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateGenreRequest {
+    #[validate(length(min = 1, max = 100))]
+    pub name: Option<String>,
+    
+    #[validate(length(min = 1, max = 100))]
+    pub slug: Option<String>,
+    
+    pub parent_id: Option<Uuid>,
+    
+    /// Move the genre to the top level
+    #[serde(default)]
+    pub clear_parent: bool,
+    
+    #[validate(length(max = 50))]
+    pub synonyms: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GenreResponse {
+    pub id: String,
+    pub name: String,
+    pub slug: String,
+    pub parent_id: Option<String>,
+    pub synonyms: Vec<String>,
+}
+
+impl From<Genre> for GenreResponse {
+    fn from(genre: Genre) -> Self {
+        Self {
+            id: genre.id.to_string(),
+            name: genre.name,
+            slug: genre.slug,
+            parent_id: genre.parent_id.map(|id| id.to_string()),
+            synonyms: genre.synonyms,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GenreNode {
+    pub id: String,
+    pub name: String,
+    pub slug: String,
+    pub synonyms: Vec<String>,
+    #[schema(no_recursion)]
+    pub children: Vec<GenreNode>,
+}
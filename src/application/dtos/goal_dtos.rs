@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use utoipa::ToSchema;
+
+use crate::domain::entities::goal::{GoalMetric, GoalPeriod, GoalProgress, ReadingGoal};
+
+/// Set the target for a period; replaces the goal already set for the same
+/// metric and period, if any
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpsertGoalRequest {
+    pub metric: GoalMetric,
+    pub period: GoalPeriod,
+    #[validate(range(min = 1900, max = 2100))]
+    pub year: i32,
+    /// 1 to 12, required for monthly goals
+    #[validate(range(min = 1, max = 12))]
+    pub month: Option<u32>,
+    #[validate(range(min = 1, max = 100000))]
+    pub target: i32,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct GoalQuery {
+    pub year: Option<i32>,
+    /// IANA time zone the goal periods follow, e.g. "Europe/Berlin"; defaults to UTC
+    pub tz: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GoalResponse {
+    pub id: String,
+    pub metric: GoalMetric,
+    pub period: GoalPeriod,
+    pub year: i32,
+    pub month: Option<u32>,
+    pub target: i32,
+    pub progress: GoalProgress,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl GoalResponse {
+    pub fn new(goal: ReadingGoal, progress: GoalProgress) -> Self {
+        Self {
+            id: goal.id.to_string(),
+            metric: goal.metric,
+            period: goal.period,
+            year: goal.year,
+            month: goal.month,
+            target: goal.target,
+            progress,
+            created_at: goal.created_at,
+            updated_at: goal.updated_at,
+        }
+    }
+}
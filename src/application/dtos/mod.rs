@@ -6,7 +6,31 @@
 pub mod auth_dtos;
 pub mod book_dtos;
 pub mod recommendation_dtos;
+pub mod work_dtos;
+pub mod author_dtos;
+pub mod genre_dtos;
+pub mod tag_dtos;
+pub mod admin_dtos;
+pub mod review_dtos;
+pub mod notification_dtos;
+pub mod trending_dtos;
+pub mod reading_dtos;
+pub mod shelf_dtos;
+pub mod goal_dtos;
+pub mod challenge_dtos;
 
 pub use auth_dtos::*;
 pub use book_dtos::*;
-pub use recommendation_dtos::*;
\ No newline at end of file
+pub use recommendation_dtos::*;
+pub use work_dtos::*;
+pub use author_dtos::*;
+pub use genre_dtos::*;
+pub use tag_dtos::*;
+pub use admin_dtos::*;
+pub use review_dtos::*;
+pub use notification_dtos::*;
+pub use trending_dtos::*;
+pub use reading_dtos::*;
+pub use shelf_dtos::*;
+pub use goal_dtos::*;
+pub use challenge_dtos::*;
\ No newline at end of file
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::entities::notification::{Notification, NotificationKind};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct NotificationQuery {
+    #[serde(default)]
+    pub unread_only: bool,
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NotificationResponse {
+    pub id: String,
+    pub kind: NotificationKind,
+    pub message: String,
+    pub book_id: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub read_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl From<Notification> for NotificationResponse {
+    fn from(notification: Notification) -> Self {
+        Self {
+            id: notification.id.to_string(),
+            kind: notification.kind,
+            message: notification.message,
+            book_id: notification.book_id.map(|id| id.to_string()),
+            created_at: notification.created_at,
+            read_at: notification.read_at,
+        }
+    }
+}
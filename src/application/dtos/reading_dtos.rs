@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::entities::recommendation::{ReadingProgress, ReadingSession};
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct StartReadingSessionRequest {
+    pub book_id: Uuid,
+
+    /// Defaults to where the caller's last session on the book ended
+    #[validate(range(min = 0))]
+    pub start_page: Option<i32>,
+}
+
+/// Either the page reached or the number of pages read
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct EndReadingSessionRequest {
+    #[validate(range(min = 0))]
+    pub end_page: Option<i32>,
+
+    #[validate(range(min = 0))]
+    pub pages_read: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ReadingSessionQuery {
+    pub book_id: Option<Uuid>,
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ReadingStatsQuery {
+    /// IANA time zone name, e.g. "Europe/Berlin"; defaults to UTC
+    pub tz: Option<String>,
+    /// Year of the year in review; defaults to the current year in `tz`
+    pub year: Option<i32>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReadingSessionResponse {
+    pub id: String,
+    pub book_id: String,
+    pub start_time: chrono::DateTime<chrono::Utc>,
+    pub end_time: Option<chrono::DateTime<chrono::Utc>>,
+    pub start_page: i32,
+    pub end_page: Option<i32>,
+    pub pages_read: i32,
+    pub duration_minutes: Option<i32>,
+    /// Progress through the book after this session; only on start and end
+    pub progress: Option<ReadingProgress>,
+}
+
+impl From<ReadingSession> for ReadingSessionResponse {
+    fn from(session: ReadingSession) -> Self {
+        Self {
+            id: session.id.to_string(),
+            book_id: session.book_id.to_string(),
+            start_time: session.start_time,
+            end_time: session.end_time,
+            start_page: session.start_page,
+            end_page: session.end_page,
+            pages_read: session.pages_read,
+            duration_minutes: session.session_duration_minutes,
+            progress: None,
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use utoipa::ToSchema;
+
+use crate::domain::entities::review::{ReviewReport, ReportReason, ReportStatus};
+use crate::application::dtos::book_dtos::BookRatingResponse;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct VoteReviewRequest {
+    /// `true` for helpful, `false` for not helpful, `null` to withdraw a vote
+    pub helpful: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ReportReviewRequest {
+    pub reason: ReportReason,
+    
+    #[validate(length(max = 500))]
+    pub details: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct RejectReviewRequest {
+    /// Shown to the author in their notification
+    #[validate(length(max = 500))]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReviewReportResponse {
+    pub id: String,
+    pub rating_id: String,
+    pub reported_by: String,
+    pub reason: ReportReason,
+    pub details: Option<String>,
+    pub status: ReportStatus,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<ReviewReport> for ReviewReportResponse {
+    fn from(report: ReviewReport) -> Self {
+        Self {
+            id: report.id.to_string(),
+            rating_id: report.rating_id.to_string(),
+            reported_by: report.reported_by.to_string(),
+            reason: report.reason,
+            details: report.details,
+            status: report.status,
+            created_at: report.created_at,
+        }
+    }
+}
+
+/// A review in the moderation queue with the reports against it
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReportedReviewResponse {
+    pub review: BookRatingResponse,
+    pub reports: Vec<ReviewReportResponse>,
+}
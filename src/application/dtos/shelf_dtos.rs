@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::application::dtos::book_dtos::BookResponse;
+use crate::domain::entities::shelf::{Shelf, ShelfEntry, ShelfStatus};
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateShelfRequest {
+    #[validate(length(min = 1, max = 50))]
+    pub name: String,
+}
+
+/// Put a book on a standard shelf and, optionally, set its custom shelves
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ShelveBookRequest {
+    pub status: ShelfStatus,
+    /// Replaces the book's custom shelves; left as they are when omitted
+    pub shelf_ids: Option<Vec<Uuid>>,
+    pub started_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub finished_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ShelfQuery {
+    pub status: Option<ShelfStatus>,
+    pub shelf_id: Option<Uuid>,
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ShelfExportFormat {
+    #[default]
+    Csv,
+    Json,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ShelfExportQuery {
+    #[serde(default)]
+    pub format: ShelfExportFormat,
+}
+
+/// A standard shelf (`status` set, no `id`) or a custom one
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ShelfResponse {
+    pub id: Option<String>,
+    pub name: String,
+    pub status: Option<ShelfStatus>,
+    pub book_count: usize,
+}
+
+impl ShelfResponse {
+    pub fn custom(shelf: Shelf, book_count: usize) -> Self {
+        Self {
+            id: Some(shelf.id.to_string()),
+            name: shelf.name,
+            status: None,
+            book_count,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ShelfEntryResponse {
+    pub book: BookResponse,
+    pub status: ShelfStatus,
+    pub shelf_ids: Vec<String>,
+    pub started_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub finished_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub added_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl ShelfEntryResponse {
+    pub fn new(entry: ShelfEntry, book: BookResponse) -> Self {
+        Self {
+            book,
+            status: entry.status,
+            shelf_ids: entry.shelf_ids.iter().map(|id| id.to_string()).collect(),
+            started_at: entry.started_at,
+            finished_at: entry.finished_at,
+            added_at: entry.added_at,
+            updated_at: entry.updated_at,
+        }
+    }
+}
+
+/// One shelved book in an export
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ShelfExportRow {
+    pub book_id: String,
+    pub title: String,
+    pub author: String,
+    pub isbn: Option<String>,
+    pub status: ShelfStatus,
+    pub shelves: Vec<String>, // Custom shelf names
+    pub my_rating: Option<f64>,
+    pub started_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub finished_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub added_at: chrono::DateTime<chrono::Utc>,
+}
@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use utoipa::ToSchema;
+
+use crate::domain::entities::tag::{BookTag, BlockedTag, TagStatus};
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct SuggestTagRequest {
+    #[validate(length(min = 1, max = 40))]
+    pub tag: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct VoteTagRequest {
+    /// 1 to upvote, -1 to downvote, 0 to withdraw a vote
+    #[validate(range(min = -1, max = 1))]
+    pub value: i8,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct RejectTagRequest {
+    /// Also add the tag to the blocklist
+    #[serde(default)]
+    pub block: bool,
+    
+    #[validate(length(max = 500))]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct BlockTagRequest {
+    #[validate(length(min = 1, max = 40))]
+    pub tag: String,
+    
+    #[validate(length(max = 500))]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BookTagResponse {
+    pub id: String,
+    pub book_id: String,
+    pub tag: String,
+    pub upvotes: i32,
+    pub downvotes: i32,
+    pub score: i32,
+    pub weight: f64, // 0.0 to 1.0, relative to the book's best ranked tag
+    pub status: TagStatus,
+}
+
+impl BookTagResponse {
+    pub fn new(tag: BookTag, weight: f64) -> Self {
+        Self {
+            id: tag.id.to_string(),
+            book_id: tag.book_id.to_string(),
+            score: tag.score(),
+            tag: tag.tag,
+            upvotes: tag.upvotes,
+            downvotes: tag.downvotes,
+            weight,
+            status: tag.status,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BlockedTagResponse {
+    pub tag: String,
+    pub reason: Option<String>,
+    pub created_by: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<BlockedTag> for BlockedTagResponse {
+    fn from(blocked: BlockedTag) -> Self {
+        Self {
+            tag: blocked.tag,
+            reason: blocked.reason,
+            created_by: blocked.created_by.to_string(),
+            created_at: blocked.created_at,
+        }
+    }
+}
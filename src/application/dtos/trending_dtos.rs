@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::dtos::book_dtos::BookResponse;
+use crate::domain::entities::trending::TrendingWindow;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct TrendingQuery {
+    #[serde(default)]
+    pub window: TrendingWindow,
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TrendingBookResponse {
+    pub rank: i32,
+    pub book: BookResponse,
+    pub score: f64,
+    /// Ratings counted towards the score: within the window for trending,
+    /// all ratings so far for new and notable
+    pub recent_ratings: i32,
+    pub recent_clicks: i32,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TrendingResponse {
+    pub window: TrendingWindow,
+    /// When the lists were last refreshed; `None` until the first refresh
+    pub computed_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub trending: Vec<TrendingBookResponse>,
+    pub new_and_notable: Vec<TrendingBookResponse>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TrendingRefreshResponse {
+    pub lists_refreshed: usize,
+    pub books_ranked: usize,
+}
@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::application::dtos::book_dtos::BookResponse;
+use crate::domain::entities::work::{Work, Series, EditionFormat};
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateWorkRequest {
+    #[validate(length(min = 1, max = 500))]
+    pub title: String,
+    
+    #[validate(length(min = 1, max = 200))]
+    pub author: String,
+    
+    #[validate(length(min = 10, max = 5000))]
+    pub description: String,
+    
+    #[validate(length(min = 1))]
+    pub genre: Vec<String>,
+    
+    #[validate(length(min = 1, max = 50))]
+    pub original_language: String,
+    
+    #[validate(range(min = 1000, max = 2100))]
+    pub original_publication_year: i32,
+    
+    pub series_id: Option<Uuid>,
+    
+    pub series_position: Option<f64>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct AddEditionRequest {
+    pub book_id: Uuid,
+    
+    pub format: EditionFormat,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateSeriesRequest {
+    #[validate(length(min = 1, max = 500))]
+    pub title: String,
+    
+    #[validate(length(max = 5000))]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WorkSummary {
+    pub id: String,
+    pub title: String,
+    pub author: String,
+    pub series_position: Option<f64>,
+    pub average_rating: f64,
+    pub ratings_count: i32,
+}
+
+impl From<Work> for WorkSummary {
+    fn from(work: Work) -> Self {
+        Self {
+            id: work.id.to_string(),
+            title: work.title,
+            author: work.author,
+            series_position: work.series_position,
+            average_rating: work.average_rating,
+            ratings_count: work.ratings_count,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WorkResponse {
+    pub id: String,
+    pub title: String,
+    pub author: String,
+    pub description: String,
+    pub genre: Vec<String>,
+    pub original_language: String,
+    pub original_publication_year: i32,
+    pub series_id: Option<String>,
+    pub series_position: Option<f64>,
+    pub average_rating: f64, // Aggregated across all editions
+    pub ratings_count: i32,
+    pub editions: Vec<BookResponse>,
+    pub next_in_series: Option<WorkSummary>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SeriesResponse {
+    pub id: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub works: Vec<WorkSummary>, // In reading order
+    pub created_at: String,
+}
+
+impl SeriesResponse {
+    pub fn new(series: Series, works: Vec<Work>) -> Self {
+        Self {
+            id: series.id.to_string(),
+            title: series.title,
+            description: series.description,
+            works: works.into_iter().map(WorkSummary::from).collect(),
+            created_at: series.created_at.to_rfc3339(),
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use anyhow::Result;
+
+/// Port for storing processed images (book covers) and serving them by URL
+#[async_trait]
+pub trait ImageStorage: Send + Sync {
+    /// Store `data` under `key` (e.g. "book-covers/{book_id}/{upload_id}/card.webp")
+    async fn store(&self, key: &str, data: Vec<u8>, content_type: &str) -> Result<StoredImage>;
+    /// Remove a stored image; deleting a missing image is not an error
+    async fn delete(&self, key: &str) -> Result<()>;
+}
+
+#[derive(Debug, Clone)]
+pub struct StoredImage {
+    pub key: String, // Backend identifier to pass to `delete`, may differ from the requested key
+    pub url: String,
+}
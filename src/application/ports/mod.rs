@@ -5,8 +5,10 @@
 
 pub mod auth_port;
 pub mod book_port;
+pub mod image_storage_port;
 pub mod recommendation_port;
 
 pub use auth_port::*;
 pub use book_port::*;
+pub use image_storage_port::*;
 pub use recommendation_port::*;
\ No newline at end of file
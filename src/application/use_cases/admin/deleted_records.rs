@@ -0,0 +1,120 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::user::User,
+    repositories::{book_repository::BookRepository, user_repository::UserRepository},
+    services::rating_service::RatingPrior,
+    errors::domain_error::DomainError,
+};
+use crate::application::dtos::admin_dtos::{DeletedBookResponse, DeletedUserResponse};
+use crate::application::dtos::book_dtos::BookResponse;
+
+/// Soft delete, list and restore books and users
+pub struct ManageDeletedRecordsUseCase<UR: UserRepository, BR: BookRepository> {
+    user_repository: UR,
+    book_repository: BR,
+    rating_prior: RatingPrior,
+}
+
+impl<UR: UserRepository, BR: BookRepository> ManageDeletedRecordsUseCase<UR, BR> {
+    pub fn new(user_repository: UR, book_repository: BR, rating_prior: RatingPrior) -> Self {
+        Self {
+            user_repository,
+            book_repository,
+            rating_prior,
+        }
+    }
+
+    /// Curators can delete books; they stay restorable until purged
+    pub async fn delete_book(&self, curator_id: Uuid, book_id: Uuid) -> Result<()> {
+        let curator = self.require_user(curator_id).await?;
+        if !curator.is_curator() {
+            return Err(DomainError::Unauthorized.into());
+        }
+
+        self.book_repository
+            .find_by_id(book_id)
+            .await?
+            .ok_or(DomainError::BookNotFound)?;
+
+        self.book_repository.delete(book_id).await
+    }
+
+    pub async fn delete_user(&self, admin_id: Uuid, user_id: Uuid) -> Result<()> {
+        self.require_admin(admin_id).await?;
+
+        if admin_id == user_id {
+            return Err(DomainError::InvalidInput("Admins cannot delete their own account".to_string()).into());
+        }
+
+        self.require_user(user_id).await?;
+        self.user_repository.delete(user_id).await
+    }
+
+    pub async fn list_deleted_books(
+        &self,
+        admin_id: Uuid,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<DeletedBookResponse>> {
+        self.require_admin(admin_id).await?;
+
+        let books = self.book_repository.find_deleted(limit, offset).await?;
+        Ok(books
+            .into_iter()
+            .map(|book| DeletedBookResponse::new(book, &self.rating_prior))
+            .collect())
+    }
+
+    pub async fn restore_book(&self, admin_id: Uuid, book_id: Uuid) -> Result<BookResponse> {
+        self.require_admin(admin_id).await?;
+
+        let book = self.book_repository
+            .restore(book_id)
+            .await?
+            .ok_or(DomainError::BookNotFound)?;
+
+        Ok(BookResponse::new(book, &self.rating_prior))
+    }
+
+    pub async fn list_deleted_users(
+        &self,
+        admin_id: Uuid,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<DeletedUserResponse>> {
+        self.require_admin(admin_id).await?;
+
+        let users = self.user_repository.find_deleted(limit, offset).await?;
+        Ok(users.into_iter().map(DeletedUserResponse::from).collect())
+    }
+
+    pub async fn restore_user(&self, admin_id: Uuid, user_id: Uuid) -> Result<DeletedUserResponse> {
+        self.require_admin(admin_id).await?;
+
+        let user = self.user_repository
+            .restore(user_id)
+            .await?
+            .ok_or(DomainError::UserNotFound)?;
+
+        Ok(DeletedUserResponse::from(user))
+    }
+
+    async fn require_user(&self, user_id: Uuid) -> Result<User> {
+        Ok(self.user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(DomainError::UserNotFound)?)
+    }
+
+    async fn require_admin(&self, user_id: Uuid) -> Result<User> {
+        let user = self.require_user(user_id).await?;
+
+        if !user.is_admin() {
+            return Err(DomainError::Unauthorized.into());
+        }
+
+        Ok(user)
+    }
+}
@@ -0,0 +1,7 @@
+//! Admin maintenance use cases module
+
+pub mod deleted_records;
+pub mod purge_deleted_records;
+
+pub use deleted_records::*;
+pub use purge_deleted_records::*;
@@ -0,0 +1,56 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    repositories::{book_repository::BookRepository, user_repository::UserRepository},
+    errors::domain_error::DomainError,
+};
+use crate::application::dtos::admin_dtos::PurgeResponse;
+
+/// Default number of days a deleted record can still be restored
+pub const DEFAULT_RETENTION_DAYS: i64 = 30;
+
+/// Hard delete books and users whose retention window has passed
+pub struct PurgeDeletedRecordsUseCase<UR: UserRepository, BR: BookRepository> {
+    user_repository: UR,
+    book_repository: BR,
+    retention_days: i64,
+}
+
+impl<UR: UserRepository, BR: BookRepository> PurgeDeletedRecordsUseCase<UR, BR> {
+    pub fn new(user_repository: UR, book_repository: BR, retention_days: i64) -> Self {
+        Self {
+            user_repository,
+            book_repository,
+            retention_days,
+        }
+    }
+
+    /// Run by the background purge job
+    pub async fn execute(&self) -> Result<PurgeResponse> {
+        let deleted_before = chrono::Utc::now() - chrono::Duration::days(self.retention_days);
+
+        let books_purged = self.book_repository.purge_deleted(deleted_before).await?;
+        let users_purged = self.user_repository.purge_deleted(deleted_before).await?;
+
+        Ok(PurgeResponse {
+            books_purged,
+            users_purged,
+            deleted_before,
+        })
+    }
+
+    /// Run a purge on demand
+    pub async fn execute_as_admin(&self, admin_id: Uuid) -> Result<PurgeResponse> {
+        let admin = self.user_repository
+            .find_by_id(admin_id)
+            .await?
+            .ok_or(DomainError::UserNotFound)?;
+
+        if !admin.is_admin() {
+            return Err(DomainError::Unauthorized.into());
+        }
+
+        self.execute().await
+    }
+}
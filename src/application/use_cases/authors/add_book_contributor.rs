@@ -0,0 +1,79 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::author::BookContributor,
+    repositories::{
+        author_repository::AuthorRepository,
+        book_repository::BookRepository,
+        user_repository::UserRepository,
+    },
+    errors::domain_error::DomainError,
+};
+use crate::application::dtos::author_dtos::{AddContributorRequest, ContributorResponse};
+
+pub struct AddBookContributorUseCase<UR: UserRepository, BR: BookRepository, AR: AuthorRepository> {
+    user_repository: UR,
+    book_repository: BR,
+    author_repository: AR,
+}
+
+impl<UR: UserRepository, BR: BookRepository, AR: AuthorRepository> AddBookContributorUseCase<UR, BR, AR> {
+    pub fn new(user_repository: UR, book_repository: BR, author_repository: AR) -> Self {
+        Self {
+            user_repository,
+            book_repository,
+            author_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        book_id: Uuid,
+        user_id: Uuid,
+        request: AddContributorRequest,
+    ) -> Result<Vec<ContributorResponse>> {
+        let curator = self.user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(DomainError::UserNotFound)?;
+
+        if !curator.is_curator() {
+            return Err(DomainError::Unauthorized.into());
+        }
+
+        self.book_repository
+            .find_by_id(book_id)
+            .await?
+            .ok_or(DomainError::BookNotFound)?;
+
+        self.author_repository
+            .find_by_id(request.author_id)
+            .await?
+            .ok_or(DomainError::AuthorNotFound)?;
+
+        let existing = self.author_repository.get_contributors_for_book(book_id).await?;
+        if existing.iter().any(|c| c.author_id == request.author_id && c.role == request.role) {
+            return Err(DomainError::InvalidInput("Contributor already credited with this role".to_string()).into());
+        }
+
+        let position = existing.iter().map(|c| c.position + 1).max().unwrap_or(0);
+        self.author_repository
+            .save_contributor(BookContributor::new(book_id, request.author_id, request.role, position))
+            .await?;
+
+        let mut responses = Vec::new();
+        for contributor in self.author_repository.get_contributors_for_book(book_id).await? {
+            if let Some(author) = self.author_repository.find_by_id(contributor.author_id).await? {
+                responses.push(ContributorResponse {
+                    author_id: author.id.to_string(),
+                    name: author.name,
+                    role: contributor.role,
+                    position: contributor.position,
+                });
+            }
+        }
+
+        Ok(responses)
+    }
+}
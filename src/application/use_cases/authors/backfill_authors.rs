@@ -0,0 +1,86 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    repositories::{
+        author_repository::AuthorRepository,
+        book_repository::BookRepository,
+        user_repository::UserRepository,
+    },
+    services::author_service::AuthorService,
+    errors::domain_error::DomainError,
+};
+use crate::application::{
+    dtos::author_dtos::AuthorBackfillResponse,
+    use_cases::authors::link_contributors::link_book_contributors,
+};
+
+const BACKFILL_PAGE_SIZE: usize = 200;
+
+/// Backfill `Author`/`Publisher` records and contributor links from the
+/// plain `Book.author` and `Book.publisher` strings
+pub struct BackfillAuthorsUseCase<UR: UserRepository, BR: BookRepository, AR: AuthorRepository> {
+    user_repository: UR,
+    book_repository: BR,
+    author_repository: AR,
+    author_service: AuthorService,
+}
+
+impl<UR: UserRepository, BR: BookRepository, AR: AuthorRepository> BackfillAuthorsUseCase<UR, BR, AR> {
+    pub fn new(
+        user_repository: UR,
+        book_repository: BR,
+        author_repository: AR,
+        author_service: AuthorService,
+    ) -> Self {
+        Self {
+            user_repository,
+            book_repository,
+            author_repository,
+            author_service,
+        }
+    }
+
+    pub async fn execute(&self, admin_id: Uuid) -> Result<AuthorBackfillResponse> {
+        let admin = self.user_repository
+            .find_by_id(admin_id)
+            .await?
+            .ok_or(DomainError::UserNotFound)?;
+
+        if !admin.is_admin() {
+            return Err(DomainError::Unauthorized.into());
+        }
+
+        let mut books_scanned = 0;
+        let mut books_linked = 0;
+        let mut after_id = None;
+
+        // Paged by ID, which the updates below never change, so no book is
+        // skipped or visited twice
+        loop {
+            let page = self.book_repository
+                .find_page_by_id(after_id, BACKFILL_PAGE_SIZE)
+                .await?;
+            let page_len = page.len();
+            after_id = page.last().map(|book| book.id);
+
+            for mut book in page {
+                if link_book_contributors(&self.author_repository, &self.author_service, &mut book).await? {
+                    book.updated_by = Some(admin_id);
+                    self.book_repository.update(book).await?;
+                    books_linked += 1;
+                }
+            }
+
+            books_scanned += page_len;
+            if page_len < BACKFILL_PAGE_SIZE {
+                break;
+            }
+        }
+
+        Ok(AuthorBackfillResponse {
+            books_scanned,
+            books_linked,
+        })
+    }
+}
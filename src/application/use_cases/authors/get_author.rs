@@ -0,0 +1,78 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::author::ContributorRole,
+    repositories::{author_repository::AuthorRepository, book_repository::BookRepository},
+    services::{author_service::AuthorService, rating_service::RatingPrior},
+    errors::domain_error::DomainError,
+};
+use crate::application::dtos::{
+    author_dtos::{AuthorResponse, BibliographyEntry},
+    book_dtos::BookResponse,
+};
+
+pub struct GetAuthorUseCase<AR: AuthorRepository, BR: BookRepository> {
+    author_repository: AR,
+    book_repository: BR,
+    author_service: AuthorService,
+    rating_prior: RatingPrior,
+}
+
+impl<AR: AuthorRepository, BR: BookRepository> GetAuthorUseCase<AR, BR> {
+    pub fn new(
+        author_repository: AR,
+        book_repository: BR,
+        author_service: AuthorService,
+        rating_prior: RatingPrior,
+    ) -> Self {
+        Self {
+            author_repository,
+            book_repository,
+            author_service,
+            rating_prior,
+        }
+    }
+
+    /// Author page: profile, bibliography and aggregate rating of the books
+    /// they wrote (translations and illustrations are listed but not rated)
+    pub async fn execute(&self, author_id: Uuid) -> Result<AuthorResponse> {
+        let author = self.author_repository
+            .find_by_id(author_id)
+            .await?
+            .ok_or(DomainError::AuthorNotFound)?;
+
+        let contributions = self.author_repository
+            .get_contributions_for_author(author_id)
+            .await?;
+
+        let mut written_books = Vec::new();
+        let mut bibliography = Vec::new();
+
+        for contribution in contributions {
+            if let Some(book) = self.book_repository.find_by_id(contribution.book_id).await? {
+                if contribution.role == ContributorRole::Author {
+                    written_books.push(book.clone());
+                }
+                bibliography.push(BibliographyEntry {
+                    role: contribution.role,
+                    book: BookResponse::new(book, &self.rating_prior),
+                });
+            }
+        }
+
+        bibliography.sort_by_key(|entry| entry.book.publication_year);
+        let (average_rating, ratings_count) = self.author_service.aggregate_rating(&written_books);
+
+        Ok(AuthorResponse {
+            id: author.id.to_string(),
+            name: author.name,
+            aliases: author.aliases,
+            bio: author.bio,
+            photo_url: author.photo_url,
+            average_rating,
+            ratings_count,
+            bibliography,
+        })
+    }
+}
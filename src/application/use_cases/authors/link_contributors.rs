@@ -0,0 +1,135 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        author::{Author, Publisher, BookContributor, ContributorRole},
+        book::Book,
+    },
+    repositories::author_repository::AuthorRepository,
+    services::author_service::AuthorService,
+};
+
+/// Resolve a book's free-text author and publisher onto `Author` and
+/// `Publisher` records, creating records for names we have not seen yet.
+/// Books that already have contributors are left alone, so this is safe to
+/// run repeatedly. The book must already be saved. Returns whether the book
+/// was changed.
+pub async fn link_book_contributors<AR: AuthorRepository>(
+    author_repository: &AR,
+    author_service: &AuthorService,
+    book: &mut Book,
+) -> Result<bool> {
+    let publisher_linked = link_book_publisher(author_repository, author_service, book).await?;
+    let authors_linked = link_book_authors(author_repository, author_service, book).await?;
+    Ok(publisher_linked || authors_linked)
+}
+
+/// Point the book at the `Publisher` record for its publisher name. Only
+/// touches the book itself, so it can run before a new book is saved.
+pub async fn link_book_publisher<AR: AuthorRepository>(
+    author_repository: &AR,
+    author_service: &AuthorService,
+    book: &mut Book,
+) -> Result<bool> {
+    if book.publisher_id.is_some() {
+        return Ok(false);
+    }
+
+    let key = AuthorService::normalize_name(&book.publisher);
+    if key.is_empty() {
+        return Ok(false);
+    }
+
+    let publisher = match author_repository.find_publisher_by_name_key(&key).await? {
+        Some(publisher) => publisher,
+        None => {
+            let keys = author_service.name_keys(&book.publisher, &[]);
+            author_repository
+                .save_publisher(Publisher::new(book.publisher.clone(), keys))
+                .await?
+        }
+    };
+
+    book.publisher_id = Some(publisher.id);
+    Ok(true)
+}
+
+/// Link the people in a saved book's author credit as its authors, unless the
+/// book already has contributors. Returns whether any author was linked.
+pub async fn link_book_authors<AR: AuthorRepository>(
+    author_repository: &AR,
+    author_service: &AuthorService,
+    book: &Book,
+) -> Result<bool> {
+    if !author_repository.get_contributors_for_book(book.id).await?.is_empty() {
+        return Ok(false);
+    }
+
+    let mut changed = false;
+    for (position, name) in author_service.split_credit(&book.author).into_iter().enumerate() {
+        let Some(author) = find_or_create_author(author_repository, author_service, name).await? else {
+            continue;
+        };
+
+        author_repository
+            .save_contributor(BookContributor::new(book.id, author.id, ContributorRole::Author, position as i32))
+            .await?;
+        changed = true;
+    }
+
+    Ok(changed)
+}
+
+/// Link the contributors a feed credits in roles other than author, such as
+/// translators and narrators, to a saved book. Credits already linked in the
+/// same role are skipped, so this is safe to run on every feed update.
+/// Returns whether any contributor was linked.
+pub async fn link_credited_contributors<AR: AuthorRepository>(
+    author_repository: &AR,
+    author_service: &AuthorService,
+    book_id: Uuid,
+    credits: &[(String, ContributorRole)],
+) -> Result<bool> {
+    let mut linked = author_repository.get_contributors_for_book(book_id).await?;
+    let mut changed = false;
+
+    for (name, role) in credits.iter().filter(|(_, role)| *role != ContributorRole::Author) {
+        let Some(author) = find_or_create_author(author_repository, author_service, name.clone()).await? else {
+            continue;
+        };
+        if linked.iter().any(|c| c.author_id == author.id && c.role == *role) {
+            continue;
+        }
+
+        let position = linked.iter().filter(|c| c.role == *role).count() as i32;
+        let contributor = author_repository
+            .save_contributor(BookContributor::new(book_id, author.id, *role, position))
+            .await?;
+        linked.push(contributor);
+        changed = true;
+    }
+
+    Ok(changed)
+}
+
+/// The author record matching `name`, created if we have not seen the name
+/// yet. `None` for names that normalize to nothing.
+async fn find_or_create_author<AR: AuthorRepository>(
+    author_repository: &AR,
+    author_service: &AuthorService,
+    name: String,
+) -> Result<Option<Author>> {
+    let key = AuthorService::normalize_name(&name);
+    if key.is_empty() {
+        return Ok(None);
+    }
+
+    match author_repository.find_by_name_key(&key).await? {
+        Some(author) => Ok(Some(author)),
+        None => {
+            let keys = author_service.name_keys(&name, &[]);
+            Ok(Some(author_repository.save(Author::new(name, keys)).await?))
+        }
+    }
+}
@@ -0,0 +1,13 @@
+//! Author and publisher use cases module
+
+pub mod link_contributors;
+pub mod backfill_authors;
+pub mod get_author;
+pub mod update_author;
+pub mod add_book_contributor;
+
+pub use link_contributors::*;
+pub use backfill_authors::*;
+pub use get_author::*;
+pub use update_author::*;
+pub use add_book_contributor::*;
@@ -0,0 +1,79 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    repositories::{author_repository::AuthorRepository, user_repository::UserRepository},
+    services::author_service::AuthorService,
+    errors::domain_error::DomainError,
+};
+use crate::application::dtos::author_dtos::{UpdateAuthorRequest, AuthorSummary};
+
+pub struct UpdateAuthorUseCase<UR: UserRepository, AR: AuthorRepository> {
+    user_repository: UR,
+    author_repository: AR,
+    author_service: AuthorService,
+}
+
+impl<UR: UserRepository, AR: AuthorRepository> UpdateAuthorUseCase<UR, AR> {
+    pub fn new(user_repository: UR, author_repository: AR, author_service: AuthorService) -> Self {
+        Self {
+            user_repository,
+            author_repository,
+            author_service,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        author_id: Uuid,
+        user_id: Uuid,
+        request: UpdateAuthorRequest,
+    ) -> Result<AuthorSummary> {
+        let curator = self.user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(DomainError::UserNotFound)?;
+
+        if !curator.is_curator() {
+            return Err(DomainError::Unauthorized.into());
+        }
+
+        let mut author = self.author_repository
+            .find_by_id(author_id)
+            .await?
+            .ok_or(DomainError::AuthorNotFound)?;
+
+        if let Some(name) = request.name {
+            author.name = name;
+        }
+        if let Some(aliases) = request.aliases {
+            author.aliases = aliases;
+        }
+        if let Some(bio) = request.bio {
+            author.bio = Some(bio);
+        }
+        if let Some(photo_url) = request.photo_url {
+            author.photo_url = Some(photo_url);
+        }
+
+        // An alias must not point at a different author
+        let name_keys = self.author_service.name_keys(&author.name, &author.aliases);
+        for key in &name_keys {
+            if let Some(other) = self.author_repository.find_by_name_key(key).await? {
+                if other.id != author.id {
+                    return Err(DomainError::InvalidInput(format!(
+                        "Name or alias already belongs to author {}",
+                        other.id
+                    )).into());
+                }
+            }
+        }
+
+        author.name_keys = name_keys;
+        author.updated_at = chrono::Utc::now();
+
+        let updated_author = self.author_repository.update(author).await?;
+
+        Ok(AuthorSummary::from(updated_author))
+    }
+}
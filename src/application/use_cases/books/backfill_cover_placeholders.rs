@@ -0,0 +1,120 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::book::{Book, CoverFormat, CoverPlaceholder},
+    repositories::{book_repository::BookRepository, user_repository::UserRepository},
+    errors::domain_error::DomainError,
+};
+use crate::infrastructure::external::{image_fetcher::ImageFetcher, image_processor::ImageProcessor};
+use crate::application::dtos::book_dtos::CoverPlaceholderBackfillResponse;
+
+const BACKFILL_PAGE_SIZE: usize = 200;
+
+/// Compute placeholders and perceptual hashes for covers stored before
+/// either existed
+pub struct BackfillCoverPlaceholdersUseCase<UR: UserRepository, BR: BookRepository> {
+    user_repository: UR,
+    book_repository: BR,
+    image_processor: ImageProcessor,
+    image_fetcher: ImageFetcher,
+}
+
+impl<UR: UserRepository, BR: BookRepository> BackfillCoverPlaceholdersUseCase<UR, BR> {
+    pub fn new(
+        user_repository: UR,
+        book_repository: BR,
+        image_processor: ImageProcessor,
+        image_fetcher: ImageFetcher,
+    ) -> Self {
+        Self {
+            user_repository,
+            book_repository,
+            image_processor,
+            image_fetcher,
+        }
+    }
+
+    /// Run by the startup backfill job
+    pub async fn execute(&self) -> Result<CoverPlaceholderBackfillResponse> {
+        let mut response = CoverPlaceholderBackfillResponse {
+            books_scanned: 0,
+            placeholders_added: 0,
+            failures: 0,
+        };
+
+        let mut after_id = None;
+
+        // Paged by ID, which the updates below never change, so no book is
+        // skipped or visited twice; soft-deleted books are covered too, as
+        // they can still be restored
+        loop {
+            let page = self.book_repository
+                .find_page_by_id(after_id, BACKFILL_PAGE_SIZE)
+                .await?;
+            let page_len = page.len();
+            after_id = page.last().map(|book| book.id);
+
+            for mut book in page {
+                if book.cover_placeholder.is_some() && book.cover_hash.is_some() {
+                    continue;
+                }
+                let Some(url) = Self::placeholder_source(&book) else {
+                    continue;
+                };
+
+                // One unreachable cover must not stop the backfill
+                match self.analyze_cover(&url).await {
+                    Ok((placeholder, cover_hash)) => {
+                        book.cover_placeholder = Some(placeholder);
+                        book.cover_hash = Some(cover_hash);
+                        book.updated_by = None;
+                        self.book_repository.update(book).await?;
+                        response.placeholders_added += 1;
+                    }
+                    Err(e) => {
+                        tracing::warn!("Could not analyze the cover of book {}: {:?}", book.id, e);
+                        response.failures += 1;
+                    }
+                }
+            }
+
+            response.books_scanned += page_len;
+            if page_len < BACKFILL_PAGE_SIZE {
+                break;
+            }
+        }
+
+        Ok(response)
+    }
+
+    /// Run the backfill on demand
+    pub async fn execute_as_admin(&self, admin_id: Uuid) -> Result<CoverPlaceholderBackfillResponse> {
+        let admin = self.user_repository
+            .find_by_id(admin_id)
+            .await?
+            .ok_or(DomainError::UserNotFound)?;
+
+        if !admin.is_admin() {
+            return Err(DomainError::Unauthorized.into());
+        }
+
+        self.execute().await
+    }
+
+    /// Smallest JPEG rendition, falling back to the feed-supplied cover
+    fn placeholder_source(book: &Book) -> Option<String> {
+        book.cover_images
+            .iter()
+            .filter(|image| image.format == CoverFormat::Jpeg)
+            .min_by_key(|image| image.width)
+            .map(|image| image.url.clone())
+            .or_else(|| book.cover_image_url.clone())
+    }
+
+    async fn analyze_cover(&self, url: &str) -> Result<(CoverPlaceholder, String)> {
+        let data = self.image_fetcher.fetch(url).await?;
+        let processor = self.image_processor.clone();
+        tokio::task::spawn_blocking(move || processor.analyze(&data)).await?
+    }
+}
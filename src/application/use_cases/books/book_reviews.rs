@@ -0,0 +1,61 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::book::ReviewSort,
+    repositories::book_repository::BookRepository,
+    errors::domain_error::DomainError,
+};
+use crate::application::dtos::book_dtos::BookRatingResponse;
+
+pub struct GetBookReviewsUseCase<BR: BookRepository> {
+    book_repository: BR,
+}
+
+impl<BR: BookRepository> GetBookReviewsUseCase<BR> {
+    pub fn new(book_repository: BR) -> Self {
+        Self { book_repository }
+    }
+
+    /// Ratings of a book that come with a written review
+    pub async fn execute(
+        &self,
+        book_id: Uuid,
+        sort: ReviewSort,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<BookRatingResponse>> {
+        self.book_repository
+            .find_by_id(book_id)
+            .await?
+            .ok_or(DomainError::BookNotFound)?;
+
+        let reviews = self.book_repository
+            .get_reviews_for_book(book_id, sort, limit.map(|l| l.min(100)), offset)
+            .await?;
+        Ok(reviews.into_iter().map(BookRatingResponse::from).collect())
+    }
+}
+
+pub struct GetUserRatingsUseCase<BR: BookRepository> {
+    book_repository: BR,
+}
+
+impl<BR: BookRepository> GetUserRatingsUseCase<BR> {
+    pub fn new(book_repository: BR) -> Self {
+        Self { book_repository }
+    }
+
+    /// Everything the user has rated, most recent first
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<BookRatingResponse>> {
+        let ratings = self.book_repository
+            .get_user_ratings_page(user_id, limit.map(|l| l.min(100)), offset)
+            .await?;
+        Ok(ratings.into_iter().map(BookRatingResponse::from).collect())
+    }
+}
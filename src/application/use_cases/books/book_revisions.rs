@@ -0,0 +1,82 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    repositories::{book_repository::BookRepository, user_repository::UserRepository},
+    services::{book_history_service::BookHistoryService, rating_service::RatingPrior},
+    errors::domain_error::DomainError,
+};
+use crate::application::dtos::book_dtos::{BookResponse, BookRevisionResponse};
+
+pub struct GetBookRevisionsUseCase<BR: BookRepository> {
+    book_repository: BR,
+}
+
+impl<BR: BookRepository> GetBookRevisionsUseCase<BR> {
+    pub fn new(book_repository: BR) -> Self {
+        Self { book_repository }
+    }
+
+    /// Revisions of a book, newest first
+    pub async fn execute(
+        &self,
+        book_id: Uuid,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<BookRevisionResponse>> {
+        self.book_repository
+            .find_by_id(book_id)
+            .await?
+            .ok_or(DomainError::BookNotFound)?;
+
+        let revisions = self.book_repository.get_revisions(book_id, limit, offset).await?;
+        Ok(revisions.into_iter().map(BookRevisionResponse::from).collect())
+    }
+}
+
+pub struct RevertBookUseCase<UR: UserRepository, BR: BookRepository> {
+    user_repository: UR,
+    book_repository: BR,
+    rating_prior: RatingPrior,
+}
+
+impl<UR: UserRepository, BR: BookRepository> RevertBookUseCase<UR, BR> {
+    pub fn new(user_repository: UR, book_repository: BR, rating_prior: RatingPrior) -> Self {
+        Self {
+            user_repository,
+            book_repository,
+            rating_prior,
+        }
+    }
+
+    /// Restore a book's catalog fields to how they were after `revision_number`.
+    /// The revert is itself recorded as a new revision.
+    pub async fn execute(&self, book_id: Uuid, revision_number: i32, curator_id: Uuid) -> Result<BookResponse> {
+        let curator = self.user_repository
+            .find_by_id(curator_id)
+            .await?
+            .ok_or(DomainError::UserNotFound)?;
+
+        if !curator.is_curator() {
+            return Err(DomainError::Unauthorized.into());
+        }
+
+        let book = self.book_repository
+            .find_by_id(book_id)
+            .await?
+            .ok_or(DomainError::BookNotFound)?;
+
+        let revision = self.book_repository
+            .get_revision(book_id, revision_number)
+            .await?
+            .ok_or(DomainError::RevisionNotFound)?;
+
+        let mut restored = BookHistoryService::restore(&book, &revision.snapshot)?;
+        restored.updated_at = chrono::Utc::now();
+        restored.updated_by = Some(curator_id);
+
+        let updated_book = self.book_repository.update(restored).await?;
+
+        Ok(BookResponse::new(updated_book, &self.rating_prior))
+    }
+}
@@ -3,50 +3,68 @@ use uuid::Uuid;
 
 use crate::domain::{
     entities::book::Book,
-    repositories::book_repository::BookRepository,
+    repositories::{
+        book_repository::BookRepository,
+        author_repository::AuthorRepository,
+        genre_repository::GenreRepository,
+    },
+    services::{author_service::AuthorService, genre_taxonomy::GenreTaxonomy, rating_service::RatingPrior},
+};
+use crate::application::{
+    dtos::book_dtos::{CreateBookRequest, BookResponse},
+    use_cases::authors::link_contributors::{link_book_authors, link_book_publisher},
 };
-use crate::application::dtos::book_dtos::{CreateBookRequest, BookResponse};
 
-pub struct CreateBookUseCase<R: BookRepository> {
+pub struct CreateBookUseCase<R: BookRepository, AR: AuthorRepository, GR: GenreRepository> {
     book_repository: R,
+    author_repository: AR,
+    genre_repository: GR,
+    author_service: AuthorService,
+    rating_prior: RatingPrior,
 }
 
-impl<R: BookRepository> CreateBookUseCase<R> {
-    pub fn new(book_repository: R) -> Self {
-        Self { book_repository }
+impl<R: BookRepository, AR: AuthorRepository, GR: GenreRepository> CreateBookUseCase<R, AR, GR> {
+    pub fn new(
+        book_repository: R,
+        author_repository: AR,
+        genre_repository: GR,
+        author_service: AuthorService,
+        rating_prior: RatingPrior,
+    ) -> Self {
+        Self {
+            book_repository,
+            author_repository,
+            genre_repository,
+            author_service,
+            rating_prior,
+        }
     }
 
     pub async fn execute(&self, request: CreateBookRequest, created_by: Uuid) -> Result<BookResponse> {
-        let book = Book::new(
+        // Map free-text genres ("Sci-Fi", "science fiction") onto canonical ones
+        let taxonomy = GenreTaxonomy::new(self.genre_repository.find_all().await?);
+
+        let mut book = Book::new(
             request.title,
             request.author,
             request.description,
-            request.genre,
+            taxonomy.canonicalize(&request.genre),
             request.publication_year,
             request.publisher,
             request.language,
             request.page_count,
             created_by,
         );
+        book.subtitle = request.subtitle;
+        book.tags = request.tags.clone();
+        book.catalog_tags = request.tags;
 
+        // Link the publisher before saving and the authors once the book
+        // exists, so a failed save leaves no contributor rows behind
+        link_book_publisher(&self.author_repository, &self.author_service, &mut book).await?;
         let saved_book = self.book_repository.save(book).await?;
+        link_book_authors(&self.author_repository, &self.author_service, &saved_book).await?;
 
-        Ok(BookResponse {
-            id: saved_book.id.to_string(),
-            title: saved_book.title,
-            author: saved_book.author,
-            isbn: saved_book.isbn,
-            description: saved_book.description,
-            genre: saved_book.genre,
-            tags: saved_book.tags,
-            publication_year: saved_book.publication_year,
-            publisher: saved_book.publisher,
-            language: saved_book.language,
-            page_count: saved_book.page_count,
-            cover_image_url: saved_book.cover_image_url,
-            average_rating: saved_book.average_rating,
-            ratings_count: saved_book.ratings_count,
-            created_at: saved_book.created_at.to_rfc3339(),
-        })
+        Ok(BookResponse::new(saved_book, &self.rating_prior))
     }
 }
\ No newline at end of file
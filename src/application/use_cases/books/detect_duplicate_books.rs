@@ -0,0 +1,88 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::duplicate::DuplicateCandidate,
+    repositories::{
+        book_repository::BookRepository,
+        duplicate_repository::DuplicateRepository,
+        user_repository::UserRepository,
+    },
+    services::duplicate_detection_service::DuplicateDetectionService,
+    errors::domain_error::DomainError,
+};
+use crate::application::dtos::book_dtos::DuplicateScanResponse;
+
+const SCAN_PAGE_SIZE: usize = 500;
+
+pub struct DetectDuplicateBooksUseCase<UR: UserRepository, BR: BookRepository, DR: DuplicateRepository> {
+    user_repository: UR,
+    book_repository: BR,
+    duplicate_repository: DR,
+    detection_service: DuplicateDetectionService,
+}
+
+impl<UR: UserRepository, BR: BookRepository, DR: DuplicateRepository> DetectDuplicateBooksUseCase<UR, BR, DR> {
+    pub fn new(
+        user_repository: UR,
+        book_repository: BR,
+        duplicate_repository: DR,
+        detection_service: DuplicateDetectionService,
+    ) -> Self {
+        Self {
+            user_repository,
+            book_repository,
+            duplicate_repository,
+            detection_service,
+        }
+    }
+
+    /// Scan the whole catalog and queue new candidate pairs for review
+    pub async fn execute(&self, admin_id: Uuid) -> Result<DuplicateScanResponse> {
+        let admin = self.user_repository
+            .find_by_id(admin_id)
+            .await?
+            .ok_or(DomainError::UserNotFound)?;
+
+        if !admin.is_admin() {
+            return Err(DomainError::Unauthorized.into());
+        }
+
+        let mut books = Vec::new();
+        loop {
+            let page = self.book_repository
+                .find_all(Some(SCAN_PAGE_SIZE), Some(books.len()))
+                .await?;
+            let page_len = page.len();
+            books.extend(page);
+
+            if page_len < SCAN_PAGE_SIZE {
+                break;
+            }
+        }
+
+        let matches = self.detection_service.find_duplicates(&books);
+        let mut queued = 0;
+
+        for (book, other, found) in &matches {
+            // Already queued, merged or dismissed pairs are not raised again
+            if self.duplicate_repository
+                .find_candidate_for_pair(book.id, other.id)
+                .await?
+                .is_some()
+            {
+                continue;
+            }
+
+            let candidate = DuplicateCandidate::new(book.id, other.id, found.similarity, found.reasons.clone());
+            self.duplicate_repository.save_candidate(candidate).await?;
+            queued += 1;
+        }
+
+        Ok(DuplicateScanResponse {
+            books_scanned: books.len(),
+            candidates_found: matches.len(),
+            candidates_queued: queued,
+        })
+    }
+}
@@ -0,0 +1,94 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    repositories::{book_repository::BookRepository, user_repository::UserRepository},
+    services::duplicate_detection_service::{CoverHashIndex, COVER_MATCH_MAX_DISTANCE},
+    errors::domain_error::DomainError,
+};
+use crate::application::dtos::book_dtos::{CoverDuplicateResponse, DuplicateBookSummary};
+
+/// Beyond this many differing bits unrelated covers start to match
+const MAX_ALLOWED_DISTANCE: u32 = 16;
+
+const SCAN_PAGE_SIZE: usize = 500;
+
+/// List pairs of books with near-identical covers for curators to fix
+pub struct FindCoverDuplicatesUseCase<UR: UserRepository, BR: BookRepository> {
+    user_repository: UR,
+    book_repository: BR,
+}
+
+impl<UR: UserRepository, BR: BookRepository> FindCoverDuplicatesUseCase<UR, BR> {
+    pub fn new(user_repository: UR, book_repository: BR) -> Self {
+        Self {
+            user_repository,
+            book_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        max_distance: Option<u32>,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<CoverDuplicateResponse>> {
+        let user = self.user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(DomainError::UserNotFound)?;
+
+        if !user.is_curator() {
+            return Err(DomainError::Unauthorized.into());
+        }
+
+        let max_distance = max_distance.unwrap_or(COVER_MATCH_MAX_DISTANCE);
+        if max_distance > MAX_ALLOWED_DISTANCE {
+            return Err(DomainError::InvalidInput(format!(
+                "max_distance must be at most {}",
+                MAX_ALLOWED_DISTANCE
+            )).into());
+        }
+
+        // Only IDs and hashes are kept while scanning the catalog in pages
+        let mut index = CoverHashIndex::new(max_distance);
+        let mut pairs: Vec<(Uuid, Uuid, u32)> = Vec::new();
+        let mut after_id = None;
+        loop {
+            let page = self.book_repository
+                .find_page_by_id(after_id, SCAN_PAGE_SIZE)
+                .await?;
+            let page_len = page.len();
+            after_id = page.last().map(|book| book.id);
+
+            for book in page.iter().filter(|book| book.deleted_at.is_none()) {
+                for (other_id, distance) in index.insert(book) {
+                    pairs.push((other_id, book.id, distance));
+                }
+            }
+
+            if page_len < SCAN_PAGE_SIZE {
+                break;
+            }
+        }
+        pairs.sort_by_key(|(_, _, distance)| *distance);
+
+        let mut responses = Vec::new();
+        for (book_id, other_id, distance) in pairs.into_iter().skip(offset.unwrap_or(0)).take(limit.unwrap_or(20)) {
+            let (Some(book), Some(other)) = (
+                self.book_repository.find_by_id(book_id).await?,
+                self.book_repository.find_by_id(other_id).await?,
+            ) else {
+                continue;
+            };
+            responses.push(CoverDuplicateResponse {
+                book: DuplicateBookSummary::from(book),
+                other: DuplicateBookSummary::from(other),
+                distance,
+            });
+        }
+
+        Ok(responses)
+    }
+}
@@ -0,0 +1,69 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    repositories::{
+        book_repository::BookRepository,
+        duplicate_repository::DuplicateRepository,
+        recommendation_repository::RecommendationRepository,
+    },
+    services::{rating_service::RatingPrior, reading_service::ReadingService},
+    errors::domain_error::DomainError,
+};
+use crate::application::dtos::book_dtos::BookResponse;
+
+/// Result of looking up a book by ID
+pub enum BookLookup {
+    Found(BookResponse),
+    /// The book was merged into another one
+    MovedTo(Uuid),
+}
+
+pub struct GetBookUseCase<BR: BookRepository, DR: DuplicateRepository, RR: RecommendationRepository> {
+    book_repository: BR,
+    duplicate_repository: DR,
+    recommendation_repository: RR,
+    reading_service: ReadingService,
+    rating_prior: RatingPrior,
+}
+
+impl<BR: BookRepository, DR: DuplicateRepository, RR: RecommendationRepository> GetBookUseCase<BR, DR, RR> {
+    pub fn new(
+        book_repository: BR,
+        duplicate_repository: DR,
+        recommendation_repository: RR,
+        reading_service: ReadingService,
+        rating_prior: RatingPrior,
+    ) -> Self {
+        Self {
+            book_repository,
+            duplicate_repository,
+            recommendation_repository,
+            reading_service,
+            rating_prior,
+        }
+    }
+
+    /// Look up a book, with the caller's reading progress when `user_id` is known
+    pub async fn execute(&self, book_id: Uuid, user_id: Option<Uuid>, preferred_locales: &[String]) -> Result<BookLookup> {
+        if let Some(book) = self.book_repository.find_by_id(book_id).await? {
+            let reading_progress = match user_id {
+                Some(user_id) => {
+                    let sessions = self.recommendation_repository.get_book_reading_sessions(user_id, book.id).await?;
+                    self.reading_service.progress(&book, &sessions)
+                }
+                None => None,
+            };
+
+            return Ok(BookLookup::Found(BookResponse {
+                reading_progress,
+                ..BookResponse::localized(book, preferred_locales, &self.rating_prior)
+            }));
+        }
+
+        match self.duplicate_repository.find_redirect(book_id).await? {
+            Some(redirect) => Ok(BookLookup::MovedTo(redirect.to_book_id)),
+            None => Err(DomainError::BookNotFound.into()),
+        }
+    }
+}
@@ -1,18 +1,25 @@
 use anyhow::Result;
 
-use crate::domain::repositories::book_repository::BookRepository;
+use crate::domain::{
+    repositories::book_repository::BookRepository,
+    services::rating_service::RatingPrior,
+};
 use crate::application::dtos::book_dtos::{BookSearchQuery, BookResponse};
 
 pub struct GetBooksUseCase<R: BookRepository> {
     book_repository: R,
+    rating_prior: RatingPrior,
 }
 
 impl<R: BookRepository> GetBooksUseCase<R> {
-    pub fn new(book_repository: R) -> Self {
-        Self { book_repository }
+    pub fn new(book_repository: R, rating_prior: RatingPrior) -> Self {
+        Self {
+            book_repository,
+            rating_prior,
+        }
     }
 
-    pub async fn execute(&self, query: BookSearchQuery) -> Result<Vec<BookResponse>> {
+    pub async fn execute(&self, query: BookSearchQuery, preferred_locales: &[String]) -> Result<Vec<BookResponse>> {
         let books = if let Some(title) = &query.title {
             self.book_repository.find_by_title(title).await?
         } else if let Some(author) = &query.author {
@@ -25,23 +32,7 @@ impl<R: BookRepository> GetBooksUseCase<R> {
 
         let book_responses = books
             .into_iter()
-            .map(|book| BookResponse {
-                id: book.id.to_string(),
-                title: book.title,
-                author: book.author,
-                isbn: book.isbn,
-                description: book.description,
-                genre: book.genre,
-                tags: book.tags,
-                publication_year: book.publication_year,
-                publisher: book.publisher,
-                language: book.language,
-                page_count: book.page_count,
-                cover_image_url: book.cover_image_url,
-                average_rating: book.average_rating,
-                ratings_count: book.ratings_count,
-                created_at: book.created_at.to_rfc3339(),
-            })
+            .map(|book| BookResponse::localized(book, preferred_locales, &self.rating_prior))
             .collect();
 
         Ok(book_responses)
@@ -0,0 +1,227 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{author::ContributorRole, book::Book, user::User},
+    repositories::{
+        book_repository::BookRepository,
+        user_repository::UserRepository,
+        author_repository::AuthorRepository,
+        genre_repository::GenreRepository,
+    },
+    services::{author_service::AuthorService, book_service::BookService, genre_taxonomy::GenreTaxonomy},
+    errors::domain_error::DomainError,
+};
+use crate::infrastructure::external::onix::{OnixParser, OnixProduct, OnixNotificationType};
+use crate::application::{
+    dtos::book_dtos::{OnixIngestResponse, OnixIngestError},
+    use_cases::authors::link_contributors::{link_book_authors, link_book_publisher, link_credited_contributors},
+};
+
+pub struct IngestOnixFeedUseCase<R: BookRepository, UR: UserRepository, AR: AuthorRepository, GR: GenreRepository> {
+    book_repository: R,
+    user_repository: UR,
+    author_repository: AR,
+    genre_repository: GR,
+    book_service: BookService,
+    author_service: AuthorService,
+    onix_parser: OnixParser,
+}
+
+impl<R: BookRepository, UR: UserRepository, AR: AuthorRepository, GR: GenreRepository> IngestOnixFeedUseCase<R, UR, AR, GR> {
+    pub fn new(
+        book_repository: R,
+        user_repository: UR,
+        author_repository: AR,
+        genre_repository: GR,
+        book_service: BookService,
+        author_service: AuthorService,
+        onix_parser: OnixParser,
+    ) -> Self {
+        Self {
+            book_repository,
+            user_repository,
+            author_repository,
+            genre_repository,
+            book_service,
+            author_service,
+            onix_parser,
+        }
+    }
+
+    /// Ingest an ONIX 3.0 feed (curator). Products are keyed on ISBN, so
+    /// re-sending the same feed leaves the catalog unchanged.
+    pub async fn execute(&self, xml: &str, ingested_by: Uuid) -> Result<OnixIngestResponse> {
+        self.require_curator(ingested_by).await?;
+
+        let products = self.onix_parser
+            .parse(xml)
+            .map_err(|e| DomainError::InvalidInput(format!("Invalid ONIX feed: {}", e)))?;
+
+        // Map BISAC headings onto canonical genres, like books entered by hand
+        let taxonomy = GenreTaxonomy::new(self.genre_repository.find_all().await?);
+
+        let mut response = OnixIngestResponse {
+            created: 0,
+            updated: 0,
+            deleted: 0,
+            skipped: 0,
+            errors: Vec::new(),
+        };
+
+        for product in products {
+            let record_reference = product.record_reference.clone();
+
+            if let Err(e) = self.ingest_product(product, &taxonomy, ingested_by, &mut response).await {
+                response.errors.push(OnixIngestError {
+                    record_reference,
+                    message: e.to_string(),
+                });
+            }
+        }
+
+        Ok(response)
+    }
+
+    async fn ingest_product(
+        &self,
+        product: OnixProduct,
+        taxonomy: &GenreTaxonomy,
+        ingested_by: Uuid,
+        response: &mut OnixIngestResponse,
+    ) -> Result<()> {
+        let isbn = product
+            .isbn
+            .as_deref()
+            .map(BookService::normalize_isbn)
+            .filter(|isbn| !isbn.is_empty())
+            .ok_or_else(|| DomainError::InvalidInput("Product has no ISBN".to_string()))?;
+
+        let existing = self.book_repository.find_by_isbn(&isbn).await?;
+
+        match (product.notification_type, existing) {
+            (OnixNotificationType::Delete, Some(book)) => {
+                self.book_repository.delete(book.id).await?;
+                response.deleted += 1;
+            }
+            (OnixNotificationType::Delete, None) => {
+                response.skipped += 1;
+            }
+            (_, Some(mut book)) => {
+                let credits = Self::apply_product(&mut book, product, taxonomy);
+                book.updated_at = chrono::Utc::now();
+                book.updated_by = Some(ingested_by);
+                self.book_service.validate_book(&book)?;
+                link_book_publisher(&self.author_repository, &self.author_service, &mut book).await?;
+                let book = self.book_repository.update(book).await?;
+                self.link_contributors(&book, &credits).await?;
+                response.updated += 1;
+            }
+            (OnixNotificationType::Update, None) => {
+                return Err(DomainError::InvalidInput(
+                    "Block update received for an unknown ISBN".to_string(),
+                ).into());
+            }
+            (_, None) => {
+                let title = product
+                    .title
+                    .clone()
+                    .ok_or_else(|| DomainError::InvalidInput("Product has no title".to_string()))?;
+
+                let mut book = Book::new(
+                    title,
+                    String::new(),
+                    String::new(),
+                    Vec::new(),
+                    0,
+                    String::new(),
+                    String::new(),
+                    0,
+                    ingested_by,
+                );
+                book.isbn = Some(isbn);
+                let credits = Self::apply_product(&mut book, product, taxonomy);
+                // Products missing an author, publisher, year or page count are reported, not saved
+                self.book_service.validate_book(&book)?;
+                link_book_publisher(&self.author_repository, &self.author_service, &mut book).await?;
+
+                let book = self.book_repository.save(book).await?;
+                self.link_contributors(&book, &credits).await?;
+                response.created += 1;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Link a saved book's author credit, then the contributors the feed
+    /// credits in other roles
+    async fn link_contributors(&self, book: &Book, credits: &[(String, ContributorRole)]) -> Result<()> {
+        link_book_authors(&self.author_repository, &self.author_service, book).await?;
+        link_credited_contributors(&self.author_repository, &self.author_service, book.id, credits).await?;
+        Ok(())
+    }
+
+    /// Copy the fields present in an ONIX product onto a book. Absent fields
+    /// keep their current value, which is what block updates (type 04) expect.
+    /// Returns the product's contributor credits for linking.
+    fn apply_product(book: &mut Book, product: OnixProduct, taxonomy: &GenreTaxonomy) -> Vec<(String, ContributorRole)> {
+        if let Some(title) = product.title {
+            book.title = title;
+        }
+        if let Some(subtitle) = product.subtitle {
+            book.subtitle = Some(subtitle);
+        }
+        let authors: Vec<&str> = product.contributors
+            .iter()
+            .filter(|c| c.role == ContributorRole::Author)
+            .map(|c| c.name.as_str())
+            .collect();
+        if !authors.is_empty() {
+            book.author = authors.join(", ");
+        }
+        if let Some(description) = product.description {
+            book.description = description;
+        }
+        if !product.genres.is_empty() {
+            book.genre = taxonomy.canonicalize(&product.genres);
+        }
+        if !product.keywords.is_empty() {
+            // Community tags stay displayed once readers have voted some in
+            if !book.community_tags {
+                book.tags = product.keywords.clone();
+            }
+            book.catalog_tags = product.keywords;
+        }
+        if let Some(publisher) = product.publisher {
+            book.publisher = publisher;
+        }
+        if let Some(year) = product.publication_year {
+            book.publication_year = year;
+        }
+        if let Some(language) = product.language {
+            book.language = language;
+        }
+        if let Some(page_count) = product.page_count {
+            book.page_count = page_count;
+        }
+        if let Some(cover_image_url) = product.cover_image_url {
+            book.cover_image_url = Some(cover_image_url);
+        }
+
+        product.contributors.into_iter().map(|c| (c.name, c.role)).collect()
+    }
+
+    async fn require_curator(&self, user_id: Uuid) -> Result<User> {
+        let user = self.user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(DomainError::UserNotFound)?;
+
+        if !user.is_curator() {
+            return Err(DomainError::Unauthorized.into());
+        }
+
+        Ok(user)
+    }
+}
@@ -0,0 +1,97 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::book::{Book, BookTranslation},
+    repositories::{book_repository::BookRepository, user_repository::UserRepository},
+    services::{localization_service::LocalizationService, rating_service::RatingPrior},
+    errors::domain_error::DomainError,
+};
+use crate::application::dtos::book_dtos::{BookTranslationRequest, BookResponse};
+
+/// Add, replace and remove a book's localized metadata. Allowed for the
+/// book's creator and curators.
+pub struct ManageBookTranslationsUseCase<UR: UserRepository, BR: BookRepository> {
+    user_repository: UR,
+    book_repository: BR,
+    rating_prior: RatingPrior,
+}
+
+impl<UR: UserRepository, BR: BookRepository> ManageBookTranslationsUseCase<UR, BR> {
+    pub fn new(user_repository: UR, book_repository: BR, rating_prior: RatingPrior) -> Self {
+        Self {
+            user_repository,
+            book_repository,
+            rating_prior,
+        }
+    }
+
+    pub async fn upsert(
+        &self,
+        book_id: Uuid,
+        locale: &str,
+        user_id: Uuid,
+        request: BookTranslationRequest,
+    ) -> Result<BookResponse> {
+        let locale = LocalizationService::normalize_locale(locale)
+            .ok_or_else(|| DomainError::InvalidInput(format!("Invalid locale \"{}\"", locale)))?;
+        let mut book = self.load_editable(book_id, user_id).await?;
+
+        let translation = BookTranslation {
+            locale: locale.clone(),
+            title: request.title,
+            subtitle: request.subtitle,
+            description: request.description,
+        };
+
+        match book.translations.iter_mut().find(|t| t.locale == locale) {
+            Some(existing) => *existing = translation,
+            None => book.translations.push(translation),
+        }
+
+        self.save(book, user_id).await
+    }
+
+    pub async fn remove(&self, book_id: Uuid, locale: &str, user_id: Uuid) -> Result<BookResponse> {
+        let locale = LocalizationService::normalize_locale(locale)
+            .ok_or_else(|| DomainError::InvalidInput(format!("Invalid locale \"{}\"", locale)))?;
+        let mut book = self.load_editable(book_id, user_id).await?;
+
+        let before = book.translations.len();
+        book.translations.retain(|t| t.locale != locale);
+        if book.translations.len() == before {
+            return Err(DomainError::InvalidInput(format!("Book has no \"{}\" translation", locale)).into());
+        }
+
+        self.save(book, user_id).await
+    }
+
+    async fn load_editable(&self, book_id: Uuid, user_id: Uuid) -> Result<Book> {
+        let book = self.book_repository
+            .find_by_id(book_id)
+            .await?
+            .ok_or(DomainError::BookNotFound)?;
+
+        if book.created_by != user_id {
+            let user = self.user_repository
+                .find_by_id(user_id)
+                .await?
+                .ok_or(DomainError::UserNotFound)?;
+
+            if !user.is_curator() {
+                return Err(DomainError::Unauthorized.into());
+            }
+        }
+
+        Ok(book)
+    }
+
+    async fn save(&self, mut book: Book, user_id: Uuid) -> Result<BookResponse> {
+        book.updated_at = chrono::Utc::now();
+        book.updated_by = Some(user_id);
+
+        let updated_book = self.book_repository.update(book).await?;
+
+        Ok(BookResponse::new(updated_book, &self.rating_prior))
+    }
+}
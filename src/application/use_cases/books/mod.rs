@@ -4,8 +4,29 @@ pub mod create_book;
 pub mod get_books;
 pub mod upload_book_image;
 pub mod rate_book;
+pub mod ingest_onix_feed;
+pub mod get_book;
+pub mod detect_duplicate_books;
+pub mod review_duplicates;
+pub mod book_revisions;
+pub mod manage_translations;
+pub mod backfill_cover_placeholders;
+pub mod find_cover_duplicates;
+pub mod book_reviews;
+pub mod recompute_rating_stats;
+pub mod similar_books;
+pub mod trending_books;
 
 pub use create_book::*;
 pub use get_books::*;
 pub use upload_book_image::*;
-pub use rate_book::*;
\ No newline at end of file
+pub use rate_book::*;
+pub use ingest_onix_feed::*;
+pub use get_book::*;
+pub use detect_duplicate_books::*;
+pub use review_duplicates::*;
+pub use book_revisions::*;
+pub use manage_translations::*;
+pub use backfill_cover_placeholders::*;
+pub use find_cover_duplicates::*;
+pub use book_reviews::*;
\ No newline at end of file
@@ -2,23 +2,38 @@ use anyhow::Result;
 use uuid::Uuid;
 
 use crate::domain::{
-    entities::book::BookRating,
-    repositories::book_repository::BookRepository,
-    services::book_service::BookService,
+    entities::{book::BookRating, review::ReviewStatus},
+    repositories::{
+        book_repository::BookRepository,
+        notification_repository::NotificationRepository,
+    },
+    services::{book_service::BookService, review_moderation_service::ReviewModerationService},
     errors::domain_error::DomainError,
 };
-use crate::application::dtos::book_dtos::{BookRatingRequest, BookRatingResponse};
+use crate::application::{
+    dtos::book_dtos::{BookRatingRequest, BookRatingResponse},
+    use_cases::reviews::screen_review::{screen_review, notify_review_rejected},
+};
 
-pub struct RateBookUseCase<R: BookRepository> {
+pub struct RateBookUseCase<R: BookRepository, N: NotificationRepository> {
     book_repository: R,
+    notification_repository: N,
     book_service: BookService,
+    review_moderation: ReviewModerationService,
 }
 
-impl<R: BookRepository> RateBookUseCase<R> {
-    pub fn new(book_repository: R, book_service: BookService) -> Self {
+impl<R: BookRepository, N: NotificationRepository> RateBookUseCase<R, N> {
+    pub fn new(
+        book_repository: R,
+        notification_repository: N,
+        book_service: BookService,
+        review_moderation: ReviewModerationService,
+    ) -> Self {
         Self {
             book_repository,
+            notification_repository,
             book_service,
+            review_moderation,
         }
     }
 
@@ -29,7 +44,7 @@ impl<R: BookRepository> RateBookUseCase<R> {
         request: BookRatingRequest,
     ) -> Result<BookRatingResponse> {
         // Check if book exists
-        let mut book = self.book_repository
+        self.book_repository
             .find_by_id(book_id)
             .await?
             .ok_or(DomainError::BookNotFound)?;
@@ -44,32 +59,79 @@ impl<R: BookRepository> RateBookUseCase<R> {
         }
 
         // Create new rating
-        let rating = BookRating {
+        let mut rating = BookRating {
             id: Uuid::new_v4(),
             book_id,
             user_id,
             rating: request.rating,
             review: request.review,
             created_at: chrono::Utc::now(),
+            updated_at: None,
+            status: ReviewStatus::Published,
+            helpful_count: 0,
+            not_helpful_count: 0,
+            helpfulness: 0.0,
+            report_count: 0,
+            moderation_reasons: vec![],
         };
+        screen_review(&self.book_repository, &self.review_moderation, &mut rating).await?;
 
-        // Save rating
+        // Save rating, the book and work stats are updated in the same transaction
         let saved_rating = self.book_repository.save_rating(rating).await?;
+        self.notify_if_rejected(&saved_rating).await?;
 
-        // Update book rating statistics
-        self.book_service.update_book_rating_stats(&mut book, request.rating);
-        self.book_repository.update(book).await?;
-
-        // Update aggregated rating stats
-        self.book_repository.update_book_rating_stats(book_id).await?;
-
-        Ok(BookRatingResponse {
-            id: saved_rating.id.to_string(),
-            book_id: saved_rating.book_id.to_string(),
-            user_id: saved_rating.user_id.to_string(),
-            rating: saved_rating.rating,
-            review: saved_rating.review,
-            created_at: saved_rating.created_at.to_rfc3339(),
-        })
+        Ok(BookRatingResponse::from(saved_rating))
     }
-}
\ No newline at end of file
+
+    /// Change the caller's rating and review of a book
+    pub async fn update(
+        &self,
+        book_id: Uuid,
+        user_id: Uuid,
+        request: BookRatingRequest,
+    ) -> Result<BookRatingResponse> {
+        self.book_repository
+            .find_by_id(book_id)
+            .await?
+            .ok_or(DomainError::BookNotFound)?;
+
+        let mut rating = self.book_repository
+            .get_user_rating(book_id, user_id)
+            .await?
+            .ok_or(DomainError::RatingNotFound)?;
+
+        rating.rating = request.rating;
+        rating.review = request.review;
+        rating.updated_at = Some(chrono::Utc::now());
+        screen_review(&self.book_repository, &self.review_moderation, &mut rating).await?;
+
+        let updated_rating = self.book_repository.update_rating(rating).await?;
+        self.notify_if_rejected(&updated_rating).await?;
+
+        Ok(BookRatingResponse::from(updated_rating))
+    }
+
+    /// Remove the caller's rating and review of a book
+    pub async fn delete(&self, book_id: Uuid, user_id: Uuid) -> Result<()> {
+        self.book_repository
+            .find_by_id(book_id)
+            .await?
+            .ok_or(DomainError::BookNotFound)?;
+
+        let rating = self.book_repository
+            .get_user_rating(book_id, user_id)
+            .await?
+            .ok_or(DomainError::RatingNotFound)?;
+
+        self.book_repository.delete_rating(&rating).await
+    }
+
+    /// The rating still counts when its review is rejected, only the text is withheld
+    async fn notify_if_rejected(&self, rating: &BookRating) -> Result<()> {
+        if rating.status == ReviewStatus::Rejected {
+            notify_review_rejected(&self.notification_repository, rating, &rating.moderation_reasons).await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use anyhow::Result;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::book::Book,
+    repositories::{book_repository::BookRepository, work_repository::WorkRepository},
+};
+use crate::application::dtos::book_dtos::RatingStatsRecomputeResponse;
+
+const RECOMPUTE_PAGE_SIZE: usize = 200;
+
+/// Recompute every book's and work's rating stats from the stored ratings,
+/// repairing drift left by failed writes or manual data fixes
+pub struct RecomputeRatingStatsUseCase<BR: BookRepository, WR: WorkRepository> {
+    book_repository: BR,
+    work_repository: WR,
+}
+
+impl<BR: BookRepository, WR: WorkRepository> RecomputeRatingStatsUseCase<BR, WR> {
+    pub fn new(book_repository: BR, work_repository: WR) -> Self {
+        Self {
+            book_repository,
+            work_repository,
+        }
+    }
+
+    /// Run by the `recompute-rating-stats` subcommand
+    pub async fn execute(&self) -> Result<RatingStatsRecomputeResponse> {
+        let mut response = RatingStatsRecomputeResponse {
+            books_scanned: 0,
+            books_repaired: 0,
+            works_recomputed: 0,
+        };
+        let mut work_ids: HashSet<Uuid> = HashSet::new();
+        let mut after_id = None;
+
+        // Paged by ID, which never changes, so books created or edited while
+        // this runs can't shift a page; soft-deleted books are repaired too,
+        // as they can still be restored
+        loop {
+            let page = self.book_repository
+                .find_page_by_id(after_id, RECOMPUTE_PAGE_SIZE)
+                .await?;
+            let page_len = page.len();
+            after_id = page.last().map(|book| book.id);
+
+            for book in page {
+                if let Some(recomputed) = self.book_repository.update_book_rating_stats(book.id).await? {
+                    if Self::has_drifted(&book, &recomputed) {
+                        tracing::info!(
+                            "Repaired rating stats of book {}: {} ratings averaging {:.3}, was {} averaging {:.3}",
+                            book.id,
+                            recomputed.ratings_count,
+                            recomputed.average_rating,
+                            book.ratings_count,
+                            book.average_rating
+                        );
+                        response.books_repaired += 1;
+                    }
+                }
+                if let Some(work_id) = book.work_id {
+                    work_ids.insert(work_id);
+                }
+            }
+
+            response.books_scanned += page_len;
+            if page_len < RECOMPUTE_PAGE_SIZE {
+                break;
+            }
+        }
+
+        for work_id in work_ids {
+            self.work_repository.update_work_rating_stats(work_id).await?;
+            response.works_recomputed += 1;
+        }
+
+        Ok(response)
+    }
+
+    fn has_drifted(stored: &Book, recomputed: &Book) -> bool {
+        stored.ratings_count != recomputed.ratings_count
+            || (stored.rating_sum - recomputed.rating_sum).abs() > 1e-9
+            || (stored.average_rating - recomputed.average_rating).abs() > 1e-9
+            || stored.rating_histogram != recomputed.rating_histogram
+    }
+}
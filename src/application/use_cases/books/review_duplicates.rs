@@ -0,0 +1,191 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        duplicate::{DuplicateCandidate, DuplicateStatus},
+        revision::BookRevision,
+        user::User,
+    },
+    repositories::{
+        book_repository::BookRepository,
+        duplicate_repository::DuplicateRepository,
+        user_repository::UserRepository,
+    },
+    services::book_history_service::BookHistoryService,
+    errors::domain_error::DomainError,
+};
+use crate::application::dtos::book_dtos::{DuplicateCandidateResponse, DuplicateBookSummary, MergeBooksResponse};
+
+pub struct ReviewDuplicatesUseCase<UR: UserRepository, BR: BookRepository, DR: DuplicateRepository> {
+    user_repository: UR,
+    book_repository: BR,
+    duplicate_repository: DR,
+}
+
+impl<UR: UserRepository, BR: BookRepository, DR: DuplicateRepository> ReviewDuplicatesUseCase<UR, BR, DR> {
+    pub fn new(user_repository: UR, book_repository: BR, duplicate_repository: DR) -> Self {
+        Self {
+            user_repository,
+            book_repository,
+            duplicate_repository,
+        }
+    }
+
+    /// List pending candidate pairs, most similar first
+    pub async fn list(
+        &self,
+        admin_id: Uuid,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<DuplicateCandidateResponse>> {
+        self.require_admin(admin_id).await?;
+
+        let candidates = self.duplicate_repository
+            .find_pending_candidates(limit, offset)
+            .await?;
+
+        let mut responses = Vec::new();
+        for candidate in candidates {
+            let book = self.book_repository.find_by_id(candidate.book_id).await?;
+            let duplicate = self.book_repository.find_by_id(candidate.duplicate_book_id).await?;
+
+            // One side may have been removed since the scan
+            if let (Some(book), Some(duplicate)) = (book, duplicate) {
+                responses.push(DuplicateCandidateResponse {
+                    id: candidate.id.to_string(),
+                    book: DuplicateBookSummary::from(book),
+                    duplicate: DuplicateBookSummary::from(duplicate),
+                    similarity: candidate.similarity,
+                    reasons: candidate.reasons,
+                    created_at: candidate.created_at.to_rfc3339(),
+                });
+            }
+        }
+
+        Ok(responses)
+    }
+
+    /// Merge a candidate pair into `surviving_book_id`. The other book's ratings,
+    /// recommendations and reading sessions move over and its ID redirects.
+    pub async fn merge(
+        &self,
+        admin_id: Uuid,
+        candidate_id: Uuid,
+        surviving_book_id: Uuid,
+    ) -> Result<MergeBooksResponse> {
+        let admin = self.require_admin(admin_id).await?;
+        let mut candidate = self.find_pending(candidate_id).await?;
+
+        if !candidate.involves(surviving_book_id) {
+            return Err(DomainError::InvalidInput(
+                "Surviving book must be one of the candidate pair".to_string(),
+            ).into());
+        }
+
+        let merged_book_id = if candidate.book_id == surviving_book_id {
+            candidate.duplicate_book_id
+        } else {
+            candidate.book_id
+        };
+
+        let mut survivor = self.book_repository
+            .find_by_id(surviving_book_id)
+            .await?
+            .ok_or(DomainError::BookNotFound)?;
+        let merged = self.book_repository
+            .find_by_id(merged_book_id)
+            .await?
+            .ok_or(DomainError::BookNotFound)?;
+        let previous = survivor.clone();
+
+        // Keep catalog data the survivor is missing
+        if survivor.isbn.is_none() {
+            survivor.isbn = merged.isbn.clone();
+        }
+        if survivor.cover_images.is_empty() && survivor.cover_image_url.is_none() {
+            survivor.cover_images = merged.cover_images.clone();
+            survivor.cover_image_url = merged.cover_image_url.clone();
+        }
+        for tag in &merged.tags {
+            if !survivor.tags.contains(tag) {
+                survivor.tags.push(tag.clone());
+            }
+        }
+        for tag in &merged.catalog_tags {
+            if !survivor.catalog_tags.contains(tag) {
+                survivor.catalog_tags.push(tag.clone());
+            }
+        }
+        survivor.community_tags |= merged.community_tags;
+        survivor.updated_at = chrono::Utc::now();
+        survivor.updated_by = Some(admin_id);
+
+        let changes = BookHistoryService::diff(Some(&previous), &survivor)?;
+        let revision = if changes.is_empty() {
+            None
+        } else {
+            let latest = self.book_repository
+                .get_revisions(surviving_book_id, Some(1), None)
+                .await?
+                .into_iter()
+                .next()
+                .map_or(0, |revision| revision.revision_number);
+            Some(BookRevision::new(
+                surviving_book_id,
+                latest + 1,
+                Some(admin_id),
+                changes,
+                BookHistoryService::snapshot(&survivor)?,
+            ))
+        };
+
+        self.duplicate_repository.merge_books(merged_book_id, survivor, revision).await?;
+        self.book_repository.update_book_rating_stats(surviving_book_id).await?;
+
+        candidate.resolve(DuplicateStatus::Merged, admin.id);
+        self.duplicate_repository.update_candidate(candidate).await?;
+
+        Ok(MergeBooksResponse {
+            surviving_book_id: surviving_book_id.to_string(),
+            merged_book_id: merged_book_id.to_string(),
+        })
+    }
+
+    /// Mark a candidate pair as not a duplicate
+    pub async fn dismiss(&self, admin_id: Uuid, candidate_id: Uuid) -> Result<()> {
+        let admin = self.require_admin(admin_id).await?;
+        let mut candidate = self.find_pending(candidate_id).await?;
+
+        candidate.resolve(DuplicateStatus::Dismissed, admin.id);
+        self.duplicate_repository.update_candidate(candidate).await?;
+
+        Ok(())
+    }
+
+    async fn require_admin(&self, user_id: Uuid) -> Result<User> {
+        let user = self.user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(DomainError::UserNotFound)?;
+
+        if !user.is_admin() {
+            return Err(DomainError::Unauthorized.into());
+        }
+
+        Ok(user)
+    }
+
+    async fn find_pending(&self, candidate_id: Uuid) -> Result<DuplicateCandidate> {
+        let candidate = self.duplicate_repository
+            .find_candidate(candidate_id)
+            .await?
+            .ok_or(DomainError::DuplicateCandidateNotFound)?;
+
+        if candidate.status != DuplicateStatus::Pending {
+            return Err(DomainError::InvalidInput("Candidate has already been reviewed".to_string()).into());
+        }
+
+        Ok(candidate)
+    }
+}
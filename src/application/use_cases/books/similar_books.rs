@@ -0,0 +1,78 @@
+use anyhow::Result;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::domain::{
+    repositories::book_repository::BookRepository,
+    services::{rating_service::RatingPrior, similarity_service::SimilarityService},
+    errors::domain_error::DomainError,
+};
+use crate::application::dtos::book_dtos::{BookResponse, SimilarBookResponse};
+
+const CONTENT_CANDIDATES: usize = 200;
+const MAX_RATERS: usize = 500;
+/// Books outside the content candidates that readers co-rated most often
+const CO_RATED_CANDIDATES: usize = 50;
+
+pub struct GetSimilarBooksUseCase<BR: BookRepository> {
+    book_repository: BR,
+    similarity_service: SimilarityService,
+    rating_prior: RatingPrior,
+}
+
+impl<BR: BookRepository> GetSimilarBooksUseCase<BR> {
+    pub fn new(book_repository: BR, similarity_service: SimilarityService, rating_prior: RatingPrior) -> Self {
+        Self {
+            book_repository,
+            similarity_service,
+            rating_prior,
+        }
+    }
+
+    /// Books most like the given one, by content and by what its readers also rated
+    pub async fn execute(
+        &self,
+        book_id: Uuid,
+        limit: Option<usize>,
+        preferred_locales: &[String],
+    ) -> Result<Vec<SimilarBookResponse>> {
+        let book = self.book_repository
+            .find_by_id(book_id)
+            .await?
+            .ok_or(DomainError::BookNotFound)?;
+
+        let mut candidates = self.book_repository
+            .find_similar_candidates(&book, CONTENT_CANDIDATES)
+            .await?;
+        let ratings = self.book_repository.get_ratings_for_book(book_id).await?;
+        let co_ratings = self.book_repository.get_co_ratings(book_id, MAX_RATERS).await?;
+
+        // Readers may connect books that share nothing on paper
+        let known: HashSet<Uuid> = candidates.iter().map(|candidate| candidate.id).collect();
+        let mut co_rated: HashMap<Uuid, usize> = HashMap::new();
+        for rating in co_ratings.iter().filter(|rating| !known.contains(&rating.book_id)) {
+            *co_rated.entry(rating.book_id).or_default() += 1;
+        }
+        let mut co_rated: Vec<(Uuid, usize)> = co_rated.into_iter().filter(|(_, count)| *count >= 2).collect();
+        co_rated.sort_by(|a, b| b.1.cmp(&a.1));
+        for (candidate_id, _) in co_rated.into_iter().take(CO_RATED_CANDIDATES) {
+            if let Some(candidate) = self.book_repository.find_by_id(candidate_id).await? {
+                candidates.push(candidate);
+            }
+        }
+
+        let limit = limit.unwrap_or(10).min(50);
+        let similar = self.similarity_service.rank(&book, candidates, &ratings, &co_ratings, limit);
+
+        Ok(similar
+            .into_iter()
+            .map(|similar| SimilarBookResponse {
+                book: BookResponse::localized(similar.book, preferred_locales, &self.rating_prior),
+                score: similar.score,
+                content_score: similar.content_score,
+                co_rating_score: similar.co_rating_score,
+                explanation: similar.explanation,
+            })
+            .collect())
+    }
+}
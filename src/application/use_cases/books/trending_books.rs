@@ -0,0 +1,147 @@
+use anyhow::Result;
+use chrono::Utc;
+
+use crate::domain::{
+    entities::trending::{TrendingEntry, TrendingList, TrendingWindow},
+    repositories::{
+        book_repository::BookRepository,
+        recommendation_repository::RecommendationRepository,
+        trending_repository::TrendingRepository,
+    },
+    services::{
+        rating_service::RatingPrior,
+        trending_service::{TrendingScore, TrendingService},
+    },
+};
+use crate::application::dtos::{
+    book_dtos::BookResponse,
+    trending_dtos::{TrendingBookResponse, TrendingRefreshResponse, TrendingResponse},
+};
+
+/// Recompute the trending and new-and-notable lists. Run by the periodic
+/// refresh job so requests only read precomputed lists.
+pub struct RefreshTrendingUseCase<BR: BookRepository, RR: RecommendationRepository, TR: TrendingRepository> {
+    book_repository: BR,
+    recommendation_repository: RR,
+    trending_repository: TR,
+    trending_service: TrendingService,
+}
+
+impl<BR: BookRepository, RR: RecommendationRepository, TR: TrendingRepository> RefreshTrendingUseCase<BR, RR, TR> {
+    pub fn new(
+        book_repository: BR,
+        recommendation_repository: RR,
+        trending_repository: TR,
+        trending_service: TrendingService,
+    ) -> Self {
+        Self {
+            book_repository,
+            recommendation_repository,
+            trending_repository,
+            trending_service,
+        }
+    }
+
+    pub async fn execute(&self) -> Result<TrendingRefreshResponse> {
+        let now = Utc::now();
+        let mut response = TrendingRefreshResponse {
+            lists_refreshed: 0,
+            books_ranked: 0,
+        };
+
+        // The longest window covers the activity of the shorter ones
+        let since = now - TrendingWindow::Month.duration();
+        let ratings = self.book_repository.get_ratings_since(since).await?;
+        let clicks = self.recommendation_repository.get_clicked_since(since).await?;
+
+        for window in TrendingWindow::ALL {
+            let scores = self.trending_service.trending(window, now, &ratings, &clicks);
+            response.books_ranked += self.store(TrendingList::Trending(window), scores, now).await?;
+            response.lists_refreshed += 1;
+        }
+
+        let recent_books = self.book_repository
+            .find_created_since(now - chrono::Duration::days(self.trending_service.new_release_days))
+            .await?;
+        let notable = self.trending_service.new_and_notable(now, &recent_books);
+        response.books_ranked += self.store(TrendingList::NewAndNotable, notable, now).await?;
+        response.lists_refreshed += 1;
+
+        Ok(response)
+    }
+
+    async fn store(&self, list: TrendingList, scores: Vec<TrendingScore>, now: chrono::DateTime<Utc>) -> Result<usize> {
+        let entries: Vec<TrendingEntry> = scores
+            .into_iter()
+            .enumerate()
+            .map(|(index, score)| TrendingEntry::new(
+                list,
+                index as i32 + 1,
+                score.book_id,
+                score.score,
+                score.recent_ratings,
+                score.recent_clicks,
+                now,
+            ))
+            .collect();
+        let count = entries.len();
+
+        self.trending_repository.replace_list(list, entries).await?;
+        Ok(count)
+    }
+}
+
+pub struct GetTrendingBooksUseCase<BR: BookRepository, TR: TrendingRepository> {
+    book_repository: BR,
+    trending_repository: TR,
+    rating_prior: RatingPrior,
+}
+
+impl<BR: BookRepository, TR: TrendingRepository> GetTrendingBooksUseCase<BR, TR> {
+    pub fn new(book_repository: BR, trending_repository: TR, rating_prior: RatingPrior) -> Self {
+        Self {
+            book_repository,
+            trending_repository,
+            rating_prior,
+        }
+    }
+
+    /// Trending books for the window and new and notable books, as of the last refresh
+    pub async fn execute(
+        &self,
+        window: TrendingWindow,
+        limit: Option<usize>,
+        preferred_locales: &[String],
+    ) -> Result<TrendingResponse> {
+        let limit = limit.unwrap_or(20).min(50);
+
+        let trending = self.trending_repository.find_list(TrendingList::Trending(window), limit).await?;
+        let new_and_notable = self.trending_repository.find_list(TrendingList::NewAndNotable, limit).await?;
+        let computed_at = trending.iter().chain(new_and_notable.iter()).map(|entry| entry.computed_at).max();
+
+        Ok(TrendingResponse {
+            window,
+            computed_at,
+            trending: self.with_books(trending, preferred_locales).await?,
+            new_and_notable: self.with_books(new_and_notable, preferred_locales).await?,
+        })
+    }
+
+    /// Books deleted since the last refresh are skipped
+    async fn with_books(&self, entries: Vec<TrendingEntry>, preferred_locales: &[String]) -> Result<Vec<TrendingBookResponse>> {
+        let mut books = Vec::new();
+        for entry in entries {
+            if let Some(book) = self.book_repository.find_by_id(entry.book_id).await? {
+                books.push(TrendingBookResponse {
+                    rank: entry.rank,
+                    book: BookResponse::localized(book, preferred_locales, &self.rating_prior),
+                    score: entry.score,
+                    recent_ratings: entry.recent_ratings,
+                    recent_clicks: entry.recent_clicks,
+                });
+            }
+        }
+
+        Ok(books)
+    }
+}
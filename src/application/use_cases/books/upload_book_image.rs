@@ -1,30 +1,45 @@
 use anyhow::Result;
+use std::sync::Arc;
 use uuid::Uuid;
 
+use crate::domain::entities::book::CoverImage;
 use crate::domain::repositories::book_repository::BookRepository;
-use crate::infrastructure::external::cloudinary::CloudinaryService;
+use crate::domain::services::duplicate_detection_service::{DuplicateDetectionService, COVER_MATCH_MAX_DISTANCE};
+use crate::application::ports::image_storage_port::ImageStorage;
+use crate::infrastructure::external::image_processor::ImageProcessor;
 use crate::domain::errors::domain_error::DomainError;
+use crate::application::dtos::book_dtos::{CoverImageSet, CoverUploadResponse, SimilarCoverResponse, DuplicateBookSummary};
 
 pub struct UploadBookImageUseCase<R: BookRepository> {
     book_repository: R,
-    cloudinary_service: CloudinaryService,
+    image_storage: Arc<dyn ImageStorage>,
+    image_processor: ImageProcessor,
 }
 
 impl<R: BookRepository> UploadBookImageUseCase<R> {
-    pub fn new(book_repository: R, cloudinary_service: CloudinaryService) -> Self {
+    pub fn new(
+        book_repository: R,
+        image_storage: Arc<dyn ImageStorage>,
+        image_processor: ImageProcessor,
+    ) -> Self {
         Self {
             book_repository,
-            cloudinary_service,
+            image_storage,
+            image_processor,
         }
     }
 
+    /// Largest accepted upload, so callers can stop reading early
+    pub fn max_upload_bytes(&self) -> usize {
+        self.image_processor.limits().max_bytes
+    }
+
     pub async fn execute(
         &self,
         book_id: Uuid,
         user_id: Uuid,
         image_data: Vec<u8>,
-        filename: &str,
-    ) -> Result<String> {
+    ) -> Result<CoverUploadResponse> {
         // Check if book exists and user has permission to upload
         let mut book = self.book_repository
             .find_by_id(book_id)
@@ -36,23 +51,70 @@ impl<R: BookRepository> UploadBookImageUseCase<R> {
             return Err(DomainError::Unauthorized.into());
         }
 
-        // Validate image (basic validation)
-        if image_data.is_empty() {
-            return Err(DomainError::InvalidInput("Empty image data".to_string()).into());
-        }
+        // Validate, decode, strip metadata and render every size; this is CPU bound
+        let processor = self.image_processor.clone();
+        let processed = tokio::task::spawn_blocking(move || processor.process(&image_data)).await??;
 
-        // Upload to Cloudinary
-        let upload_result = self.cloudinary_service
-            .upload_image(image_data, filename, "book-covers")
-            .await
-            .map_err(|e| DomainError::ExternalServiceError(e.to_string()))?;
+        // Store every rendition under a fresh prefix so cached URLs never serve a stale cover
+        let upload_id = Uuid::new_v4();
+        let mut cover_images = Vec::with_capacity(processed.variants.len());
+        for variant in processed.variants {
+            let key = format!(
+                "book-covers/{}/{}/{}.{}",
+                book_id, upload_id, variant.size.as_str(), variant.format.extension()
+            );
+            let stored = self.image_storage
+                .store(&key, variant.data, variant.format.content_type())
+                .await
+                .map_err(|e| DomainError::ExternalServiceError(e.to_string()))?;
+
+            cover_images.push(CoverImage {
+                size: variant.size,
+                format: variant.format,
+                width: variant.width,
+                height: variant.height,
+                url: stored.url,
+                key: stored.key,
+            });
+        }
 
-        // Update book with image URL
-        book.cover_image_url = Some(upload_result.secure_url.clone());
+        // Update book with the new renditions, superseding any feed-supplied cover
+        let replaced = std::mem::replace(&mut book.cover_images, cover_images);
+        book.cover_placeholder = Some(processed.placeholder);
+        book.cover_hash = Some(processed.hash.clone());
+        book.cover_image_url = None;
         book.updated_at = chrono::Utc::now();
+        book.updated_by = Some(user_id);
 
-        self.book_repository.update(book).await?;
+        let updated_book = self.book_repository.update(book).await?;
 
-        Ok(upload_result.secure_url)
+        // Revisions don't record covers and a merge deletes the book it took a
+        // cover from, so no other record points at the previous renditions. A
+        // failed delete only leaks storage.
+        for image in replaced.iter().filter(|image| !image.key.is_empty()) {
+            if let Err(e) = self.image_storage.delete(&image.key).await {
+                tracing::warn!("Failed to delete replaced cover {}: {:?}", image.key, e);
+            }
+        }
+
+        // Warn when another book already uses this picture, often a wrong upload
+        let candidates = self.book_repository.find_with_cover_hash().await?;
+        let similar_covers = DuplicateDetectionService::find_cover_matches(
+            &updated_book,
+            &processed.hash,
+            &candidates,
+            COVER_MATCH_MAX_DISTANCE,
+        )
+        .into_iter()
+        .map(|(other, distance)| SimilarCoverResponse {
+            book: DuplicateBookSummary::from(other.clone()),
+            distance,
+        })
+        .collect();
+
+        Ok(CoverUploadResponse {
+            cover_images: CoverImageSet::from(&updated_book),
+            similar_covers,
+        })
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,221 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        book::Book,
+        challenge::{Challenge, ChallengeParticipant, ChallengeProgress},
+        user::User,
+    },
+    repositories::{
+        user_repository::UserRepository,
+        book_repository::BookRepository,
+        shelf_repository::ShelfRepository,
+        challenge_repository::ChallengeRepository,
+    },
+    services::challenge_service::ChallengeService,
+    errors::domain_error::DomainError,
+};
+use crate::application::dtos::challenge_dtos::{
+    ChallengeResponse, CreateChallengeRequest, LeaderboardEntry, LeaderboardResponse,
+};
+
+const DEFAULT_LEADERBOARD_LIMIT: usize = 50;
+const MAX_LEADERBOARD_LIMIT: usize = 100;
+
+pub struct ManageChallengesUseCase<UR: UserRepository, BR: BookRepository, SR: ShelfRepository, CR: ChallengeRepository> {
+    user_repository: UR,
+    book_repository: BR,
+    shelf_repository: SR,
+    challenge_repository: CR,
+    challenge_service: ChallengeService,
+}
+
+impl<UR: UserRepository, BR: BookRepository, SR: ShelfRepository, CR: ChallengeRepository> ManageChallengesUseCase<UR, BR, SR, CR> {
+    pub fn new(user_repository: UR, book_repository: BR, shelf_repository: SR, challenge_repository: CR) -> Self {
+        Self {
+            user_repository,
+            book_repository,
+            shelf_repository,
+            challenge_repository,
+            challenge_service: ChallengeService::new(),
+        }
+    }
+
+    pub async fn create(&self, admin_id: Uuid, request: CreateChallengeRequest) -> Result<ChallengeResponse> {
+        self.require_admin(admin_id).await?;
+        self.challenge_service
+            .check_challenge(&request.name, &request.rules, request.starts_at, request.ends_at)?;
+
+        let challenge = self.challenge_repository
+            .save(Challenge::new(
+                request.name.trim().to_string(),
+                request.description.trim().to_string(),
+                request.rules,
+                request.starts_at,
+                request.ends_at,
+                admin_id,
+            ))
+            .await?;
+
+        Ok(ChallengeResponse::new(challenge, 0, None, Utc::now()))
+    }
+
+    pub async fn delete(&self, admin_id: Uuid, challenge_id: Uuid) -> Result<()> {
+        self.require_admin(admin_id).await?;
+        self.require_challenge(challenge_id).await?;
+
+        self.challenge_repository.delete(challenge_id).await
+    }
+
+    /// All challenges, or only those running now, with the user's progress in
+    /// the ones they joined
+    pub async fn list(&self, user_id: Uuid, active_only: bool) -> Result<Vec<ChallengeResponse>> {
+        let now = Utc::now();
+        let challenges = self.challenge_repository
+            .find_all(active_only.then_some(now))
+            .await?;
+
+        let mut responses = Vec::with_capacity(challenges.len());
+        for challenge in challenges {
+            responses.push(self.to_response(challenge, user_id).await?);
+        }
+
+        Ok(responses)
+    }
+
+    pub async fn get(&self, user_id: Uuid, challenge_id: Uuid) -> Result<ChallengeResponse> {
+        let challenge = self.require_challenge(challenge_id).await?;
+        self.to_response(challenge, user_id).await
+    }
+
+    /// Join a challenge that hasn't ended; books finished since it started count
+    pub async fn join(&self, user_id: Uuid, challenge_id: Uuid) -> Result<ChallengeResponse> {
+        let challenge = self.require_challenge(challenge_id).await?;
+        if Utc::now() >= challenge.ends_at {
+            return Err(DomainError::InvalidInput("This challenge has ended".to_string()).into());
+        }
+        if self.challenge_repository.find_participant(challenge_id, user_id).await?.is_some() {
+            return Err(DomainError::ChallengeAlreadyJoined.into());
+        }
+
+        self.challenge_repository
+            .join(ChallengeParticipant::new(challenge_id, user_id))
+            .await?;
+        self.to_response(challenge, user_id).await
+    }
+
+    pub async fn leave(&self, user_id: Uuid, challenge_id: Uuid) -> Result<()> {
+        self.require_challenge(challenge_id).await?;
+        self.challenge_repository.leave(challenge_id, user_id).await
+    }
+
+    /// Participants ranked by progress, then by books finished
+    pub async fn leaderboard(&self, challenge_id: Uuid, limit: Option<usize>) -> Result<LeaderboardResponse> {
+        let challenge = self.require_challenge(challenge_id).await?;
+        let limit = limit.unwrap_or(DEFAULT_LEADERBOARD_LIMIT).clamp(1, MAX_LEADERBOARD_LIMIT);
+
+        let participants = self.challenge_repository.find_participants(challenge_id).await?;
+        let user_ids: Vec<Uuid> = participants.iter().map(|participant| participant.user_id).collect();
+        let mut finished = self.finished_books(&challenge, &user_ids).await?;
+
+        let standings = user_ids
+            .iter()
+            .map(|user_id| {
+                let books = finished.remove(user_id).unwrap_or_default();
+                (*user_id, self.challenge_service.evaluate(&challenge.rules, &books))
+            })
+            .collect();
+
+        let mut entries = Vec::new();
+        for (rank, user_id, progress) in self.challenge_service.rank(standings) {
+            if entries.len() == limit {
+                break;
+            }
+            // Deleted accounts drop off the board
+            let Some(user) = self.user_repository.find_by_id(user_id).await? else {
+                continue;
+            };
+
+            entries.push(LeaderboardEntry {
+                rank,
+                user_id: user_id.to_string(),
+                username: user.username,
+                progress: progress.progress,
+                books_counted: progress.books_counted,
+                completed: progress.completed,
+            });
+        }
+
+        Ok(LeaderboardResponse {
+            challenge_id: challenge_id.to_string(),
+            participants_count: participants.len(),
+            entries,
+        })
+    }
+
+    async fn to_response(&self, challenge: Challenge, user_id: Uuid) -> Result<ChallengeResponse> {
+        let participants_count = self.challenge_repository.count_participants(challenge.id).await?;
+        let my_progress = match self.challenge_repository.find_participant(challenge.id, user_id).await? {
+            Some(_) => Some(self.progress(&challenge, user_id).await?),
+            None => None,
+        };
+
+        Ok(ChallengeResponse::new(challenge, participants_count, my_progress, Utc::now()))
+    }
+
+    async fn progress(&self, challenge: &Challenge, user_id: Uuid) -> Result<ChallengeProgress> {
+        let books = self.finished_books(challenge, &[user_id])
+            .await?
+            .remove(&user_id)
+            .unwrap_or_default();
+
+        Ok(self.challenge_service.evaluate(&challenge.rules, &books))
+    }
+
+    /// Books each user finished while the challenge ran
+    async fn finished_books(&self, challenge: &Challenge, user_ids: &[Uuid]) -> Result<HashMap<Uuid, Vec<Book>>> {
+        let entries = self.shelf_repository
+            .find_finished_between(user_ids, challenge.starts_at, challenge.ends_at)
+            .await?;
+
+        let mut books: HashMap<Uuid, Option<Book>> = HashMap::new();
+        let mut finished: HashMap<Uuid, Vec<Book>> = HashMap::new();
+        for entry in entries {
+            if !books.contains_key(&entry.book_id) {
+                let book = self.book_repository.find_by_id(entry.book_id).await?;
+                books.insert(entry.book_id, book);
+            }
+            if let Some(Some(book)) = books.get(&entry.book_id) {
+                finished.entry(entry.user_id).or_default().push(book.clone());
+            }
+        }
+
+        Ok(finished)
+    }
+
+    async fn require_challenge(&self, challenge_id: Uuid) -> Result<Challenge> {
+        let challenge = self.challenge_repository
+            .find_by_id(challenge_id)
+            .await?
+            .ok_or(DomainError::ChallengeNotFound)?;
+
+        Ok(challenge)
+    }
+
+    async fn require_admin(&self, user_id: Uuid) -> Result<User> {
+        let user = self.user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(DomainError::UserNotFound)?;
+
+        if !user.is_admin() {
+            return Err(DomainError::Unauthorized.into());
+        }
+
+        Ok(user)
+    }
+}
@@ -0,0 +1,5 @@
+//! Community challenge use cases module
+
+pub mod manage_challenges;
+
+pub use manage_challenges::*;
@@ -0,0 +1,45 @@
+use anyhow::Result;
+use uuid::Uuid;
+use std::collections::HashMap;
+
+use crate::domain::{
+    entities::genre::Genre,
+    repositories::genre_repository::GenreRepository,
+};
+use crate::application::dtos::genre_dtos::GenreNode;
+
+pub struct GetGenreTreeUseCase<GR: GenreRepository> {
+    genre_repository: GR,
+}
+
+impl<GR: GenreRepository> GetGenreTreeUseCase<GR> {
+    pub fn new(genre_repository: GR) -> Self {
+        Self { genre_repository }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<GenreNode>> {
+        let genres = self.genre_repository.find_all().await?;
+
+        let mut children: HashMap<Option<Uuid>, Vec<Genre>> = HashMap::new();
+        for genre in genres {
+            children.entry(genre.parent_id).or_insert_with(Vec::new).push(genre);
+        }
+
+        Ok(Self::build_level(None, &mut children))
+    }
+
+    fn build_level(parent_id: Option<Uuid>, children: &mut HashMap<Option<Uuid>, Vec<Genre>>) -> Vec<GenreNode> {
+        let level = children.remove(&parent_id).unwrap_or_default();
+
+        level
+            .into_iter()
+            .map(|genre| GenreNode {
+                id: genre.id.to_string(),
+                children: Self::build_level(Some(genre.id), children),
+                name: genre.name,
+                slug: genre.slug,
+                synonyms: genre.synonyms,
+            })
+            .collect()
+    }
+}
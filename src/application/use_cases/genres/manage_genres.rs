@@ -0,0 +1,142 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{genre::Genre, user::User},
+    repositories::{genre_repository::GenreRepository, user_repository::UserRepository},
+    services::genre_taxonomy::GenreTaxonomy,
+    errors::domain_error::DomainError,
+};
+use crate::application::dtos::genre_dtos::{CreateGenreRequest, UpdateGenreRequest, GenreResponse};
+
+/// Admin CRUD over the genre taxonomy
+pub struct ManageGenresUseCase<UR: UserRepository, GR: GenreRepository> {
+    user_repository: UR,
+    genre_repository: GR,
+}
+
+impl<UR: UserRepository, GR: GenreRepository> ManageGenresUseCase<UR, GR> {
+    pub fn new(user_repository: UR, genre_repository: GR) -> Self {
+        Self {
+            user_repository,
+            genre_repository,
+        }
+    }
+
+    pub async fn create(&self, admin_id: Uuid, request: CreateGenreRequest) -> Result<GenreResponse> {
+        self.require_admin(admin_id).await?;
+
+        let slug = request
+            .slug
+            .map(|slug| GenreTaxonomy::slugify(&slug))
+            .unwrap_or_else(|| GenreTaxonomy::slugify(&request.name));
+        let genre = Genre::new(request.name, slug, request.parent_id, request.synonyms);
+
+        self.validate(&genre).await?;
+        let saved_genre = self.genre_repository.save(genre).await?;
+
+        Ok(GenreResponse::from(saved_genre))
+    }
+
+    pub async fn update(
+        &self,
+        admin_id: Uuid,
+        genre_id: Uuid,
+        request: UpdateGenreRequest,
+    ) -> Result<GenreResponse> {
+        self.require_admin(admin_id).await?;
+
+        let mut genre = self.genre_repository
+            .find_by_id(genre_id)
+            .await?
+            .ok_or(DomainError::GenreNotFound)?;
+
+        if let Some(name) = request.name {
+            genre.name = name;
+        }
+        if let Some(slug) = request.slug {
+            genre.slug = GenreTaxonomy::slugify(&slug);
+        }
+        if request.clear_parent {
+            genre.parent_id = None;
+        } else if let Some(parent_id) = request.parent_id {
+            genre.parent_id = Some(parent_id);
+        }
+        if let Some(synonyms) = request.synonyms {
+            genre.synonyms = synonyms;
+        }
+        genre.updated_at = chrono::Utc::now();
+
+        self.validate(&genre).await?;
+        let updated_genre = self.genre_repository.update(genre).await?;
+
+        Ok(GenreResponse::from(updated_genre))
+    }
+
+    /// Delete a leaf genre. Genres with children must be emptied first.
+    pub async fn delete(&self, admin_id: Uuid, genre_id: Uuid) -> Result<()> {
+        self.require_admin(admin_id).await?;
+
+        self.genre_repository
+            .find_by_id(genre_id)
+            .await?
+            .ok_or(DomainError::GenreNotFound)?;
+
+        if !self.genre_repository.find_children(genre_id).await?.is_empty() {
+            return Err(DomainError::InvalidInput("Genre still has child genres".to_string()).into());
+        }
+
+        self.genre_repository.delete(genre_id).await
+    }
+
+    /// Slugs, names and synonyms must resolve to a single genre, and the
+    /// parent must keep the tree acyclic
+    async fn validate(&self, genre: &Genre) -> Result<()> {
+        if genre.slug.is_empty() {
+            return Err(DomainError::InvalidInput("Genre slug cannot be empty".to_string()).into());
+        }
+
+        let others: Vec<Genre> = self.genre_repository
+            .find_all()
+            .await?
+            .into_iter()
+            .filter(|other| other.id != genre.id)
+            .collect();
+        let taxonomy = GenreTaxonomy::new(others.clone());
+
+        for key in std::iter::once(&genre.name)
+            .chain(std::iter::once(&genre.slug))
+            .chain(genre.synonyms.iter())
+        {
+            if let Some(existing) = taxonomy.resolve(key) {
+                return Err(DomainError::InvalidInput(format!(
+                    "\"{}\" already refers to genre \"{}\"",
+                    key, existing.name
+                )).into());
+            }
+        }
+
+        if let Some(parent_id) = genre.parent_id {
+            let mut all = others;
+            all.push(genre.clone());
+            GenreTaxonomy::new(all)
+                .validate_parent(genre.id, parent_id)
+                .map_err(|e| DomainError::InvalidInput(e.to_string()))?;
+        }
+
+        Ok(())
+    }
+
+    async fn require_admin(&self, user_id: Uuid) -> Result<User> {
+        let user = self.user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(DomainError::UserNotFound)?;
+
+        if !user.is_admin() {
+            return Err(DomainError::Unauthorized.into());
+        }
+
+        Ok(user)
+    }
+}
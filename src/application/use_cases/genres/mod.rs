@@ -0,0 +1,7 @@
+//! Genre taxonomy use cases module
+
+pub mod manage_genres;
+pub mod get_genre_tree;
+
+pub use manage_genres::*;
+pub use get_genre_tree::*;
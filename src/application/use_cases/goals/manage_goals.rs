@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use chrono::Utc;
+use chrono_tz::Tz;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::goal::{GoalMetric, GoalProgress, ReadingGoal},
+    repositories::{
+        book_repository::BookRepository,
+        recommendation_repository::RecommendationRepository,
+        shelf_repository::ShelfRepository,
+        goal_repository::GoalRepository,
+    },
+    services::{goal_service::GoalService, stats_service::parse_time_zone},
+    errors::domain_error::DomainError,
+};
+use crate::application::dtos::goal_dtos::{GoalResponse, UpsertGoalRequest};
+
+pub struct ManageGoalsUseCase<BR: BookRepository, RR: RecommendationRepository, SR: ShelfRepository, GR: GoalRepository> {
+    book_repository: BR,
+    recommendation_repository: RR,
+    shelf_repository: SR,
+    goal_repository: GR,
+    goal_service: GoalService,
+}
+
+impl<BR: BookRepository, RR: RecommendationRepository, SR: ShelfRepository, GR: GoalRepository> ManageGoalsUseCase<BR, RR, SR, GR> {
+    pub fn new(book_repository: BR, recommendation_repository: RR, shelf_repository: SR, goal_repository: GR) -> Self {
+        Self {
+            book_repository,
+            recommendation_repository,
+            shelf_repository,
+            goal_repository,
+            goal_service: GoalService::new(),
+        }
+    }
+
+    /// The user's goals with their progress, optionally for one year. Periods
+    /// follow the calendar of `tz`, like the reading stats.
+    pub async fn list(&self, user_id: Uuid, year: Option<i32>, tz: Option<&str>) -> Result<Vec<GoalResponse>> {
+        let time_zone = parse_time_zone(tz)?;
+        let goals = self.goal_repository.find_for_user(user_id, year).await?;
+
+        let mut responses = Vec::with_capacity(goals.len());
+        for goal in goals {
+            let progress = self.progress(&goal, time_zone).await?;
+            responses.push(GoalResponse::new(goal, progress));
+        }
+
+        Ok(responses)
+    }
+
+    /// Set the target for a metric and period, replacing the one already set
+    pub async fn upsert(&self, user_id: Uuid, request: UpsertGoalRequest, tz: Option<&str>) -> Result<GoalResponse> {
+        self.goal_service.check_goal(request.period, request.year, request.month, request.target)?;
+        let time_zone = parse_time_zone(tz)?;
+
+        let goal = ReadingGoal::new(user_id, request.metric, request.period, request.year, request.month, request.target);
+        let goal = match self.goal_repository
+            .find_for_user(user_id, Some(request.year))
+            .await?
+            .into_iter()
+            .find(|existing| existing.same_period(&goal))
+        {
+            Some(existing) => ReadingGoal {
+                target: goal.target,
+                updated_at: Utc::now(),
+                ..existing
+            },
+            None => goal,
+        };
+
+        let goal = self.goal_repository.save(goal).await?;
+        let progress = self.progress(&goal, time_zone).await?;
+        Ok(GoalResponse::new(goal, progress))
+    }
+
+    pub async fn delete(&self, user_id: Uuid, goal_id: Uuid) -> Result<()> {
+        self.goal_repository
+            .find_by_id(goal_id)
+            .await?
+            .filter(|goal| goal.user_id == user_id)
+            .ok_or(DomainError::GoalNotFound)?;
+
+        self.goal_repository.delete(goal_id).await
+    }
+
+    async fn progress(&self, goal: &ReadingGoal, time_zone: Tz) -> Result<GoalProgress> {
+        let now = Utc::now();
+        let Some((from, to)) = self.goal_service.period_range(goal, time_zone) else {
+            return Ok(self.goal_service.progress(goal, &[], &[], &HashMap::new(), time_zone, now));
+        };
+
+        let entries = self.shelf_repository
+            .find_finished_between(&[goal.user_id], from, to)
+            .await?;
+        let mut finished_books = Vec::with_capacity(entries.len());
+        for entry in entries {
+            if let Some(book) = self.book_repository.find_by_id(entry.book_id).await? {
+                finished_books.push(book);
+            }
+        }
+        let (sessions, logged_pages) = match goal.metric {
+            GoalMetric::Books => (Vec::new(), HashMap::new()),
+            GoalMetric::Pages => {
+                let sessions = self.recommendation_repository
+                    .get_reading_sessions_between(goal.user_id, from, to)
+                    .await?;
+                // Finished books only add the pages no session ever logged
+                let all_sessions = self.recommendation_repository
+                    .get_ended_reading_sessions(goal.user_id)
+                    .await?;
+                (sessions, GoalService::logged_pages(&all_sessions))
+            }
+        };
+
+        Ok(self.goal_service.progress(goal, &finished_books, &sessions, &logged_pages, time_zone, now))
+    }
+}
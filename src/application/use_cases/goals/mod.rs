@@ -0,0 +1,5 @@
+//! Reading goal use cases module
+
+pub mod manage_goals;
+
+pub use manage_goals::*;
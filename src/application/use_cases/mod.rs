@@ -6,7 +6,29 @@
 pub mod auth;
 pub mod books;
 pub mod recommendations;
+pub mod works;
+pub mod authors;
+pub mod genres;
+pub mod tags;
+pub mod admin;
+pub mod reviews;
+pub mod notifications;
+pub mod reading;
+pub mod shelves;
+pub mod goals;
+pub mod challenges;
 
 pub use auth::*;
 pub use books::*;
-pub use recommendations::*;
\ No newline at end of file
+pub use recommendations::*;
+pub use works::*;
+pub use authors::*;
+pub use genres::*;
+pub use tags::*;
+pub use admin::*;
+pub use reviews::*;
+pub use notifications::*;
+pub use reading::*;
+pub use shelves::*;
+pub use goals::*;
+pub use challenges::*;
\ No newline at end of file
@@ -0,0 +1,47 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    repositories::notification_repository::NotificationRepository,
+    errors::domain_error::DomainError,
+};
+use crate::application::dtos::notification_dtos::NotificationResponse;
+
+pub struct ManageNotificationsUseCase<NR: NotificationRepository> {
+    notification_repository: NR,
+}
+
+impl<NR: NotificationRepository> ManageNotificationsUseCase<NR> {
+    pub fn new(notification_repository: NR) -> Self {
+        Self { notification_repository }
+    }
+
+    /// The user's notifications, newest first
+    pub async fn list(
+        &self,
+        user_id: Uuid,
+        unread_only: bool,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<NotificationResponse>> {
+        let notifications = self.notification_repository
+            .find_for_user(user_id, unread_only, limit.map(|l| l.min(100)), offset)
+            .await?;
+        Ok(notifications.into_iter().map(NotificationResponse::from).collect())
+    }
+
+    pub async fn mark_read(&self, user_id: Uuid, notification_id: Uuid) -> Result<NotificationResponse> {
+        let mut notification = self.notification_repository
+            .find_by_id(notification_id)
+            .await?
+            .filter(|notification| notification.user_id == user_id)
+            .ok_or(DomainError::NotificationNotFound)?;
+
+        if notification.read_at.is_none() {
+            notification.read_at = Some(chrono::Utc::now());
+            notification = self.notification_repository.update(notification).await?;
+        }
+
+        Ok(NotificationResponse::from(notification))
+    }
+}
@@ -0,0 +1,5 @@
+//! User notification use cases module
+
+pub mod manage_notifications;
+
+pub use manage_notifications::*;
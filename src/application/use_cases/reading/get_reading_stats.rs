@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use chrono::{Datelike, Utc};
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{shelf::ShelfStatus, stats::ReadingStats},
+    repositories::{
+        book_repository::BookRepository,
+        recommendation_repository::RecommendationRepository,
+        shelf_repository::ShelfRepository,
+    },
+    services::stats_service::{parse_time_zone, FinishedBook, StatsService},
+};
+use crate::application::dtos::reading_dtos::ReadingStatsQuery;
+
+/// A reader's dashboard: finished books come from the read shelf, time and
+/// pages from reading sessions, and the rating distribution from their ratings
+pub struct GetReadingStatsUseCase<BR: BookRepository, RR: RecommendationRepository, SR: ShelfRepository> {
+    book_repository: BR,
+    recommendation_repository: RR,
+    shelf_repository: SR,
+    stats_service: StatsService,
+}
+
+impl<BR: BookRepository, RR: RecommendationRepository, SR: ShelfRepository> GetReadingStatsUseCase<BR, RR, SR> {
+    pub fn new(book_repository: BR, recommendation_repository: RR, shelf_repository: SR) -> Self {
+        Self {
+            book_repository,
+            recommendation_repository,
+            shelf_repository,
+            stats_service: StatsService::new(),
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, query: ReadingStatsQuery) -> Result<ReadingStats> {
+        let time_zone = parse_time_zone(query.tz.as_deref())?;
+        let now = Utc::now();
+        let year = query.year.unwrap_or_else(|| now.with_timezone(&time_zone).year());
+
+        let mut finished = Vec::new();
+        for entry in self.shelf_repository.find_all_entries(user_id).await? {
+            let (ShelfStatus::Read, Some(finished_at)) = (entry.status, entry.finished_at) else {
+                continue;
+            };
+            if let Some(book) = self.book_repository.find_by_id(entry.book_id).await? {
+                finished.push(FinishedBook { book, finished_at });
+            }
+        }
+        let sessions = self.recommendation_repository
+            .get_ended_reading_sessions(user_id)
+            .await?;
+        let ratings: HashMap<Uuid, f64> = self.book_repository
+            .get_user_ratings(user_id)
+            .await?
+            .into_iter()
+            .map(|rating| (rating.book_id, rating.rating))
+            .collect();
+
+        Ok(self.stats_service.compute(&finished, &sessions, &ratings, time_zone, year, now))
+    }
+}
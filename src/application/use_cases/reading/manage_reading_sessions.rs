@@ -0,0 +1,123 @@
+use anyhow::Result;
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        book::Book,
+        recommendation::{ReadingProgress, ReadingSession},
+    },
+    repositories::{
+        book_repository::BookRepository,
+        recommendation_repository::RecommendationRepository,
+    },
+    services::reading_service::ReadingService,
+    errors::domain_error::DomainError,
+};
+use crate::application::dtos::reading_dtos::{
+    StartReadingSessionRequest, EndReadingSessionRequest, ReadingSessionQuery, ReadingSessionResponse,
+};
+
+pub struct ManageReadingSessionsUseCase<BR: BookRepository, RR: RecommendationRepository> {
+    book_repository: BR,
+    recommendation_repository: RR,
+    reading_service: ReadingService,
+}
+
+impl<BR: BookRepository, RR: RecommendationRepository> ManageReadingSessionsUseCase<BR, RR> {
+    pub fn new(book_repository: BR, recommendation_repository: RR, reading_service: ReadingService) -> Self {
+        Self {
+            book_repository,
+            recommendation_repository,
+            reading_service,
+        }
+    }
+
+    /// Start reading a book, by default from where the last session ended.
+    /// Only one session per book can be open at a time.
+    pub async fn start(&self, user_id: Uuid, request: StartReadingSessionRequest) -> Result<ReadingSessionResponse> {
+        let book = self.book_repository
+            .find_by_id(request.book_id)
+            .await?
+            .ok_or(DomainError::BookNotFound)?;
+
+        let mut sessions = self.recommendation_repository
+            .get_book_reading_sessions(user_id, book.id)
+            .await?;
+        if sessions.iter().any(|session| session.is_active()) {
+            return Err(DomainError::ReadingSessionInProgress.into());
+        }
+
+        let start_page = match request.start_page {
+            Some(page) => page,
+            None => self.reading_service
+                .progress(&book, &sessions)
+                .map(|progress| progress.current_page)
+                .unwrap_or(0),
+        };
+        self.reading_service.check_page(&book, start_page)?;
+
+        let session = self.recommendation_repository
+            .start_reading_session(ReadingSession::new(user_id, book.id, start_page))
+            .await?;
+        sessions.push(session.clone());
+
+        Ok(ReadingSessionResponse {
+            progress: self.reading_service.progress(&book, &sessions),
+            ..ReadingSessionResponse::from(session)
+        })
+    }
+
+    pub async fn end(
+        &self,
+        user_id: Uuid,
+        session_id: Uuid,
+        request: EndReadingSessionRequest,
+    ) -> Result<ReadingSessionResponse> {
+        let mut session = self.recommendation_repository
+            .find_reading_session(session_id)
+            .await?
+            .filter(|session| session.user_id == user_id)
+            .ok_or(DomainError::ReadingSessionNotFound)?;
+        let book = self.book_repository
+            .find_by_id(session.book_id)
+            .await?
+            .ok_or(DomainError::BookNotFound)?;
+
+        self.reading_service.end_session(&mut session, &book, request.end_page, request.pages_read, Utc::now())?;
+        let session = self.recommendation_repository
+            .end_reading_session(session)
+            .await?
+            .ok_or(DomainError::ReadingSessionAlreadyEnded)?;
+
+        Ok(ReadingSessionResponse {
+            progress: self.progress(user_id, &book).await?,
+            ..ReadingSessionResponse::from(session)
+        })
+    }
+
+    /// The user's sessions, newest first, optionally on one book
+    pub async fn list(&self, user_id: Uuid, query: ReadingSessionQuery) -> Result<Vec<ReadingSessionResponse>> {
+        let limit = query.limit.unwrap_or(20).min(100);
+
+        let sessions = match query.book_id {
+            Some(book_id) => {
+                let mut sessions = self.recommendation_repository.get_book_reading_sessions(user_id, book_id).await?;
+                sessions.reverse();
+                sessions.truncate(limit);
+                sessions
+            }
+            None => self.recommendation_repository.get_user_reading_sessions(user_id, Some(limit)).await?,
+        };
+
+        Ok(sessions.into_iter().map(ReadingSessionResponse::from).collect())
+    }
+
+    /// The user's progress through the book; `None` if they never started it
+    pub async fn progress(&self, user_id: Uuid, book: &Book) -> Result<Option<ReadingProgress>> {
+        let sessions = self.recommendation_repository
+            .get_book_reading_sessions(user_id, book.id)
+            .await?;
+        Ok(self.reading_service.progress(book, &sessions))
+    }
+}
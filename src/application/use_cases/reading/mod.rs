@@ -0,0 +1,7 @@
+//! Reading session, progress and statistics use cases module
+
+pub mod manage_reading_sessions;
+pub mod get_reading_stats;
+
+pub use manage_reading_sessions::*;
+pub use get_reading_stats::*;
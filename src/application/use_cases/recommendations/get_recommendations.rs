@@ -2,34 +2,50 @@ use anyhow::Result;
 use uuid::Uuid;
 
 use crate::{
-    domain::repositories::{
-        user_repository::UserRepository,
-        book_repository::BookRepository,
+    domain::{
+        repositories::{
+            user_repository::UserRepository,
+            book_repository::BookRepository,
+            shelf_repository::ShelfRepository,
+        },
+        entities::shelf::ShelfStatus,
+        services::recommendation_service::RecommendationService,
     },
     infrastructure::external::ml_model::{MLModelService, RecommendationInput},
     application::dtos::recommendation_dtos::RecommendationResponse,
 };
 
-pub struct GetRecommendationsUseCase<UR: UserRepository, BR: BookRepository> {
+pub struct GetRecommendationsUseCase<UR: UserRepository, BR: BookRepository, SR: ShelfRepository> {
     user_repository: UR,
     book_repository: BR,
+    shelf_repository: SR,
     ml_service: MLModelService,
+    recommendation_service: RecommendationService,
 }
 
-impl<UR: UserRepository, BR: BookRepository> GetRecommendationsUseCase<UR, BR> {
+impl<UR: UserRepository, BR: BookRepository, SR: ShelfRepository> GetRecommendationsUseCase<UR, BR, SR> {
     pub fn new(
         user_repository: UR,
         book_repository: BR,
+        shelf_repository: SR,
         ml_service: MLModelService,
+        recommendation_service: RecommendationService,
     ) -> Self {
         Self {
             user_repository,
             book_repository,
+            shelf_repository,
             ml_service,
+            recommendation_service,
         }
     }
 
-    pub async fn execute(&self, user_id: Uuid, limit: Option<usize>) -> Result<RecommendationResponse> {
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        limit: Option<usize>,
+        preferred_locales: &[String],
+    ) -> Result<RecommendationResponse> {
         // Get user profile and preferences
         let user = self.user_repository.find_by_id(user_id).await?
             .ok_or_else(|| anyhow::anyhow!("User not found"))?;
@@ -38,6 +54,7 @@ impl<UR: UserRepository, BR: BookRepository> GetRecommendationsUseCase<UR, BR> {
         let user_ratings = self.book_repository.get_user_ratings(user_id).await?;
         let reading_history: Vec<Uuid> = user_ratings.iter().map(|r| r.book_id).collect();
         let ratings: Vec<f64> = user_ratings.iter().map(|r| r.rating).collect();
+        let reading_history_ids = reading_history.clone();
 
         // Prepare ML model input
         let ml_input = RecommendationInput {
@@ -50,32 +67,44 @@ impl<UR: UserRepository, BR: BookRepository> GetRecommendationsUseCase<UR, BR> {
         // Get recommendations from ML model
         let ml_output = self.ml_service.get_recommendations(ml_input)?;
 
+        // Works the user has read, is reading or gave up on, in any edition
+        let shelf_entries = self.shelf_repository.find_all_entries(user_id).await?;
+        let shelved_book_ids = shelf_entries
+            .iter()
+            .filter(|entry| entry.status != ShelfStatus::WantToRead)
+            .map(|entry| entry.book_id);
+        let mut read_work_ids = Vec::new();
+        for book_id in reading_history_ids.iter().copied().chain(shelved_book_ids) {
+            if let Some(work_id) = self.book_repository.find_by_id(book_id).await?.and_then(|b| b.work_id) {
+                read_work_ids.push(work_id);
+            }
+        }
+
         // Fetch book details for recommended books
-        let mut recommended_books = Vec::new();
-        let limit = limit.unwrap_or(10).min(ml_output.book_ids.len());
-        
-        for i in 0..limit {
-            if let Some(book) = self.book_repository.find_by_id(ml_output.book_ids[i]).await? {
-                recommended_books.push(crate::application::dtos::book_dtos::BookResponse {
-                    id: book.id.to_string(),
-                    title: book.title,
-                    author: book.author,
-                    isbn: book.isbn,
-                    description: book.description,
-                    genre: book.genre,
-                    tags: book.tags,
-                    publication_year: book.publication_year,
-                    publisher: book.publisher,
-                    language: book.language,
-                    page_count: book.page_count,
-                    cover_image_url: book.cover_image_url,
-                    average_rating: book.average_rating,
-                    ratings_count: book.ratings_count,
-                    created_at: book.created_at.to_rfc3339(),
-                });
+        let mut candidate_books = Vec::new();
+        for book_id in &ml_output.book_ids {
+            if let Some(book) = self.book_repository.find_by_id(*book_id).await? {
+                candidate_books.push(book);
             }
         }
 
+        // Recommend each work once, whichever edition the model ranked highest,
+        // books the user wants to read first
+        let limit = limit.unwrap_or(10);
+        let candidate_books = self.recommendation_service.collapse_editions(candidate_books, &read_work_ids);
+        let recommended_books = self.recommendation_service
+            .apply_shelves(candidate_books, &shelf_entries)
+            .into_iter()
+            .take(limit)
+            .map(|book| {
+                crate::application::dtos::book_dtos::BookResponse::localized(
+                    book,
+                    preferred_locales,
+                    self.recommendation_service.rating_prior(),
+                )
+            })
+            .collect();
+
         Ok(RecommendationResponse {
             recommendations: recommended_books,
             confidence: ml_output.confidence,
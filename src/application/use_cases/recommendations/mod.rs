@@ -1,5 +1,7 @@
 //! Recommendation use cases module
 
 pub mod get_recommendations;
+pub mod update_preferences;
 
-pub use get_recommendations::*;
\ No newline at end of file
+pub use get_recommendations::*;
+pub use update_preferences::*;
\ No newline at end of file
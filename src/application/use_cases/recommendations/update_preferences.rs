@@ -0,0 +1,32 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    repositories::{user_repository::UserRepository, genre_repository::GenreRepository},
+    services::genre_taxonomy::GenreTaxonomy,
+};
+
+pub struct UpdatePreferencesUseCase<UR: UserRepository, GR: GenreRepository> {
+    user_repository: UR,
+    genre_repository: GR,
+}
+
+impl<UR: UserRepository, GR: GenreRepository> UpdatePreferencesUseCase<UR, GR> {
+    pub fn new(user_repository: UR, genre_repository: GR) -> Self {
+        Self {
+            user_repository,
+            genre_repository,
+        }
+    }
+
+    /// Store the user's preferred genres under their canonical names and
+    /// return what was stored
+    pub async fn execute(&self, user_id: Uuid, preferences: Vec<String>) -> Result<Vec<String>> {
+        let taxonomy = GenreTaxonomy::new(self.genre_repository.find_all().await?);
+        let preferences = taxonomy.canonicalize(&preferences);
+
+        self.user_repository.update_preferences(user_id, preferences.clone()).await?;
+
+        Ok(preferences)
+    }
+}
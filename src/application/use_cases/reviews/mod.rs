@@ -0,0 +1,11 @@
+//! Review moderation, helpfulness and abuse report use cases module
+
+pub mod screen_review;
+pub mod vote_review;
+pub mod report_review;
+pub mod moderate_reviews;
+
+pub use screen_review::*;
+pub use vote_review::*;
+pub use report_review::*;
+pub use moderate_reviews::*;
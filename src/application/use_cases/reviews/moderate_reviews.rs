@@ -0,0 +1,171 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{book::BookRating, review::{ReviewStatus, ReportStatus}, user::User},
+    repositories::{
+        user_repository::UserRepository,
+        book_repository::BookRepository,
+        review_repository::ReviewRepository,
+        notification_repository::NotificationRepository,
+    },
+    errors::domain_error::DomainError,
+};
+use crate::application::{
+    dtos::{
+        book_dtos::BookRatingResponse,
+        review_dtos::{ReportedReviewResponse, ReviewReportResponse, RejectReviewRequest},
+    },
+    use_cases::reviews::screen_review::notify_review_rejected,
+};
+
+/// Curator queues of reviews held by the moderation filters and of reported reviews
+pub struct ModerateReviewsUseCase<UR: UserRepository, BR: BookRepository, RR: ReviewRepository, NR: NotificationRepository> {
+    user_repository: UR,
+    book_repository: BR,
+    review_repository: RR,
+    notification_repository: NR,
+}
+
+impl<UR: UserRepository, BR: BookRepository, RR: ReviewRepository, NR: NotificationRepository> ModerateReviewsUseCase<UR, BR, RR, NR> {
+    pub fn new(user_repository: UR, book_repository: BR, review_repository: RR, notification_repository: NR) -> Self {
+        Self {
+            user_repository,
+            book_repository,
+            review_repository,
+            notification_repository,
+        }
+    }
+
+    /// Reviews held by the moderation filters, oldest first
+    pub async fn list_pending(
+        &self,
+        curator_id: Uuid,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<BookRatingResponse>> {
+        self.require_curator(curator_id).await?;
+
+        let pending = self.review_repository.find_pending_reviews(limit, offset).await?;
+        Ok(pending.into_iter().map(BookRatingResponse::from).collect())
+    }
+
+    /// Publish a held or rejected review
+    pub async fn approve(&self, curator_id: Uuid, rating_id: Uuid) -> Result<BookRatingResponse> {
+        self.require_curator(curator_id).await?;
+
+        let mut rating = self.find_review(rating_id).await?;
+        if !matches!(rating.status, ReviewStatus::Pending | ReviewStatus::Rejected) {
+            return Err(DomainError::InvalidInput("Only held or rejected reviews can be approved".to_string()).into());
+        }
+
+        rating.status = ReviewStatus::Published;
+        rating.moderation_reasons.clear();
+        let rating = self.book_repository.update_rating(rating).await?;
+
+        Ok(BookRatingResponse::from(rating))
+    }
+
+    /// Turn down a held review and tell the author why
+    pub async fn reject(&self, curator_id: Uuid, rating_id: Uuid, request: RejectReviewRequest) -> Result<BookRatingResponse> {
+        self.require_curator(curator_id).await?;
+
+        let mut rating = self.find_review(rating_id).await?;
+        if rating.status != ReviewStatus::Pending {
+            return Err(DomainError::InvalidInput("Only held reviews can be rejected".to_string()).into());
+        }
+
+        rating.status = ReviewStatus::Rejected;
+        if let Some(reason) = request.reason {
+            rating.moderation_reasons = vec![reason];
+        }
+        let rating = self.book_repository.update_rating(rating).await?;
+        notify_review_rejected(&self.notification_repository, &rating, &rating.moderation_reasons).await?;
+
+        Ok(BookRatingResponse::from(rating))
+    }
+
+    /// Reviews with open reports, most reported first
+    pub async fn list_reported(
+        &self,
+        curator_id: Uuid,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<ReportedReviewResponse>> {
+        self.require_curator(curator_id).await?;
+
+        let mut queue = Vec::new();
+        for rating in self.review_repository.find_reported_reviews(limit, offset).await? {
+            let reports = self.review_repository.find_open_reports(rating.id).await?;
+            queue.push(ReportedReviewResponse {
+                review: BookRatingResponse::from(rating),
+                reports: reports.into_iter().map(ReviewReportResponse::from).collect(),
+            });
+        }
+
+        Ok(queue)
+    }
+
+    /// Reports were unfounded: close them and publish the review again if
+    /// the reports had hidden it. Reviews in any other state keep it.
+    pub async fn dismiss(&self, curator_id: Uuid, rating_id: Uuid) -> Result<BookRatingResponse> {
+        self.decide(curator_id, rating_id, ReportStatus::Dismissed, ReviewStatus::Published).await
+    }
+
+    /// Reports were justified: close them and take the review down. The
+    /// rating keeps counting towards the book's average.
+    pub async fn remove(&self, curator_id: Uuid, rating_id: Uuid) -> Result<BookRatingResponse> {
+        self.decide(curator_id, rating_id, ReportStatus::Upheld, ReviewStatus::Removed).await
+    }
+
+    async fn decide(
+        &self,
+        curator_id: Uuid,
+        rating_id: Uuid,
+        report_status: ReportStatus,
+        review_status: ReviewStatus,
+    ) -> Result<BookRatingResponse> {
+        self.require_curator(curator_id).await?;
+        self.find_review(rating_id).await?;
+
+        self.review_repository.resolve_reports(rating_id, report_status, curator_id).await?;
+        let mut rating = self.review_repository.update_review_report_count(rating_id).await?;
+        let status_changes = match review_status {
+            // Only undo the hiding the reports caused, never publish a held or rejected review
+            ReviewStatus::Published => rating.status == ReviewStatus::Hidden,
+            _ => rating.status != review_status,
+        };
+        if status_changes {
+            rating.status = review_status;
+            rating = self.book_repository.update_rating(rating).await?;
+        }
+
+        if status_changes && review_status == ReviewStatus::Removed {
+            let reasons = vec!["Removed after reports from other readers".to_string()];
+            notify_review_rejected(&self.notification_repository, &rating, &reasons).await?;
+        }
+
+        Ok(BookRatingResponse::from(rating))
+    }
+
+    async fn find_review(&self, rating_id: Uuid) -> Result<BookRating> {
+        self.book_repository
+            .find_rating(rating_id)
+            .await?
+            .filter(|rating| rating.review.is_some())
+            .ok_or_else(|| DomainError::ReviewNotFound.into())
+    }
+
+    async fn require_curator(&self, user_id: Uuid) -> Result<User> {
+        let user = self.user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(DomainError::UserNotFound)?;
+
+        if !user.is_curator() {
+            return Err(DomainError::Unauthorized.into());
+        }
+
+        Ok(user)
+    }
+}
@@ -0,0 +1,58 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::review::{ReviewReport, ReviewStatus},
+    repositories::{book_repository::BookRepository, review_repository::ReviewRepository},
+    services::review_service::ReviewService,
+    errors::domain_error::DomainError,
+};
+use crate::application::dtos::review_dtos::{ReportReviewRequest, ReviewReportResponse};
+
+pub struct ReportReviewUseCase<BR: BookRepository, RR: ReviewRepository> {
+    book_repository: BR,
+    review_repository: RR,
+    review_service: ReviewService,
+}
+
+impl<BR: BookRepository, RR: ReviewRepository> ReportReviewUseCase<BR, RR> {
+    pub fn new(book_repository: BR, review_repository: RR, review_service: ReviewService) -> Self {
+        Self {
+            book_repository,
+            review_repository,
+            review_service,
+        }
+    }
+
+    /// Report a review as abusive. Once enough reports are open the review is
+    /// hidden until a moderator upholds or dismisses them.
+    pub async fn execute(
+        &self,
+        rating_id: Uuid,
+        user_id: Uuid,
+        request: ReportReviewRequest,
+    ) -> Result<ReviewReportResponse> {
+        let rating = self.book_repository
+            .find_rating(rating_id)
+            .await?
+            .ok_or(DomainError::ReviewNotFound)?;
+
+        self.review_service.check_can_respond(&rating, user_id)?;
+
+        if self.review_repository.find_report(rating_id, user_id).await?.is_some() {
+            return Err(DomainError::InvalidInput("You have already reported this review".to_string()).into());
+        }
+
+        let report = self.review_repository
+            .save_report(ReviewReport::new(rating_id, user_id, request.reason, request.details))
+            .await?;
+
+        let mut rating = self.review_repository.update_review_report_count(rating_id).await?;
+        if self.review_service.should_hide(&rating) {
+            rating.status = ReviewStatus::Hidden;
+            self.book_repository.update_rating(rating).await?;
+        }
+
+        Ok(ReviewReportResponse::from(report))
+    }
+}
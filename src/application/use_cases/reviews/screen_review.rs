@@ -0,0 +1,70 @@
+use anyhow::Result;
+
+use crate::domain::{
+    entities::{
+        book::BookRating,
+        notification::{Notification, NotificationKind},
+        review::ReviewStatus,
+    },
+    repositories::{book_repository::BookRepository, notification_repository::NotificationRepository},
+    services::review_moderation_service::{ReviewModerationService, ReviewContext, ModerationVerdict},
+};
+
+/// Run a new or edited review through the moderation filters and set its
+/// status. Reviews hidden or removed over abuse reports stay with moderators.
+pub async fn screen_review<BR: BookRepository>(
+    book_repository: &BR,
+    moderation: &ReviewModerationService,
+    rating: &mut BookRating,
+) -> Result<()> {
+    if matches!(rating.status, ReviewStatus::Hidden | ReviewStatus::Removed) {
+        return Ok(());
+    }
+
+    let Some(text) = rating.review.as_deref() else {
+        rating.status = ReviewStatus::Published;
+        rating.moderation_reasons.clear();
+        return Ok(());
+    };
+
+    let previous_reviews: Vec<String> = book_repository
+        .get_user_ratings(rating.user_id)
+        .await?
+        .into_iter()
+        .filter(|other| other.id != rating.id)
+        .filter_map(|other| other.review)
+        .collect();
+
+    let decision = moderation.moderate(&ReviewContext {
+        text,
+        previous_reviews: &previous_reviews,
+    });
+
+    rating.status = match decision.verdict {
+        ModerationVerdict::Approve => ReviewStatus::Published,
+        ModerationVerdict::Hold => ReviewStatus::Pending,
+        ModerationVerdict::Reject => ReviewStatus::Rejected,
+    };
+    rating.moderation_reasons = decision.reasons;
+
+    Ok(())
+}
+
+/// Tell the author their review was turned down and why
+pub async fn notify_review_rejected<NR: NotificationRepository>(
+    notification_repository: &NR,
+    rating: &BookRating,
+    reasons: &[String],
+) -> Result<()> {
+    let message = if reasons.is_empty() {
+        "Your review was not published because it breaks the review guidelines".to_string()
+    } else {
+        format!("Your review was not published: {}", reasons.join("; "))
+    };
+
+    notification_repository
+        .save(Notification::new(rating.user_id, NotificationKind::ReviewRejected, message, Some(rating.book_id)))
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::review::ReviewVote,
+    repositories::{book_repository::BookRepository, review_repository::ReviewRepository},
+    services::review_service::ReviewService,
+    errors::domain_error::DomainError,
+};
+use crate::application::dtos::{book_dtos::BookRatingResponse, review_dtos::VoteReviewRequest};
+
+pub struct VoteReviewUseCase<BR: BookRepository, RR: ReviewRepository> {
+    book_repository: BR,
+    review_repository: RR,
+    review_service: ReviewService,
+}
+
+impl<BR: BookRepository, RR: ReviewRepository> VoteReviewUseCase<BR, RR> {
+    pub fn new(book_repository: BR, review_repository: RR, review_service: ReviewService) -> Self {
+        Self {
+            book_repository,
+            review_repository,
+            review_service,
+        }
+    }
+
+    /// Record whether a user found a review helpful (one vote per user,
+    /// `None` withdraws it) and re-rank the review
+    pub async fn execute(
+        &self,
+        rating_id: Uuid,
+        user_id: Uuid,
+        request: VoteReviewRequest,
+    ) -> Result<BookRatingResponse> {
+        let rating = self.book_repository
+            .find_rating(rating_id)
+            .await?
+            .ok_or(DomainError::ReviewNotFound)?;
+
+        self.review_service.check_can_respond(&rating, user_id)?;
+
+        match (self.review_repository.find_vote(rating_id, user_id).await?, request.helpful) {
+            (Some(vote), None) => self.review_repository.delete_vote(vote.id).await?,
+            (Some(vote), Some(helpful)) if vote.helpful == helpful => return Ok(BookRatingResponse::from(rating)),
+            (Some(mut vote), Some(helpful)) => {
+                vote.helpful = helpful;
+                vote.updated_at = chrono::Utc::now();
+                self.review_repository.save_vote(vote).await?;
+            }
+            (None, None) => return Ok(BookRatingResponse::from(rating)),
+            (None, Some(helpful)) => {
+                self.review_repository.save_vote(ReviewVote::new(rating_id, user_id, helpful)).await?;
+            }
+        }
+
+        let rating = self.review_repository.update_review_vote_counts(rating_id).await?;
+
+        Ok(BookRatingResponse::from(rating))
+    }
+}
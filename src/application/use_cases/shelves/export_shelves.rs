@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::repositories::{
+    book_repository::BookRepository,
+    shelf_repository::ShelfRepository,
+};
+use crate::application::dtos::shelf_dtos::ShelfExportRow;
+
+const CSV_HEADER: &str = "Book Id,Title,Author,ISBN,Status,Shelves,My Rating,Date Started,Date Finished,Date Added";
+
+/// Everything on a user's shelves, for download or moving to another service
+pub struct ExportShelvesUseCase<BR: BookRepository, SR: ShelfRepository> {
+    book_repository: BR,
+    shelf_repository: SR,
+}
+
+impl<BR: BookRepository, SR: ShelfRepository> ExportShelvesUseCase<BR, SR> {
+    pub fn new(book_repository: BR, shelf_repository: SR) -> Self {
+        Self {
+            book_repository,
+            shelf_repository,
+        }
+    }
+
+    /// One row per shelved book, oldest first, with the user's rating if any
+    pub async fn execute(&self, user_id: Uuid) -> Result<Vec<ShelfExportRow>> {
+        let entries = self.shelf_repository.find_all_entries(user_id).await?;
+        let shelf_names: HashMap<Uuid, String> = self.shelf_repository
+            .find_shelves_for_user(user_id)
+            .await?
+            .into_iter()
+            .map(|shelf| (shelf.id, shelf.name))
+            .collect();
+        let ratings: HashMap<Uuid, f64> = self.book_repository
+            .get_user_ratings(user_id)
+            .await?
+            .into_iter()
+            .map(|rating| (rating.book_id, rating.rating))
+            .collect();
+
+        let mut rows = Vec::new();
+        for entry in entries {
+            let Some(book) = self.book_repository.find_by_id(entry.book_id).await? else {
+                continue;
+            };
+
+            rows.push(ShelfExportRow {
+                book_id: book.id.to_string(),
+                title: book.title,
+                author: book.author,
+                isbn: book.isbn,
+                status: entry.status,
+                shelves: entry.shelf_ids.iter().filter_map(|id| shelf_names.get(id).cloned()).collect(),
+                my_rating: ratings.get(&book.id).copied(),
+                started_at: entry.started_at,
+                finished_at: entry.finished_at,
+                added_at: entry.added_at,
+            });
+        }
+
+        Ok(rows)
+    }
+}
+
+/// Rows as CSV, custom shelves separated by "; " and dates as YYYY-MM-DD
+pub fn shelves_to_csv(rows: &[ShelfExportRow]) -> String {
+    let date = |value: Option<chrono::DateTime<chrono::Utc>>| {
+        value.map(|value| value.format("%Y-%m-%d").to_string()).unwrap_or_default()
+    };
+
+    let mut csv = String::from(CSV_HEADER);
+    csv.push_str("\r\n");
+    for row in rows {
+        let fields = [
+            row.book_id.clone(),
+            row.title.clone(),
+            row.author.clone(),
+            row.isbn.clone().unwrap_or_default(),
+            row.status.as_str().to_string(),
+            row.shelves.join("; "),
+            row.my_rating.map(|rating| rating.to_string()).unwrap_or_default(),
+            date(row.started_at),
+            date(row.finished_at),
+            date(Some(row.added_at)),
+        ];
+        let line: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+        csv.push_str(&line.join(","));
+        csv.push_str("\r\n");
+    }
+
+    csv
+}
+
+/// Quote fields containing separators, quotes or line breaks (RFC 4180).
+/// Fields a spreadsheet would run as a formula, such as a title starting
+/// with "=", are prefixed with an apostrophe so they open as text.
+fn csv_field(value: &str) -> String {
+    let value = if value.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    };
+
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_field_quotes_and_neutralizes_formulas() {
+        assert_eq!(csv_field("Dune"), "Dune");
+        assert_eq!(csv_field("Herbert, Frank"), "\"Herbert, Frank\"");
+        assert_eq!(csv_field("The \"Best\" Book"), "\"The \"\"Best\"\" Book\"");
+        assert_eq!(csv_field("=HYPERLINK(\"http://x\")"), "\"'=HYPERLINK(\"\"http://x\"\")\"");
+        assert_eq!(csv_field("+1"), "'+1");
+        assert_eq!(csv_field("-2"), "'-2");
+        assert_eq!(csv_field("@SUM(A1)"), "'@SUM(A1)");
+        assert_eq!(csv_field("\tcmd"), "'\tcmd");
+        assert_eq!(csv_field("\rcmd"), "\"'\rcmd\"");
+    }
+}
@@ -0,0 +1,153 @@
+use anyhow::Result;
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::shelf::{Shelf, ShelfEntry, ShelfStatus, ShelfStatusChange},
+    repositories::{
+        book_repository::BookRepository,
+        shelf_repository::ShelfRepository,
+    },
+    services::{rating_service::RatingPrior, shelf_service::ShelfService},
+    errors::domain_error::DomainError,
+};
+use crate::application::dtos::{
+    book_dtos::BookResponse,
+    shelf_dtos::{ShelfEntryResponse, ShelfQuery, ShelfResponse, ShelveBookRequest},
+};
+
+pub struct ManageShelvesUseCase<BR: BookRepository, SR: ShelfRepository> {
+    book_repository: BR,
+    shelf_repository: SR,
+    shelf_service: ShelfService,
+    rating_prior: RatingPrior,
+}
+
+impl<BR: BookRepository, SR: ShelfRepository> ManageShelvesUseCase<BR, SR> {
+    pub fn new(book_repository: BR, shelf_repository: SR, rating_prior: RatingPrior) -> Self {
+        Self {
+            book_repository,
+            shelf_repository,
+            shelf_service: ShelfService::new(),
+            rating_prior,
+        }
+    }
+
+    /// The standard shelves followed by the user's custom shelves, with book counts
+    pub async fn list_shelves(&self, user_id: Uuid) -> Result<Vec<ShelfResponse>> {
+        let entries = self.shelf_repository.find_all_entries(user_id).await?;
+        let shelves = self.shelf_repository.find_shelves_for_user(user_id).await?;
+
+        let standard = ShelfStatus::ALL.into_iter().map(|status| ShelfResponse {
+            id: None,
+            name: status.as_str().to_string(),
+            status: Some(status),
+            book_count: entries.iter().filter(|entry| entry.status == status).count(),
+        });
+        let custom = shelves.into_iter().map(|shelf| {
+            let book_count = entries.iter().filter(|entry| entry.shelf_ids.contains(&shelf.id)).count();
+            ShelfResponse::custom(shelf, book_count)
+        });
+
+        Ok(standard.chain(custom).collect())
+    }
+
+    pub async fn create_shelf(&self, user_id: Uuid, name: &str) -> Result<ShelfResponse> {
+        let existing = self.shelf_repository.find_shelves_for_user(user_id).await?;
+        let name = self.shelf_service.check_shelf_name(name, &existing)?;
+
+        let shelf = self.shelf_repository
+            .create_shelf(Shelf::new(user_id, name))
+            .await?;
+        Ok(ShelfResponse::custom(shelf, 0))
+    }
+
+    /// Books on the shelf keep their standard shelf and other custom shelves
+    pub async fn delete_shelf(&self, user_id: Uuid, shelf_id: Uuid) -> Result<()> {
+        self.shelf_repository
+            .find_shelf(shelf_id)
+            .await?
+            .filter(|shelf| shelf.user_id == user_id)
+            .ok_or(DomainError::ShelfNotFound)?;
+
+        self.shelf_repository.delete_shelf(shelf_id).await
+    }
+
+    pub async fn shelve_book(
+        &self,
+        user_id: Uuid,
+        book_id: Uuid,
+        request: ShelveBookRequest,
+        preferred_locales: &[String],
+    ) -> Result<ShelfEntryResponse> {
+        let book = self.book_repository
+            .find_by_id(book_id)
+            .await?
+            .ok_or(DomainError::BookNotFound)?;
+
+        let existing = self.shelf_repository.find_entry(user_id, book_id).await?;
+        let (mut entry, change) = self.shelf_service.shelve(
+            existing,
+            user_id,
+            book_id,
+            request.status,
+            request.started_at,
+            request.finished_at,
+            Utc::now(),
+        )?;
+
+        if let Some(mut shelf_ids) = request.shelf_ids {
+            shelf_ids.sort();
+            shelf_ids.dedup();
+            let shelves = self.shelf_repository.find_shelves_for_user(user_id).await?;
+            if !shelf_ids.iter().all(|id| shelves.iter().any(|shelf| shelf.id == *id)) {
+                return Err(DomainError::ShelfNotFound.into());
+            }
+            entry.shelf_ids = shelf_ids;
+        }
+
+        let entry = self.shelf_repository.save_entry(entry, change).await?;
+        Ok(ShelfEntryResponse::new(entry, BookResponse::localized(book, preferred_locales, &self.rating_prior)))
+    }
+
+    /// Take a book off all shelves; the removal is kept in its history
+    pub async fn unshelve_book(&self, user_id: Uuid, book_id: Uuid) -> Result<()> {
+        let entry = self.shelf_repository
+            .find_entry(user_id, book_id)
+            .await?
+            .ok_or(DomainError::ShelfEntryNotFound)?;
+
+        let change = ShelfStatusChange::new(user_id, book_id, Some(entry.status), None);
+        self.shelf_repository.delete_entry(entry, change).await
+    }
+
+    /// Shelved books, most recently updated first; deleted books are skipped
+    pub async fn list_books(
+        &self,
+        user_id: Uuid,
+        query: ShelfQuery,
+        preferred_locales: &[String],
+    ) -> Result<Vec<ShelfEntryResponse>> {
+        let entries = self.shelf_repository
+            .find_entries(user_id, query.status, query.shelf_id, query.limit.map(|l| l.min(100)), query.offset)
+            .await?;
+
+        self.with_books(entries, preferred_locales).await
+    }
+
+    /// When the book moved between shelves, oldest first
+    pub async fn history(&self, user_id: Uuid, book_id: Uuid) -> Result<Vec<ShelfStatusChange>> {
+        self.shelf_repository.find_status_changes(user_id, book_id).await
+    }
+
+    async fn with_books(&self, entries: Vec<ShelfEntry>, preferred_locales: &[String]) -> Result<Vec<ShelfEntryResponse>> {
+        let mut responses = Vec::new();
+        for entry in entries {
+            if let Some(book) = self.book_repository.find_by_id(entry.book_id).await? {
+                responses.push(ShelfEntryResponse::new(entry, BookResponse::localized(book, preferred_locales, &self.rating_prior)));
+            }
+        }
+
+        Ok(responses)
+    }
+}
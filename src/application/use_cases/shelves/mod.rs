@@ -0,0 +1,7 @@
+//! Reading shelf use cases module
+
+pub mod manage_shelves;
+pub mod export_shelves;
+
+pub use manage_shelves::*;
+pub use export_shelves::*;
@@ -0,0 +1,48 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::tag::TagStatus,
+    repositories::{book_repository::BookRepository, tag_repository::TagRepository},
+    services::tag_service::TagService,
+    errors::domain_error::DomainError,
+};
+use crate::application::dtos::tag_dtos::BookTagResponse;
+
+pub struct GetBookTagsUseCase<BR: BookRepository, TR: TagRepository> {
+    book_repository: BR,
+    tag_repository: TR,
+    tag_service: TagService,
+}
+
+impl<BR: BookRepository, TR: TagRepository> GetBookTagsUseCase<BR, TR> {
+    pub fn new(book_repository: BR, tag_repository: TR, tag_service: TagService) -> Self {
+        Self {
+            book_repository,
+            tag_repository,
+            tag_service,
+        }
+    }
+
+    /// Approved tags with a positive score, best ranked first, with weights
+    pub async fn execute(&self, book_id: Uuid) -> Result<Vec<BookTagResponse>> {
+        self.book_repository
+            .find_by_id(book_id)
+            .await?
+            .ok_or(DomainError::BookNotFound)?;
+
+        let approved = self.tag_repository
+            .find_tags_for_book(book_id, Some(TagStatus::Approved))
+            .await?;
+        let weights = self.tag_service.tag_weights(&approved);
+
+        Ok(self.tag_service
+            .top_tags(&approved, usize::MAX)
+            .into_iter()
+            .map(|tag| {
+                let weight = weights.get(&tag.tag).copied().unwrap_or(0.0);
+                BookTagResponse::new(tag.clone(), weight)
+            })
+            .collect())
+    }
+}
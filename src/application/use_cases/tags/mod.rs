@@ -0,0 +1,13 @@
+//! User-contributed tag use cases module
+
+pub mod refresh_book_tags;
+pub mod suggest_tag;
+pub mod vote_tag;
+pub mod get_book_tags;
+pub mod moderate_tags;
+
+pub use refresh_book_tags::*;
+pub use suggest_tag::*;
+pub use vote_tag::*;
+pub use get_book_tags::*;
+pub use moderate_tags::*;
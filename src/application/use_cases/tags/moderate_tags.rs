@@ -0,0 +1,144 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{tag::{BlockedTag, TagStatus}, user::User},
+    repositories::{
+        user_repository::UserRepository,
+        book_repository::BookRepository,
+        tag_repository::TagRepository,
+    },
+    services::tag_service::TagService,
+    errors::domain_error::DomainError,
+};
+use crate::application::{
+    dtos::tag_dtos::{RejectTagRequest, BlockTagRequest, BookTagResponse, BlockedTagResponse},
+    use_cases::tags::refresh_book_tags::refresh_book_tags,
+};
+
+/// Curator moderation queue and tag blocklist
+pub struct ModerateTagsUseCase<UR: UserRepository, BR: BookRepository, TR: TagRepository> {
+    user_repository: UR,
+    book_repository: BR,
+    tag_repository: TR,
+    tag_service: TagService,
+}
+
+impl<UR: UserRepository, BR: BookRepository, TR: TagRepository> ModerateTagsUseCase<UR, BR, TR> {
+    pub fn new(user_repository: UR, book_repository: BR, tag_repository: TR, tag_service: TagService) -> Self {
+        Self {
+            user_repository,
+            book_repository,
+            tag_repository,
+            tag_service,
+        }
+    }
+
+    pub async fn list_pending(
+        &self,
+        curator_id: Uuid,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<BookTagResponse>> {
+        self.require_curator(curator_id).await?;
+
+        let pending = self.tag_repository.find_pending_tags(limit, offset).await?;
+        Ok(pending.into_iter().map(|tag| BookTagResponse::new(tag, 0.0)).collect())
+    }
+
+    pub async fn approve(&self, curator_id: Uuid, tag_id: Uuid) -> Result<BookTagResponse> {
+        self.review(curator_id, tag_id, TagStatus::Approved).await
+    }
+
+    pub async fn reject(&self, curator_id: Uuid, tag_id: Uuid, request: RejectTagRequest) -> Result<BookTagResponse> {
+        let rejected = self.review(curator_id, tag_id, TagStatus::Rejected).await?;
+
+        if request.block {
+            self.block_tag(curator_id, &rejected.tag, request.reason).await?;
+        }
+
+        Ok(rejected)
+    }
+
+    pub async fn list_blocked(&self, curator_id: Uuid) -> Result<Vec<BlockedTagResponse>> {
+        self.require_curator(curator_id).await?;
+
+        let blocked = self.tag_repository.find_blocked_tags().await?;
+        Ok(blocked.into_iter().map(BlockedTagResponse::from).collect())
+    }
+
+    pub async fn block(&self, curator_id: Uuid, request: BlockTagRequest) -> Result<BlockedTagResponse> {
+        self.require_curator(curator_id).await?;
+
+        let tag_name = TagService::normalize_tag(&request.tag)
+            .ok_or_else(|| DomainError::InvalidInput("Tag must contain letters or digits".to_string()))?;
+
+        self.block_tag(curator_id, &tag_name, request.reason).await
+    }
+
+    pub async fn unblock(&self, curator_id: Uuid, tag: &str) -> Result<()> {
+        self.require_curator(curator_id).await?;
+
+        let tag_name = TagService::normalize_tag(tag).unwrap_or_default();
+        self.tag_repository.delete_blocked_tag(&tag_name).await
+    }
+
+    /// Add a tag to the blocklist and reject it on every book that has it
+    async fn block_tag(&self, curator_id: Uuid, tag_name: &str, reason: Option<String>) -> Result<BlockedTagResponse> {
+        let blocked = match self.tag_repository.find_blocked_tag(tag_name).await? {
+            Some(existing) => existing,
+            None => self.tag_repository
+                .save_blocked_tag(BlockedTag::new(tag_name.to_string(), reason, curator_id))
+                .await?,
+        };
+
+        for mut tag in self.tag_repository.find_tags_by_name(tag_name).await? {
+            if tag.status == TagStatus::Rejected {
+                continue;
+            }
+
+            let was_approved = tag.status == TagStatus::Approved;
+            tag.review(TagStatus::Rejected, curator_id);
+            let book_id = tag.book_id;
+            self.tag_repository.update_tag(tag).await?;
+
+            if was_approved {
+                refresh_book_tags(&self.book_repository, &self.tag_repository, &self.tag_service, book_id).await?;
+            }
+        }
+
+        Ok(BlockedTagResponse::from(blocked))
+    }
+
+    async fn review(&self, curator_id: Uuid, tag_id: Uuid, status: TagStatus) -> Result<BookTagResponse> {
+        self.require_curator(curator_id).await?;
+
+        let mut tag = self.tag_repository
+            .find_tag(tag_id)
+            .await?
+            .ok_or(DomainError::TagNotFound)?;
+
+        let was_approved = tag.status == TagStatus::Approved;
+        tag.review(status, curator_id);
+        let tag = self.tag_repository.update_tag(tag).await?;
+
+        if was_approved || status == TagStatus::Approved {
+            refresh_book_tags(&self.book_repository, &self.tag_repository, &self.tag_service, tag.book_id).await?;
+        }
+
+        Ok(BookTagResponse::new(tag, 0.0))
+    }
+
+    async fn require_curator(&self, user_id: Uuid) -> Result<User> {
+        let user = self.user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(DomainError::UserNotFound)?;
+
+        if !user.is_curator() {
+            return Err(DomainError::Unauthorized.into());
+        }
+
+        Ok(user)
+    }
+}
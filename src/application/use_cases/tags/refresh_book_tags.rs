@@ -0,0 +1,36 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::tag::TagStatus,
+    repositories::{book_repository::BookRepository, tag_repository::TagRepository},
+    services::tag_service::{TagService, MAX_DISPLAYED_TAGS},
+};
+
+/// Rewrite `Book.tags` from the book's top voted approved tags. While no
+/// approved tag has a positive score the book shows its catalog tags, the
+/// ones it was created or ingested with.
+pub async fn refresh_book_tags<BR: BookRepository, TR: TagRepository>(
+    book_repository: &BR,
+    tag_repository: &TR,
+    tag_service: &TagService,
+    book_id: Uuid,
+) -> Result<()> {
+    let mut book = match book_repository.find_by_id(book_id).await? {
+        Some(book) => book,
+        None => return Ok(()),
+    };
+
+    let approved = tag_repository.find_tags_for_book(book_id, Some(TagStatus::Approved)).await?;
+    let (displayed, community_tags) = tag_service.displayed_tags(&approved, &book.catalog_tags, MAX_DISPLAYED_TAGS);
+
+    if displayed != book.tags || community_tags != book.community_tags {
+        book.tags = displayed;
+        book.community_tags = community_tags;
+        book.updated_at = chrono::Utc::now();
+        book.updated_by = None;
+        book_repository.update(book).await?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::tag::{BookTag, TagStatus},
+    repositories::{book_repository::BookRepository, tag_repository::TagRepository},
+    services::tag_service::TagService,
+    errors::domain_error::DomainError,
+};
+use crate::application::{
+    dtos::tag_dtos::{SuggestTagRequest, BookTagResponse},
+    use_cases::tags::vote_tag::apply_tag_vote,
+};
+
+pub struct SuggestTagUseCase<BR: BookRepository, TR: TagRepository> {
+    book_repository: BR,
+    tag_repository: TR,
+    tag_service: TagService,
+}
+
+impl<BR: BookRepository, TR: TagRepository> SuggestTagUseCase<BR, TR> {
+    pub fn new(book_repository: BR, tag_repository: TR, tag_service: TagService) -> Self {
+        Self {
+            book_repository,
+            tag_repository,
+            tag_service,
+        }
+    }
+
+    /// Suggest a tag on a book. New tags wait for curator approval; suggesting
+    /// a tag the book already has counts as an upvote for it.
+    pub async fn execute(&self, book_id: Uuid, user_id: Uuid, request: SuggestTagRequest) -> Result<BookTagResponse> {
+        self.book_repository
+            .find_by_id(book_id)
+            .await?
+            .ok_or(DomainError::BookNotFound)?;
+
+        let tag_name = TagService::normalize_tag(&request.tag)
+            .ok_or_else(|| DomainError::InvalidInput("Tag must contain letters or digits".to_string()))?;
+
+        if self.tag_repository.find_blocked_tag(&tag_name).await?.is_some() {
+            return Err(DomainError::InvalidInput(format!("Tag \"{}\" is not allowed", tag_name)).into());
+        }
+
+        let tag = match self.tag_repository.find_tag_for_book(book_id, &tag_name).await? {
+            Some(existing) if existing.status == TagStatus::Rejected => {
+                return Err(DomainError::InvalidInput(format!(
+                    "Tag \"{}\" was rejected for this book",
+                    tag_name
+                )).into());
+            }
+            Some(existing) => existing,
+            None => self.tag_repository.save_tag(BookTag::new(book_id, tag_name, user_id)).await?,
+        };
+
+        let tag = apply_tag_vote(
+            &self.book_repository,
+            &self.tag_repository,
+            &self.tag_service,
+            tag,
+            user_id,
+            1,
+        ).await?;
+
+        Ok(BookTagResponse::new(tag, 0.0))
+    }
+}
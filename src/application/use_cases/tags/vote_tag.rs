@@ -0,0 +1,90 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::tag::{BookTag, TagVote, TagStatus},
+    repositories::{book_repository::BookRepository, tag_repository::TagRepository},
+    services::tag_service::TagService,
+    errors::domain_error::DomainError,
+};
+use crate::application::{
+    dtos::tag_dtos::{VoteTagRequest, BookTagResponse},
+    use_cases::tags::refresh_book_tags::refresh_book_tags,
+};
+
+/// Record a user's vote on a tag (one vote per user, 0 withdraws it),
+/// recount the tally and refresh the book's displayed tags
+pub async fn apply_tag_vote<BR: BookRepository, TR: TagRepository>(
+    book_repository: &BR,
+    tag_repository: &TR,
+    tag_service: &TagService,
+    tag: BookTag,
+    user_id: Uuid,
+    value: i8,
+) -> Result<BookTag> {
+    match (tag_repository.find_vote(tag.id, user_id).await?, value) {
+        (Some(vote), 0) => tag_repository.delete_vote(vote.id).await?,
+        (Some(vote), value) if vote.value == value => return Ok(tag),
+        (Some(mut vote), value) => {
+            vote.value = value;
+            vote.updated_at = chrono::Utc::now();
+            tag_repository.save_vote(vote).await?;
+        }
+        (None, 0) => return Ok(tag),
+        (None, value) => {
+            tag_repository.save_vote(TagVote::new(tag.id, user_id, value)).await?;
+        }
+    }
+
+    let tag = tag_repository.update_tag_vote_counts(tag.id).await?;
+    if tag.status == TagStatus::Approved {
+        refresh_book_tags(book_repository, tag_repository, tag_service, tag.book_id).await?;
+    }
+
+    Ok(tag)
+}
+
+pub struct VoteTagUseCase<BR: BookRepository, TR: TagRepository> {
+    book_repository: BR,
+    tag_repository: TR,
+    tag_service: TagService,
+}
+
+impl<BR: BookRepository, TR: TagRepository> VoteTagUseCase<BR, TR> {
+    pub fn new(book_repository: BR, tag_repository: TR, tag_service: TagService) -> Self {
+        Self {
+            book_repository,
+            tag_repository,
+            tag_service,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        book_id: Uuid,
+        tag_id: Uuid,
+        user_id: Uuid,
+        request: VoteTagRequest,
+    ) -> Result<BookTagResponse> {
+        let tag = self.tag_repository
+            .find_tag(tag_id)
+            .await?
+            .filter(|tag| tag.book_id == book_id)
+            .ok_or(DomainError::TagNotFound)?;
+
+        if tag.status == TagStatus::Rejected {
+            return Err(DomainError::InvalidInput("Rejected tags cannot be voted on".to_string()).into());
+        }
+
+        let tag = apply_tag_vote(
+            &self.book_repository,
+            &self.tag_repository,
+            &self.tag_service,
+            tag,
+            user_id,
+            request.value,
+        ).await?;
+
+        Ok(BookTagResponse::new(tag, 0.0))
+    }
+}
@@ -0,0 +1,73 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    repositories::{
+        book_repository::BookRepository,
+        user_repository::UserRepository,
+        work_repository::WorkRepository,
+    },
+    services::rating_service::RatingPrior,
+    errors::domain_error::DomainError,
+};
+use crate::application::dtos::{book_dtos::BookResponse, work_dtos::AddEditionRequest};
+
+pub struct AddEditionUseCase<UR: UserRepository, BR: BookRepository, WR: WorkRepository> {
+    user_repository: UR,
+    book_repository: BR,
+    work_repository: WR,
+    rating_prior: RatingPrior,
+}
+
+impl<UR: UserRepository, BR: BookRepository, WR: WorkRepository> AddEditionUseCase<UR, BR, WR> {
+    pub fn new(user_repository: UR, book_repository: BR, work_repository: WR, rating_prior: RatingPrior) -> Self {
+        Self {
+            user_repository,
+            book_repository,
+            work_repository,
+            rating_prior,
+        }
+    }
+
+    /// Attach an existing book to a work as one of its editions
+    pub async fn execute(
+        &self,
+        work_id: Uuid,
+        user_id: Uuid,
+        request: AddEditionRequest,
+    ) -> Result<BookResponse> {
+        let curator = self.user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(DomainError::UserNotFound)?;
+
+        if !curator.is_curator() {
+            return Err(DomainError::Unauthorized.into());
+        }
+
+        self.work_repository
+            .find_by_id(work_id)
+            .await?
+            .ok_or(DomainError::WorkNotFound)?;
+
+        let mut book = self.book_repository
+            .find_by_id(request.book_id)
+            .await?
+            .ok_or(DomainError::BookNotFound)?;
+
+        let previous_work_id = book.work_id.replace(work_id);
+        book.format = request.format;
+        book.updated_at = chrono::Utc::now();
+        book.updated_by = Some(user_id);
+
+        let updated_book = self.book_repository.update(book).await?;
+
+        // The edition's ratings move with it
+        self.work_repository.update_work_rating_stats(work_id).await?;
+        if let Some(previous_work_id) = previous_work_id.filter(|id| *id != work_id) {
+            self.work_repository.update_work_rating_stats(previous_work_id).await?;
+        }
+
+        Ok(BookResponse::new(updated_book, &self.rating_prior))
+    }
+}
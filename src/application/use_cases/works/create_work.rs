@@ -0,0 +1,70 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::work::Work,
+    repositories::{user_repository::UserRepository, work_repository::WorkRepository},
+    services::work_service::WorkService,
+    errors::domain_error::DomainError,
+};
+use crate::application::dtos::work_dtos::{CreateWorkRequest, WorkSummary};
+
+pub struct CreateWorkUseCase<UR: UserRepository, WR: WorkRepository> {
+    user_repository: UR,
+    work_repository: WR,
+    work_service: WorkService,
+}
+
+impl<UR: UserRepository, WR: WorkRepository> CreateWorkUseCase<UR, WR> {
+    pub fn new(user_repository: UR, work_repository: WR, work_service: WorkService) -> Self {
+        Self {
+            user_repository,
+            work_repository,
+            work_service,
+        }
+    }
+
+    pub async fn execute(&self, request: CreateWorkRequest, created_by: Uuid) -> Result<WorkSummary> {
+        let curator = self.user_repository
+            .find_by_id(created_by)
+            .await?
+            .ok_or(DomainError::UserNotFound)?;
+
+        if !curator.is_curator() {
+            return Err(DomainError::Unauthorized.into());
+        }
+
+        let mut work = Work::new(
+            request.title,
+            request.author,
+            request.description,
+            request.genre,
+            request.original_language,
+            request.original_publication_year,
+            created_by,
+        );
+
+        if let Some(series_id) = request.series_id {
+            self.work_repository
+                .find_series_by_id(series_id)
+                .await?
+                .ok_or(DomainError::SeriesNotFound)?;
+
+            let position = request.series_position.ok_or_else(|| {
+                DomainError::InvalidInput("series_position is required with series_id".to_string())
+            })?;
+
+            let series_works = self.work_repository.find_works_in_series(series_id).await?;
+            self.work_service
+                .validate_series_position(&series_works, work.id, position)
+                .map_err(|e| DomainError::InvalidInput(e.to_string()))?;
+
+            work.series_id = Some(series_id);
+            work.series_position = Some(position);
+        }
+
+        let saved_work = self.work_repository.save(work).await?;
+
+        Ok(WorkSummary::from(saved_work))
+    }
+}
@@ -0,0 +1,79 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::work::Work,
+    repositories::work_repository::WorkRepository,
+    services::{rating_service::RatingPrior, work_service::WorkService},
+    errors::domain_error::DomainError,
+};
+use crate::application::dtos::{
+    book_dtos::BookResponse,
+    work_dtos::{WorkResponse, WorkSummary},
+};
+
+pub struct GetWorkUseCase<WR: WorkRepository> {
+    work_repository: WR,
+    work_service: WorkService,
+    rating_prior: RatingPrior,
+}
+
+impl<WR: WorkRepository> GetWorkUseCase<WR> {
+    pub fn new(work_repository: WR, work_service: WorkService, rating_prior: RatingPrior) -> Self {
+        Self {
+            work_repository,
+            work_service,
+            rating_prior,
+        }
+    }
+
+    pub async fn execute(&self, work_id: Uuid) -> Result<WorkResponse> {
+        let work = self.work_repository
+            .find_by_id(work_id)
+            .await?
+            .ok_or(DomainError::WorkNotFound)?;
+
+        let editions = self.work_repository.find_editions(work_id).await?;
+        let next_in_series = self.find_next_in_series(&work).await?;
+
+        Ok(WorkResponse {
+            id: work.id.to_string(),
+            title: work.title,
+            author: work.author,
+            description: work.description,
+            genre: work.genre,
+            original_language: work.original_language,
+            original_publication_year: work.original_publication_year,
+            series_id: work.series_id.map(|id| id.to_string()),
+            series_position: work.series_position,
+            average_rating: work.average_rating,
+            ratings_count: work.ratings_count,
+            editions: editions
+                .into_iter()
+                .map(|book| BookResponse::new(book, &self.rating_prior))
+                .collect(),
+            next_in_series: next_in_series.map(WorkSummary::from),
+            created_at: work.created_at.to_rfc3339(),
+        })
+    }
+
+    /// The work to read after `work_id`, or `None` at the end of a series
+    pub async fn next_in_series(&self, work_id: Uuid) -> Result<Option<WorkSummary>> {
+        let work = self.work_repository
+            .find_by_id(work_id)
+            .await?
+            .ok_or(DomainError::WorkNotFound)?;
+
+        Ok(self.find_next_in_series(&work).await?.map(WorkSummary::from))
+    }
+
+    async fn find_next_in_series(&self, work: &Work) -> Result<Option<Work>> {
+        let series_id = match work.series_id {
+            Some(series_id) => series_id,
+            None => return Ok(None),
+        };
+
+        let series_works = self.work_repository.find_works_in_series(series_id).await?;
+        Ok(self.work_service.next_in_series(&series_works, work).cloned())
+    }
+}
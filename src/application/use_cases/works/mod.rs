@@ -0,0 +1,11 @@
+//! Work, edition and series use cases module
+
+pub mod create_work;
+pub mod add_edition;
+pub mod get_work;
+pub mod series;
+
+pub use create_work::*;
+pub use add_edition::*;
+pub use get_work::*;
+pub use series::*;
@@ -0,0 +1,60 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::work::Series,
+    repositories::{user_repository::UserRepository, work_repository::WorkRepository},
+    errors::domain_error::DomainError,
+};
+use crate::application::dtos::work_dtos::{CreateSeriesRequest, SeriesResponse};
+
+pub struct CreateSeriesUseCase<UR: UserRepository, WR: WorkRepository> {
+    user_repository: UR,
+    work_repository: WR,
+}
+
+impl<UR: UserRepository, WR: WorkRepository> CreateSeriesUseCase<UR, WR> {
+    pub fn new(user_repository: UR, work_repository: WR) -> Self {
+        Self {
+            user_repository,
+            work_repository,
+        }
+    }
+
+    pub async fn execute(&self, request: CreateSeriesRequest, created_by: Uuid) -> Result<SeriesResponse> {
+        let curator = self.user_repository
+            .find_by_id(created_by)
+            .await?
+            .ok_or(DomainError::UserNotFound)?;
+
+        if !curator.is_curator() {
+            return Err(DomainError::Unauthorized.into());
+        }
+
+        let series = Series::new(request.title, request.description, created_by);
+        let saved_series = self.work_repository.save_series(series).await?;
+
+        Ok(SeriesResponse::new(saved_series, Vec::new()))
+    }
+}
+
+pub struct GetSeriesUseCase<WR: WorkRepository> {
+    work_repository: WR,
+}
+
+impl<WR: WorkRepository> GetSeriesUseCase<WR> {
+    pub fn new(work_repository: WR) -> Self {
+        Self { work_repository }
+    }
+
+    pub async fn execute(&self, series_id: Uuid) -> Result<SeriesResponse> {
+        let series = self.work_repository
+            .find_series_by_id(series_id)
+            .await?
+            .ok_or(DomainError::SeriesNotFound)?;
+
+        let works = self.work_repository.find_works_in_series(series_id).await?;
+
+        Ok(SeriesResponse::new(series, works))
+    }
+}
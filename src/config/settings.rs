@@ -22,6 +22,166 @@ pub struct CloudinarySettings {
     pub upload_preset: String,
 }
 
+/// Where processed cover images are stored
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageBackend {
+    #[default]
+    Filesystem,
+    S3,
+    Cloudinary,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct FilesystemStorageSettings {
+    pub root_dir: String,
+    pub mount_path: String, // Path the app serves `root_dir` under, e.g. "/uploads"
+    pub public_url: String, // Base URL written into image URLs
+}
+
+impl Default for FilesystemStorageSettings {
+    fn default() -> Self {
+        Self {
+            root_dir: "./uploads".to_string(),
+            mount_path: "/uploads".to_string(),
+            public_url: "http://127.0.0.1:8080/uploads".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct S3StorageSettings {
+    pub endpoint: String, // e.g. "https://s3.eu-west-1.amazonaws.com" or "http://localhost:9000"
+    pub region: String,
+    pub bucket: String,
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    #[serde(default)]
+    pub path_style: bool, // Required by most S3-compatible servers
+    pub public_url: Option<String>, // CDN in front of the bucket, defaults to the bucket URL
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct StorageSettings {
+    #[serde(default)]
+    pub backend: StorageBackend,
+    #[serde(default)]
+    pub filesystem: FilesystemStorageSettings,
+    pub s3: Option<S3StorageSettings>,
+}
+
+/// Words that hold or reject a review. Lists can also be read from files with
+/// one word per line, so they can be updated without touching the config.
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct ProfanityFilterSettings {
+    #[serde(default)]
+    pub hold_words: Vec<String>,
+    #[serde(default)]
+    pub reject_words: Vec<String>,
+    pub hold_words_file: Option<String>,
+    pub reject_words_file: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct LinkFilterSettings {
+    pub links_to_hold: usize, // 0 disables
+    pub links_to_reject: usize, // 0 disables
+    #[serde(default)]
+    pub blocked_domains: Vec<String>,
+    pub max_uppercase_ratio: f64,
+}
+
+impl Default for LinkFilterSettings {
+    fn default() -> Self {
+        Self {
+            links_to_hold: 1,
+            links_to_reject: 3,
+            blocked_domains: Vec::new(),
+            max_uppercase_ratio: 0.7,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct RepeatedTextFilterSettings {
+    pub max_char_run: usize, // 0 disables
+    pub min_unique_word_ratio: f64,
+    pub reject_duplicates: bool, // Reject rather than hold reviews pasted onto several books
+}
+
+impl Default for RepeatedTextFilterSettings {
+    fn default() -> Self {
+        Self {
+            max_char_run: 10,
+            min_unique_word_ratio: 0.3,
+            reject_duplicates: false,
+        }
+    }
+}
+
+/// Review moderation chain: which filters run, in order, and their settings
+#[derive(Debug, Deserialize, Clone)]
+pub struct ModerationSettings {
+    pub filters: Vec<String>, // "profanity", "links", "repeated_text"
+    #[serde(default)]
+    pub profanity: ProfanityFilterSettings,
+    #[serde(default)]
+    pub links: LinkFilterSettings,
+    #[serde(default)]
+    pub repeated_text: RepeatedTextFilterSettings,
+}
+
+impl Default for ModerationSettings {
+    fn default() -> Self {
+        Self {
+            filters: vec!["profanity".to_string(), "links".to_string(), "repeated_text".to_string()],
+            profanity: ProfanityFilterSettings::default(),
+            links: LinkFilterSettings::default(),
+            repeated_text: RepeatedTextFilterSettings::default(),
+        }
+    }
+}
+
+/// Non-personalized discovery lists, recomputed in the background
+#[derive(Debug, Deserialize, Clone)]
+pub struct TrendingSettings {
+    pub refresh_interval_minutes: u64,
+    pub list_size: usize,
+    pub click_weight: f64, // A recommendation click counts this much relative to a 5★ rating
+    pub new_release_days: i64,
+    pub new_and_notable_min_ratings: i32,
+    pub new_and_notable_min_average: f64,
+}
+
+impl Default for TrendingSettings {
+    fn default() -> Self {
+        Self {
+            refresh_interval_minutes: 15,
+            list_size: 50,
+            click_weight: 0.5,
+            new_release_days: 90,
+            new_and_notable_min_ratings: 3,
+            new_and_notable_min_average: 4.0,
+        }
+    }
+}
+
+/// Prior for the Bayesian-weighted rating used to rank books
+#[derive(Debug, Deserialize, Clone)]
+pub struct RatingSettings {
+    pub prior_mean: f64,
+    pub prior_weight: f64, // Number of ratings the prior counts as
+}
+
+impl Default for RatingSettings {
+    fn default() -> Self {
+        Self {
+            prior_mean: 3.5,
+            prior_weight: 10.0,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct MLModelSettings {
     pub model_path: String,
@@ -38,7 +198,15 @@ pub struct ServerSettings {
 pub struct Settings {
     pub database: DatabaseSettings,
     pub jwt: JwtSettings,
-    pub cloudinary: CloudinarySettings,
+    pub cloudinary: Option<CloudinarySettings>,
+    #[serde(default)]
+    pub storage: StorageSettings,
+    #[serde(default)]
+    pub moderation: ModerationSettings,
+    #[serde(default)]
+    pub trending: TrendingSettings,
+    #[serde(default)]
+    pub ratings: RatingSettings,
     pub ml_model: MLModelSettings,
     pub server: ServerSettings,
 }
@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct Author {
+    pub id: Uuid,
+    pub name: String,
+    pub aliases: Vec<String>, // Alternative spellings, pen names
+    pub name_keys: Vec<String>, // Normalized name and aliases, used for matching
+    pub bio: Option<String>,
+    pub photo_url: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Author {
+    pub fn new(name: String, name_keys: Vec<String>) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            name,
+            aliases: Vec::new(),
+            name_keys,
+            bio: None,
+            photo_url: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct Publisher {
+    pub id: Uuid,
+    pub name: String,
+    pub aliases: Vec<String>,
+    pub name_keys: Vec<String>,
+    pub website: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Publisher {
+    pub fn new(name: String, name_keys: Vec<String>) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            name,
+            aliases: Vec::new(),
+            name_keys,
+            website: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ContributorRole {
+    Author,
+    Translator,
+    Illustrator,
+    Editor,
+    Narrator,
+}
+
+/// Links an author to a book with a role (many-to-many)
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BookContributor {
+    pub id: Uuid,
+    pub book_id: Uuid,
+    pub author_id: Uuid,
+    pub role: ContributorRole,
+    pub position: i32, // Credit order on the book
+    pub created_at: DateTime<Utc>,
+}
+
+impl BookContributor {
+    pub fn new(book_id: Uuid, author_id: Uuid, role: ContributorRole, position: i32) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            book_id,
+            author_id,
+            role,
+            position,
+            created_at: Utc::now(),
+        }
+    }
+}
@@ -3,25 +3,55 @@ use uuid::Uuid;
 use chrono::{DateTime, Utc};
 use utoipa::ToSchema;
 
+use crate::domain::entities::{review::ReviewStatus, work::EditionFormat};
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct Book {
     pub id: Uuid,
     pub title: String,
+    #[serde(default)]
+    pub subtitle: Option<String>,
     pub author: String,
     pub isbn: Option<String>,
     pub description: String,
+    #[serde(default)]
+    pub translations: Vec<BookTranslation>, // Localized title, subtitle and description
     pub genre: Vec<String>,
-    pub tags: Vec<String>,
+    pub tags: Vec<String>, // Displayed tags: the top community tags, or `catalog_tags`
+    #[serde(default)]
+    pub catalog_tags: Vec<String>, // Tags the book was created or ingested with
+    #[serde(default)]
+    pub community_tags: bool, // Whether `tags` holds community tags rather than `catalog_tags`
     pub publication_year: i32,
     pub publisher: String,
+    #[serde(default)]
+    pub publisher_id: Option<Uuid>, // Linked publisher record, `publisher` stays the display name
     pub language: String,
     pub page_count: i32,
-    pub cover_image_url: Option<String>,
+    pub cover_image_url: Option<String>, // Cover supplied by a publisher feed or uploaded before processing
+    #[serde(default)]
+    pub cover_images: Vec<CoverImage>, // Processed renditions of the uploaded cover
+    #[serde(default)]
+    pub cover_placeholder: Option<CoverPlaceholder>, // Shown while the cover loads
+    #[serde(default)]
+    pub cover_hash: Option<String>, // 64-bit dHash of the cover as 16 hex digits
+    #[serde(default)]
+    pub work_id: Option<Uuid>, // Work this book is an edition of
+    #[serde(default)]
+    pub format: EditionFormat,
     pub average_rating: f64,
     pub ratings_count: i32,
+    #[serde(default)]
+    pub rating_sum: f64, // Sum of all rating values, kept in step with ratings_count
+    #[serde(default)]
+    pub rating_histogram: Vec<i32>, // Number of 1★ to 5★ ratings, each rounded to the nearest star
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub created_by: Uuid, // User who added the book
+    #[serde(default)]
+    pub updated_by: Option<Uuid>, // User behind the latest change, None for system changes
+    #[serde(default)]
+    pub deleted_at: Option<DateTime<Utc>>, // Soft delete tombstone
 }
 
 impl Book {
@@ -40,21 +70,115 @@ impl Book {
         Self {
             id: Uuid::new_v4(),
             title,
+            subtitle: None,
             author,
             isbn: None,
             description,
+            translations: Vec::new(),
             genre,
             tags: Vec::new(),
+            catalog_tags: Vec::new(),
+            community_tags: false,
             publication_year,
             publisher,
+            publisher_id: None,
             language,
             page_count,
             cover_image_url: None,
+            cover_images: Vec::new(),
+            cover_placeholder: None,
+            cover_hash: None,
+            work_id: None,
+            format: EditionFormat::Unknown,
             average_rating: 0.0,
             ratings_count: 0,
+            rating_sum: 0.0,
+            rating_histogram: vec![0; 5],
             created_at: now,
             updated_at: now,
             created_by,
+            updated_by: None,
+            deleted_at: None,
+        }
+    }
+}
+
+/// Title, subtitle and description of a book in one locale
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct BookTranslation {
+    pub locale: String, // BCP 47, e.g. "pt-BR"
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub description: Option<String>, // Falls back to the original description
+}
+
+/// One stored rendition of a book cover
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CoverImage {
+    pub size: CoverSize,
+    pub format: CoverFormat,
+    pub width: u32,
+    pub height: u32,
+    pub url: String,
+    #[serde(default)]
+    pub key: String, // Storage key, used to delete the rendition when the cover is replaced
+}
+
+/// Cheap stand-ins clients can render before the cover image arrives
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CoverPlaceholder {
+    pub blurhash: String,
+    pub average_color: String, // "#rrggbb"
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CoverSize {
+    Thumbnail,
+    Card,
+    Full,
+}
+
+impl CoverSize {
+    pub const ALL: [CoverSize; 3] = [CoverSize::Thumbnail, CoverSize::Card, CoverSize::Full];
+
+    /// Maximum width in pixels; smaller uploads are never upscaled
+    pub fn max_width(&self) -> u32 {
+        match self {
+            CoverSize::Thumbnail => 160,
+            CoverSize::Card => 400,
+            CoverSize::Full => 1200,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CoverSize::Thumbnail => "thumbnail",
+            CoverSize::Card => "card",
+            CoverSize::Full => "full",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CoverFormat {
+    Webp,
+    Jpeg,
+}
+
+impl CoverFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            CoverFormat::Webp => "webp",
+            CoverFormat::Jpeg => "jpg",
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            CoverFormat::Webp => "image/webp",
+            CoverFormat::Jpeg => "image/jpeg",
         }
     }
 }
@@ -67,4 +191,30 @@ pub struct BookRating {
     pub rating: f64, // 1.0 to 5.0
     pub review: Option<String>,
     pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    pub updated_at: Option<DateTime<Utc>>, // Set once the rating or review is edited
+    #[serde(default)]
+    pub status: ReviewStatus,
+    #[serde(default)]
+    pub helpful_count: i32,
+    #[serde(default)]
+    pub not_helpful_count: i32,
+    #[serde(default)]
+    pub helpfulness: f64, // Wilson lower bound of the helpful share, for ranking
+    #[serde(default)]
+    pub report_count: i32, // Reports awaiting a moderator
+    #[serde(default)]
+    pub moderation_reasons: Vec<String>, // Why the filters held or rejected the review
+}
+
+/// Order of a book's review listing
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewSort {
+    #[default]
+    Newest,
+    Oldest,
+    HighestRated,
+    LowestRated,
+    MostHelpful,
 }
\ No newline at end of file
@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use utoipa::ToSchema;
+
+/// One condition of a challenge, checked against the books a participant
+/// finished while the challenge ran
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ChallengeRule {
+    BooksRead { count: i32 },
+    PagesRead { count: i32 },
+    DistinctGenres { count: i32 },
+    BooksInGenre { genre: String, count: i32 },
+    DistinctAuthors { count: i32 },
+}
+
+impl ChallengeRule {
+    pub fn target(&self) -> i32 {
+        match self {
+            ChallengeRule::BooksRead { count }
+            | ChallengeRule::PagesRead { count }
+            | ChallengeRule::DistinctGenres { count }
+            | ChallengeRule::BooksInGenre { count, .. }
+            | ChallengeRule::DistinctAuthors { count } => *count,
+        }
+    }
+}
+
+/// A community challenge defined by an admin; participants complete it by
+/// meeting every rule between `starts_at` and `ends_at`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct Challenge {
+    pub id: Uuid,
+    pub name: String,
+    pub description: String,
+    pub rules: Vec<ChallengeRule>,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Challenge {
+    pub fn new(
+        name: String,
+        description: String,
+        rules: Vec<ChallengeRule>,
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+        created_by: Uuid,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            name,
+            description,
+            rules,
+            starts_at,
+            ends_at,
+            created_by,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.starts_at <= now && now < self.ends_at
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ChallengeParticipant {
+    pub id: Uuid,
+    pub challenge_id: Uuid,
+    pub user_id: Uuid,
+    pub joined_at: DateTime<Utc>,
+}
+
+impl ChallengeParticipant {
+    pub fn new(challenge_id: Uuid, user_id: Uuid) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            challenge_id,
+            user_id,
+            joined_at: Utc::now(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ChallengeRuleProgress {
+    pub rule: ChallengeRule,
+    pub current: i32,
+    pub met: bool,
+}
+
+/// A participant's standing in a challenge
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ChallengeProgress {
+    pub rules: Vec<ChallengeRuleProgress>,
+    pub books_counted: i32,
+    /// Mean share of each rule met, from 0.0 to 1.0
+    pub progress: f64,
+    pub completed: bool,
+}
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use utoipa::ToSchema;
+
+/// A pair of books the duplicate detector believes are the same work
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DuplicateCandidate {
+    pub id: Uuid,
+    pub book_id: Uuid,
+    pub duplicate_book_id: Uuid,
+    pub similarity: f64, // 0.0 to 1.0
+    pub reasons: Vec<String>,
+    pub status: DuplicateStatus,
+    pub created_at: DateTime<Utc>,
+    pub reviewed_by: Option<Uuid>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicateStatus {
+    Pending,
+    Merged,
+    Dismissed,
+}
+
+impl DuplicateCandidate {
+    pub fn new(book_id: Uuid, duplicate_book_id: Uuid, similarity: f64, reasons: Vec<String>) -> Self {
+        // Store pairs in a stable order so the same pair is never queued twice
+        let (book_id, duplicate_book_id) = if book_id < duplicate_book_id {
+            (book_id, duplicate_book_id)
+        } else {
+            (duplicate_book_id, book_id)
+        };
+
+        Self {
+            id: Uuid::new_v4(),
+            book_id,
+            duplicate_book_id,
+            similarity,
+            reasons,
+            status: DuplicateStatus::Pending,
+            created_at: Utc::now(),
+            reviewed_by: None,
+            reviewed_at: None,
+        }
+    }
+
+    pub fn involves(&self, book_id: Uuid) -> bool {
+        self.book_id == book_id || self.duplicate_book_id == book_id
+    }
+
+    pub fn resolve(&mut self, status: DuplicateStatus, reviewed_by: Uuid) {
+        self.status = status;
+        self.reviewed_by = Some(reviewed_by);
+        self.reviewed_at = Some(Utc::now());
+    }
+}
+
+/// Left behind when a book is merged away, so old links keep working
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BookRedirect {
+    pub from_book_id: Uuid,
+    pub to_book_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use utoipa::ToSchema;
+
+/// A node in the managed genre tree
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct Genre {
+    pub id: Uuid,
+    pub name: String, // Canonical display name, e.g. "Science Fiction"
+    pub slug: String, // e.g. "science-fiction"
+    pub parent_id: Option<Uuid>,
+    pub synonyms: Vec<String>, // e.g. ["Sci-Fi", "SF"]
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Genre {
+    pub fn new(name: String, slug: String, parent_id: Option<Uuid>, synonyms: Vec<String>) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            name,
+            slug,
+            parent_id,
+            synonyms,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
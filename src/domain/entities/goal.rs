@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use utoipa::ToSchema;
+
+/// What a reading goal counts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum GoalMetric {
+    Books,
+    Pages,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum GoalPeriod {
+    Year,
+    Month,
+}
+
+/// A reader's target for a year or a month, e.g. 24 books in 2025
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReadingGoal {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub metric: GoalMetric,
+    pub period: GoalPeriod,
+    pub year: i32,
+    pub month: Option<u32>, // 1 to 12, monthly goals only
+    pub target: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ReadingGoal {
+    pub fn new(
+        user_id: Uuid,
+        metric: GoalMetric,
+        period: GoalPeriod,
+        year: i32,
+        month: Option<u32>,
+        target: i32,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            metric,
+            period,
+            year,
+            month,
+            target,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Whether both goals count the same thing over the same period
+    pub fn same_period(&self, other: &ReadingGoal) -> bool {
+        self.metric == other.metric && self.period == other.period && self.year == other.year && self.month == other.month
+    }
+}
+
+/// How far along a goal is
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct GoalProgress {
+    pub current: i32,
+    pub target: i32,
+    pub percent_complete: f64,
+    pub completed: bool,
+    /// Where a steady pace would be by now; 0 before the period and the target after it
+    pub expected_by_now: f64,
+    pub on_track: bool,
+}
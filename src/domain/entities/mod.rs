@@ -6,7 +6,33 @@
 pub mod user;
 pub mod book;
 pub mod recommendation;
+pub mod duplicate;
+pub mod work;
+pub mod author;
+pub mod genre;
+pub mod tag;
+pub mod revision;
+pub mod review;
+pub mod notification;
+pub mod trending;
+pub mod shelf;
+pub mod goal;
+pub mod challenge;
+pub mod stats;
 
 pub use user::*;
 pub use book::*;
-pub use recommendation::*;
\ No newline at end of file
+pub use recommendation::*;
+pub use duplicate::*;
+pub use work::*;
+pub use author::*;
+pub use genre::*;
+pub use tag::*;
+pub use revision::*;
+pub use review::*;
+pub use notification::*;
+pub use trending::*;
+pub use shelf::*;
+pub use goal::*;
+pub use challenge::*;
+pub use stats::*;
\ No newline at end of file
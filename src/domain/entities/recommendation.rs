@@ -13,6 +13,8 @@ pub struct Recommendation {
     pub algorithm_version: String, // Which ML model version was used
     pub created_at: DateTime<Utc>,
     pub is_clicked: bool, // User interaction tracking
+    #[serde(default)]
+    pub clicked_at: Option<DateTime<Utc>>, // First click, feeds trending
     pub is_purchased: bool, // Conversion tracking
 }
 
@@ -33,12 +35,14 @@ impl Recommendation {
             algorithm_version,
             created_at: Utc::now(),
             is_clicked: false,
+            clicked_at: None,
             is_purchased: false,
         }
     }
 
     pub fn mark_clicked(&mut self) {
         self.is_clicked = true;
+        self.clicked_at.get_or_insert_with(Utc::now);
     }
 
     pub fn mark_purchased(&mut self) {
@@ -64,4 +68,41 @@ pub struct ReadingSession {
     pub end_time: Option<DateTime<Utc>>,
     pub pages_read: i32,
     pub session_duration_minutes: Option<i32>,
+    #[serde(default)]
+    pub start_page: i32,
+    #[serde(default)]
+    pub end_page: Option<i32>, // Page reached, set when the session ends
+}
+
+impl ReadingSession {
+    pub fn new(user_id: Uuid, book_id: Uuid, start_page: i32) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            book_id,
+            start_time: Utc::now(),
+            end_time: None,
+            pages_read: 0,
+            session_duration_minutes: None,
+            start_page,
+            end_page: None,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.end_time.is_none()
+    }
+}
+
+/// A reader's progress through a book, derived from their reading sessions
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReadingProgress {
+    pub current_page: i32,
+    pub page_count: i32,
+    pub percent_complete: Option<f64>, // None when the book's page count is unknown
+    pub pages_read: i32, // Across all sessions, re-read pages included
+    pub minutes_read: i32,
+    pub sessions_count: i32,
+    pub last_read_at: Option<DateTime<Utc>>,
+    pub finished: bool,
 }
\ No newline at end of file
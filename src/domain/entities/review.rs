@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use utoipa::ToSchema;
+
+/// Visibility of the review text on a `BookRating`. The rating itself always
+/// counts towards the book's average.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewStatus {
+    #[default]
+    Published,
+    Pending,  // Held by the moderation filters, waiting for a moderator
+    Rejected, // Turned down by the moderation filters or a moderator
+    Hidden,   // Reported past the threshold, waiting for a moderator
+    Removed,  // A moderator upheld the reports
+}
+
+/// One user's helpfulness vote on a review
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReviewVote {
+    pub id: Uuid,
+    pub rating_id: Uuid,
+    pub user_id: Uuid,
+    pub helpful: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportReason {
+    Spam,
+    Offensive,
+    Harassment,
+    Spoilers,
+    OffTopic,
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportStatus {
+    Open,
+    Upheld,
+    Dismissed,
+}
+
+/// A user's abuse report against a review, one per user and review
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReviewReport {
+    pub id: Uuid,
+    pub rating_id: Uuid,
+    pub reported_by: Uuid,
+    pub reason: ReportReason,
+    pub details: Option<String>,
+    pub status: ReportStatus,
+    pub created_at: DateTime<Utc>,
+    pub resolved_by: Option<Uuid>,
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+impl ReviewVote {
+    pub fn new(rating_id: Uuid, user_id: Uuid, helpful: bool) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            rating_id,
+            user_id,
+            helpful,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+impl ReviewReport {
+    pub fn new(rating_id: Uuid, reported_by: Uuid, reason: ReportReason, details: Option<String>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            rating_id,
+            reported_by,
+            reason,
+            details,
+            status: ReportStatus::Open,
+            created_at: Utc::now(),
+            resolved_by: None,
+            resolved_at: None,
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use utoipa::ToSchema;
+
+/// One recorded change to a book's catalog data
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BookRevision {
+    pub id: Uuid,
+    pub book_id: Uuid,
+    pub revision_number: i32, // 1 is the book as created
+    pub changed_by: Option<Uuid>, // None for system changes such as tag refreshes
+    pub changed_at: DateTime<Utc>,
+    pub changes: Vec<FieldChange>,
+    /// Tracked fields of the book after this revision, used for revert
+    #[schema(value_type = Object)]
+    pub snapshot: serde_json::Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct FieldChange {
+    pub field: String,
+    #[schema(value_type = Object)]
+    pub old_value: serde_json::Value, // null when the book was created
+    #[schema(value_type = Object)]
+    pub new_value: serde_json::Value,
+}
+
+impl BookRevision {
+    pub fn new(
+        book_id: Uuid,
+        revision_number: i32,
+        changed_by: Option<Uuid>,
+        changes: Vec<FieldChange>,
+        snapshot: serde_json::Value,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            book_id,
+            revision_number,
+            changed_by,
+            changed_at: Utc::now(),
+            changes,
+            snapshot,
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use utoipa::ToSchema;
+
+/// The standard shelves; a shelved book is on exactly one of them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ShelfStatus {
+    WantToRead,
+    CurrentlyReading,
+    Read,
+    Abandoned,
+}
+
+impl ShelfStatus {
+    pub const ALL: [ShelfStatus; 4] = [
+        ShelfStatus::WantToRead,
+        ShelfStatus::CurrentlyReading,
+        ShelfStatus::Read,
+        ShelfStatus::Abandoned,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ShelfStatus::WantToRead => "want_to_read",
+            ShelfStatus::CurrentlyReading => "currently_reading",
+            ShelfStatus::Read => "read",
+            ShelfStatus::Abandoned => "abandoned",
+        }
+    }
+}
+
+/// A shelf the user named themselves, alongside the standard ones
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct Shelf {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Shelf {
+    pub fn new(user_id: Uuid, name: String) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            name,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// A book on a user's shelves: its standard shelf and any custom ones
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ShelfEntry {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub book_id: Uuid,
+    pub status: ShelfStatus,
+    pub shelf_ids: Vec<Uuid>, // Custom shelves
+    pub started_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub added_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ShelfEntry {
+    pub fn new(user_id: Uuid, book_id: Uuid, status: ShelfStatus) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            book_id,
+            status,
+            shelf_ids: Vec::new(),
+            started_at: None,
+            finished_at: None,
+            added_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+/// A move between standard shelves; `from_status` is `None` when the book was
+/// first shelved and `to_status` is `None` when it was taken off the shelves
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ShelfStatusChange {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub book_id: Uuid,
+    pub from_status: Option<ShelfStatus>,
+    pub to_status: Option<ShelfStatus>,
+    pub changed_at: DateTime<Utc>,
+}
+
+impl ShelfStatusChange {
+    pub fn new(user_id: Uuid, book_id: Uuid, from_status: Option<ShelfStatus>, to_status: Option<ShelfStatus>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            book_id,
+            from_status,
+            to_status,
+            changed_at: Utc::now(),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use chrono::NaiveDate;
+use utoipa::ToSchema;
+
+/// A reader's statistics, with dates bucketed in `time_zone`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReadingStats {
+    pub time_zone: String,
+    pub totals: ReadingTotals,
+    pub by_year: Vec<PeriodStats>,
+    pub by_month: Vec<PeriodStats>,
+    pub streaks: ReadingStreaks,
+    pub genres: Vec<BreakdownEntry>,
+    pub authors: Vec<BreakdownEntry>,
+    pub decades: Vec<BreakdownEntry>, // By publication year, e.g. "1990s"
+    /// Number of 1★ to 5★ ratings, each rounded to the nearest star
+    pub rating_distribution: Vec<i32>,
+    pub average_rating: Option<f64>,
+    pub year_in_review: YearInReview,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ReadingTotals {
+    pub books_read: i32,
+    pub pages_read: i32,
+    pub sessions_count: i32,
+    pub minutes_read: i32,
+    pub average_session_minutes: Option<f64>,
+}
+
+/// Books finished and pages read in a year ("2025") or month ("2025-03")
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct PeriodStats {
+    pub period: String,
+    pub books_read: i32,
+    pub pages_read: i32,
+    pub minutes_read: i32,
+}
+
+/// Consecutive local days with at least one reading session
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ReadingStreaks {
+    /// Ending today, or yesterday if the reader hasn't read yet today
+    pub current_days: i32,
+    pub longest_days: i32,
+    pub longest_started_on: Option<NaiveDate>,
+    pub longest_ended_on: Option<NaiveDate>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct BreakdownEntry {
+    pub name: String,
+    pub books_read: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct BookHighlight {
+    pub book_id: Uuid,
+    pub title: String,
+    pub author: String,
+    pub page_count: i32,
+    pub my_rating: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct YearInReview {
+    pub year: i32,
+    pub books_read: i32,
+    pub pages_read: i32,
+    pub minutes_read: i32,
+    pub longest_streak_days: i32,
+    pub busiest_month: Option<String>, // "2025-03", the month with the most books finished
+    pub top_genre: Option<String>,
+    pub top_author: Option<String>,
+    pub longest_book: Option<BookHighlight>,
+    pub shortest_book: Option<BookHighlight>,
+    pub highest_rated: Option<BookHighlight>,
+    pub first_book: Option<BookHighlight>,
+    pub last_book: Option<BookHighlight>,
+}
@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use utoipa::ToSchema;
+
+/// A reader-suggested tag on a book, with its vote tally
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BookTag {
+    pub id: Uuid,
+    pub book_id: Uuid,
+    pub tag: String, // Normalized key, e.g. "found family"
+    pub suggested_by: Uuid,
+    pub upvotes: i32,
+    pub downvotes: i32,
+    pub status: TagStatus,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub reviewed_by: Option<Uuid>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TagStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+/// One user's vote on a book tag, +1 or -1
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TagVote {
+    pub id: Uuid,
+    pub book_tag_id: Uuid,
+    pub user_id: Uuid,
+    pub value: i8,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A tag curators have banned from being suggested on any book
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BlockedTag {
+    pub id: Uuid,
+    pub tag: String,
+    pub reason: Option<String>,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl BookTag {
+    pub fn new(book_id: Uuid, tag: String, suggested_by: Uuid) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            book_id,
+            tag,
+            suggested_by,
+            upvotes: 0,
+            downvotes: 0,
+            status: TagStatus::Pending,
+            created_at: now,
+            updated_at: now,
+            reviewed_by: None,
+            reviewed_at: None,
+        }
+    }
+
+    pub fn score(&self) -> i32 {
+        self.upvotes - self.downvotes
+    }
+
+    pub fn review(&mut self, status: TagStatus, reviewed_by: Uuid) {
+        self.status = status;
+        self.reviewed_by = Some(reviewed_by);
+        self.reviewed_at = Some(Utc::now());
+        self.updated_at = Utc::now();
+    }
+}
+
+impl TagVote {
+    pub fn new(book_tag_id: Uuid, user_id: Uuid, value: i8) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            book_tag_id,
+            user_id,
+            value,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+impl BlockedTag {
+    pub fn new(tag: String, reason: Option<String>, created_by: Uuid) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            tag,
+            reason,
+            created_by,
+            created_at: Utc::now(),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use chrono::{DateTime, Duration, Utc};
+use utoipa::ToSchema;
+
+/// How far back trending looks. Activity counts less the older it is, halving
+/// every quarter of the window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TrendingWindow {
+    Day,
+    #[default]
+    Week,
+    Month,
+}
+
+impl TrendingWindow {
+    pub const ALL: [TrendingWindow; 3] = [TrendingWindow::Day, TrendingWindow::Week, TrendingWindow::Month];
+
+    pub fn duration(&self) -> Duration {
+        match self {
+            TrendingWindow::Day => Duration::days(1),
+            TrendingWindow::Week => Duration::days(7),
+            TrendingWindow::Month => Duration::days(30),
+        }
+    }
+
+    pub fn half_life(&self) -> Duration {
+        self.duration() / 4
+    }
+}
+
+/// A precomputed discovery list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TrendingList {
+    Trending(TrendingWindow),
+    NewAndNotable,
+}
+
+impl TrendingList {
+    /// Key the list is stored under, e.g. "trending_week"
+    pub fn key(&self) -> String {
+        match self {
+            TrendingList::Trending(TrendingWindow::Day) => "trending_day".to_string(),
+            TrendingList::Trending(TrendingWindow::Week) => "trending_week".to_string(),
+            TrendingList::Trending(TrendingWindow::Month) => "trending_month".to_string(),
+            TrendingList::NewAndNotable => "new_and_notable".to_string(),
+        }
+    }
+}
+
+/// One ranked book of a discovery list, as of the last refresh
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TrendingEntry {
+    pub id: Uuid,
+    pub list: String, // TrendingList::key
+    pub rank: i32,
+    pub book_id: Uuid,
+    pub score: f64,
+    pub recent_ratings: i32,
+    pub recent_clicks: i32,
+    pub computed_at: DateTime<Utc>,
+}
+
+impl TrendingEntry {
+    pub fn new(
+        list: TrendingList,
+        rank: i32,
+        book_id: Uuid,
+        score: f64,
+        recent_ratings: i32,
+        recent_clicks: i32,
+        computed_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            list: list.key(),
+            rank,
+            book_id,
+            score,
+            recent_ratings,
+            recent_clicks,
+            computed_at,
+        }
+    }
+}
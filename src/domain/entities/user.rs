@@ -12,9 +12,22 @@ pub struct User {
     pub first_name: String,
     pub last_name: String,
     pub preferences: Vec<String>, // Book genres/categories
+    #[serde(default)]
+    pub role: UserRole,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub is_active: bool,
+    #[serde(default)]
+    pub deleted_at: Option<DateTime<Utc>>, // Soft delete tombstone
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum UserRole {
+    #[default]
+    Reader,
+    Curator, // Can edit the catalog
+    Admin,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -45,12 +58,22 @@ impl User {
             first_name,
             last_name,
             preferences: Vec::new(),
+            role: UserRole::Reader,
             created_at: now,
             updated_at: now,
             is_active: true,
+            deleted_at: None,
         }
     }
 
+    pub fn is_admin(&self) -> bool {
+        self.role == UserRole::Admin
+    }
+
+    pub fn is_curator(&self) -> bool {
+        matches!(self.role, UserRole::Curator | UserRole::Admin)
+    }
+
     pub fn to_profile(&self) -> UserProfile {
         UserProfile {
             id: self.id,
@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use utoipa::ToSchema;
+
+/// An abstract work. Each `Book` record is one edition of a work, so ratings
+/// and recommendations can be aggregated across formats and translations.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct Work {
+    pub id: Uuid,
+    pub title: String,
+    pub author: String,
+    pub description: String,
+    pub genre: Vec<String>,
+    pub original_language: String,
+    pub original_publication_year: i32,
+    pub series_id: Option<Uuid>,
+    pub series_position: Option<f64>, // Fractional positions allow novellas between volumes (e.g. 2.5)
+    pub average_rating: f64, // Across all editions
+    pub ratings_count: i32,
+    #[serde(default)]
+    pub rating_sum: f64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub created_by: Uuid,
+}
+
+impl Work {
+    pub fn new(
+        title: String,
+        author: String,
+        description: String,
+        genre: Vec<String>,
+        original_language: String,
+        original_publication_year: i32,
+        created_by: Uuid,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            title,
+            author,
+            description,
+            genre,
+            original_language,
+            original_publication_year,
+            series_id: None,
+            series_position: None,
+            average_rating: 0.0,
+            ratings_count: 0,
+            rating_sum: 0.0,
+            created_at: now,
+            updated_at: now,
+            created_by,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct Series {
+    pub id: Uuid,
+    pub title: String,
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub created_by: Uuid,
+}
+
+impl Series {
+    pub fn new(title: String, description: Option<String>, created_by: Uuid) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            title,
+            description,
+            created_at: now,
+            updated_at: now,
+            created_by,
+        }
+    }
+}
+
+/// Physical or digital format of an edition
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EditionFormat {
+    Hardcover,
+    Paperback,
+    Ebook,
+    Audiobook,
+    #[default]
+    Unknown,
+}
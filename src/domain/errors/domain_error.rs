@@ -17,12 +17,72 @@ pub enum DomainError {
     #[error("Book not found")]
     BookNotFound,
     
+    #[error("Author not found")]
+    AuthorNotFound,
+    
+    #[error("Work not found")]
+    WorkNotFound,
+    
+    #[error("Series not found")]
+    SeriesNotFound,
+    
+    #[error("Genre not found")]
+    GenreNotFound,
+    
+    #[error("Tag not found")]
+    TagNotFound,
+    
+    #[error("Revision not found")]
+    RevisionNotFound,
+    
+    #[error("Rating not found")]
+    RatingNotFound,
+    
+    #[error("Review not found")]
+    ReviewNotFound,
+    
+    #[error("Notification not found")]
+    NotificationNotFound,
+    
+    #[error("Reading session not found")]
+    ReadingSessionNotFound,
+    
+    #[error("Reading session already ended")]
+    ReadingSessionAlreadyEnded,
+    
+    #[error("A reading session on this book is already in progress")]
+    ReadingSessionInProgress,
+    
+    #[error("Shelf not found")]
+    ShelfNotFound,
+    
+    #[error("Book is not on your shelves")]
+    ShelfEntryNotFound,
+    
+    #[error("Reading goal not found")]
+    GoalNotFound,
+    
+    #[error("Challenge not found")]
+    ChallengeNotFound,
+    
+    #[error("Already taking part in this challenge")]
+    ChallengeAlreadyJoined,
+    
+    #[error("Duplicate candidate not found")]
+    DuplicateCandidateNotFound,
+    
     #[error("Unauthorized access")]
     Unauthorized,
     
     #[error("Invalid input: {0}")]
     InvalidInput(String),
     
+    #[error("Payload too large: {0}")]
+    PayloadTooLarge(String),
+    
+    #[error("Unsupported media type: {0}")]
+    UnsupportedMediaType(String),
+    
     #[error("External service error: {0}")]
     ExternalServiceError(String),
     
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::entities::author::{Author, Publisher, BookContributor};
+
+#[async_trait]
+pub trait AuthorRepository: Send + Sync {
+    async fn save(&self, author: Author) -> Result<Author>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Author>>;
+    async fn find_by_name_key(&self, name_key: &str) -> Result<Option<Author>>;
+    async fn update(&self, author: Author) -> Result<Author>;
+
+    // Contributor relation
+    async fn save_contributor(&self, contributor: BookContributor) -> Result<BookContributor>;
+    async fn get_contributors_for_book(&self, book_id: Uuid) -> Result<Vec<BookContributor>>;
+    async fn get_contributions_for_author(&self, author_id: Uuid) -> Result<Vec<BookContributor>>;
+
+    // Publisher operations
+    async fn save_publisher(&self, publisher: Publisher) -> Result<Publisher>;
+    async fn find_publisher_by_id(&self, id: Uuid) -> Result<Option<Publisher>>;
+    async fn find_publisher_by_name_key(&self, name_key: &str) -> Result<Option<Publisher>>;
+}
@@ -1,24 +1,74 @@
 use async_trait::async_trait;
 use anyhow::Result;
 use uuid::Uuid;
+use chrono::{DateTime, Utc};
 
-use crate::domain::entities::book::{Book, BookRating};
+use crate::domain::entities::{
+    book::{Book, BookRating, ReviewSort},
+    revision::BookRevision,
+};
 
 #[async_trait]
 pub trait BookRepository: Send + Sync {
+    /// Save a new book and record it as revision 1
     async fn save(&self, book: Book) -> Result<Book>;
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Book>>;
+    async fn find_by_isbn(&self, isbn: &str) -> Result<Option<Book>>;
     async fn find_by_title(&self, title: &str) -> Result<Vec<Book>>;
     async fn find_by_author(&self, author: &str) -> Result<Vec<Book>>;
     async fn find_by_genre(&self, genre: &str) -> Result<Vec<Book>>;
     async fn find_all(&self, limit: Option<usize>, offset: Option<usize>) -> Result<Vec<Book>>;
+    /// Up to `limit` books with an ID after `after_id`, soft-deleted ones
+    /// included, for maintenance commands that must visit every book once
+    async fn find_page_by_id(&self, after_id: Option<Uuid>, limit: usize) -> Result<Vec<Book>>;
+    /// Every book with a perceptual cover hash, for cover duplicate checks
+    async fn find_with_cover_hash(&self) -> Result<Vec<Book>>;
+    /// Books added to the catalog at or after `since`
+    async fn find_created_since(&self, since: DateTime<Utc>) -> Result<Vec<Book>>;
+    /// Books sharing a genre, tag or author with `book`, most rated first
+    async fn find_similar_candidates(&self, book: &Book, limit: usize) -> Result<Vec<Book>>;
+    /// Update a book, recording a revision attributed to `book.updated_by`
+    /// when any catalog field changed
     async fn update(&self, book: Book) -> Result<Book>;
+    /// Soft delete: the book is hidden from every query but can be restored
+    /// until it is purged
     async fn delete(&self, id: Uuid) -> Result<()>;
+    async fn find_deleted(&self, limit: Option<usize>, offset: Option<usize>) -> Result<Vec<Book>>;
+    async fn restore(&self, id: Uuid) -> Result<Option<Book>>;
+    /// Hard delete books deleted before `deleted_before`, with their ratings,
+    /// recommendations, reading sessions, contributors, tags and revisions.
+    /// Returns the number of books purged.
+    async fn purge_deleted(&self, deleted_before: DateTime<Utc>) -> Result<usize>;
     
-    // Rating operations
+    // Rating operations. Saving, updating and deleting a rating adjust the
+    // book's and work's rating stats in the same transaction.
     async fn save_rating(&self, rating: BookRating) -> Result<BookRating>;
+    async fn find_rating(&self, id: Uuid) -> Result<Option<BookRating>>;
+    /// Write the rating, review, status and moderation reasons; vote and
+    /// report tallies are left to their recounts
+    async fn update_rating(&self, rating: BookRating) -> Result<BookRating>;
+    async fn delete_rating(&self, rating: &BookRating) -> Result<()>;
     async fn get_ratings_for_book(&self, book_id: Uuid) -> Result<Vec<BookRating>>;
+    /// Ratings of a book that carry a published review
+    async fn get_reviews_for_book(
+        &self,
+        book_id: Uuid,
+        sort: ReviewSort,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<BookRating>>;
     async fn get_user_rating(&self, book_id: Uuid, user_id: Uuid) -> Result<Option<BookRating>>;
-    async fn update_book_rating_stats(&self, book_id: Uuid) -> Result<()>;
+    /// Ratings posted at or after `since`, across all books
+    async fn get_ratings_since(&self, since: DateTime<Utc>) -> Result<Vec<BookRating>>;
+    /// Ratings of other books by up to `max_raters` of the book's most recent raters
+    async fn get_co_ratings(&self, book_id: Uuid, max_raters: usize) -> Result<Vec<BookRating>>;
+    /// Recompute the book's rating stats from its stored ratings. Returns the
+    /// updated book, or `None` if it doesn't exist.
+    async fn update_book_rating_stats(&self, book_id: Uuid) -> Result<Option<Book>>;
     async fn get_user_ratings(&self, user_id: Uuid) -> Result<Vec<BookRating>>;
+    async fn get_user_ratings_page(&self, user_id: Uuid, limit: Option<usize>, offset: Option<usize>) -> Result<Vec<BookRating>>;
+    
+    // Revision operations
+    async fn get_revisions(&self, book_id: Uuid, limit: Option<usize>, offset: Option<usize>) -> Result<Vec<BookRevision>>;
+    async fn get_revision(&self, book_id: Uuid, revision_number: i32) -> Result<Option<BookRevision>>;
 }
\ No newline at end of file
@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use anyhow::Result;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+
+use crate::domain::entities::challenge::{Challenge, ChallengeParticipant};
+
+#[async_trait]
+pub trait ChallengeRepository: Send + Sync {
+    async fn save(&self, challenge: Challenge) -> Result<Challenge>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Challenge>>;
+    /// Challenges by start date, newest first; only those running at `active_at` if given
+    async fn find_all(&self, active_at: Option<DateTime<Utc>>) -> Result<Vec<Challenge>>;
+    /// Delete the challenge along with its participants
+    async fn delete(&self, id: Uuid) -> Result<()>;
+
+    // Participants
+    async fn join(&self, participant: ChallengeParticipant) -> Result<ChallengeParticipant>;
+    async fn leave(&self, challenge_id: Uuid, user_id: Uuid) -> Result<()>;
+    async fn find_participant(&self, challenge_id: Uuid, user_id: Uuid) -> Result<Option<ChallengeParticipant>>;
+    /// Everyone taking part, earliest to join first
+    async fn find_participants(&self, challenge_id: Uuid) -> Result<Vec<ChallengeParticipant>>;
+    async fn count_participants(&self, challenge_id: Uuid) -> Result<usize>;
+}
@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::entities::{
+    book::Book,
+    duplicate::{DuplicateCandidate, BookRedirect},
+    revision::BookRevision,
+};
+
+#[async_trait]
+pub trait DuplicateRepository: Send + Sync {
+    // Review queue operations
+    async fn save_candidate(&self, candidate: DuplicateCandidate) -> Result<DuplicateCandidate>;
+    async fn find_candidate(&self, id: Uuid) -> Result<Option<DuplicateCandidate>>;
+    async fn find_candidate_for_pair(&self, book_id: Uuid, duplicate_book_id: Uuid) -> Result<Option<DuplicateCandidate>>;
+    async fn find_pending_candidates(&self, limit: Option<usize>, offset: Option<usize>) -> Result<Vec<DuplicateCandidate>>;
+    async fn update_candidate(&self, candidate: DuplicateCandidate) -> Result<DuplicateCandidate>;
+
+    // Merge operations
+    /// Move ratings, recommendations and reading sessions from `source_id` onto
+    /// `survivor`, copy the survivor's catalog fields (with `revision`, if any),
+    /// delete the source book and leave a redirect, atomically
+    async fn merge_books(&self, source_id: Uuid, survivor: Book, revision: Option<BookRevision>) -> Result<BookRedirect>;
+    async fn find_redirect(&self, book_id: Uuid) -> Result<Option<BookRedirect>>;
+}
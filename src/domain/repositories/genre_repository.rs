@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::entities::genre::Genre;
+
+#[async_trait]
+pub trait GenreRepository: Send + Sync {
+    async fn save(&self, genre: Genre) -> Result<Genre>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Genre>>;
+    async fn find_by_slug(&self, slug: &str) -> Result<Option<Genre>>;
+    async fn find_all(&self) -> Result<Vec<Genre>>;
+    async fn find_children(&self, parent_id: Uuid) -> Result<Vec<Genre>>;
+    async fn update(&self, genre: Genre) -> Result<Genre>;
+    async fn delete(&self, id: Uuid) -> Result<()>;
+}
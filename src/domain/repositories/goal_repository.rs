@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::entities::goal::ReadingGoal;
+
+#[async_trait]
+pub trait GoalRepository: Send + Sync {
+    /// Create or replace the goal
+    async fn save(&self, goal: ReadingGoal) -> Result<ReadingGoal>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ReadingGoal>>;
+    /// A user's goals, most recent period first, optionally for one year
+    async fn find_for_user(&self, user_id: Uuid, year: Option<i32>) -> Result<Vec<ReadingGoal>>;
+    async fn delete(&self, id: Uuid) -> Result<()>;
+}
@@ -6,7 +6,29 @@
 pub mod user_repository;
 pub mod book_repository;
 pub mod recommendation_repository;
+pub mod duplicate_repository;
+pub mod work_repository;
+pub mod author_repository;
+pub mod genre_repository;
+pub mod tag_repository;
+pub mod review_repository;
+pub mod notification_repository;
+pub mod trending_repository;
+pub mod shelf_repository;
+pub mod goal_repository;
+pub mod challenge_repository;
 
 pub use user_repository::*;
 pub use book_repository::*;
-pub use recommendation_repository::*;
\ No newline at end of file
+pub use recommendation_repository::*;
+pub use duplicate_repository::*;
+pub use work_repository::*;
+pub use author_repository::*;
+pub use genre_repository::*;
+pub use tag_repository::*;
+pub use review_repository::*;
+pub use notification_repository::*;
+pub use trending_repository::*;
+pub use shelf_repository::*;
+pub use goal_repository::*;
+pub use challenge_repository::*;
\ No newline at end of file
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::entities::notification::Notification;
+
+#[async_trait]
+pub trait NotificationRepository: Send + Sync {
+    async fn save(&self, notification: Notification) -> Result<Notification>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Notification>>;
+    /// A user's notifications, newest first
+    async fn find_for_user(
+        &self,
+        user_id: Uuid,
+        unread_only: bool,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<Notification>>;
+    async fn update(&self, notification: Notification) -> Result<Notification>;
+}
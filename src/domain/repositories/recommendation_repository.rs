@@ -1,6 +1,7 @@
 use async_trait::async_trait;
 use anyhow::Result;
 use uuid::Uuid;
+use chrono::{DateTime, Utc};
 
 use crate::domain::entities::recommendation::{Recommendation, UserPreference, ReadingSession};
 
@@ -12,6 +13,8 @@ pub trait RecommendationRepository: Send + Sync {
     async fn mark_recommendation_clicked(&self, recommendation_id: Uuid) -> Result<()>;
     async fn mark_recommendation_purchased(&self, recommendation_id: Uuid) -> Result<()>;
     async fn delete_old_recommendations(&self, user_id: Uuid, days: i32) -> Result<()>;
+    /// Recommendations first clicked at or after `since`
+    async fn get_clicked_since(&self, since: DateTime<Utc>) -> Result<Vec<Recommendation>>;
     
     // User preference operations
     async fn save_user_preference(&self, preference: UserPreference) -> Result<UserPreference>;
@@ -21,8 +24,21 @@ pub trait RecommendationRepository: Send + Sync {
     
     // Reading session operations
     async fn start_reading_session(&self, session: ReadingSession) -> Result<ReadingSession>;
-    async fn end_reading_session(&self, session_id: Uuid, pages_read: i32, duration_minutes: i32) -> Result<()>;
+    async fn find_reading_session(&self, session_id: Uuid) -> Result<Option<ReadingSession>>;
+    /// Store the end of a session; `None` if it had already ended
+    async fn end_reading_session(&self, session: ReadingSession) -> Result<Option<ReadingSession>>;
     async fn get_user_reading_sessions(&self, user_id: Uuid, limit: Option<usize>) -> Result<Vec<ReadingSession>>;
+    /// Every session of the user on the book, oldest first
+    async fn get_book_reading_sessions(&self, user_id: Uuid, book_id: Uuid) -> Result<Vec<ReadingSession>>;
+    /// Every ended session of the user, oldest first
+    async fn get_ended_reading_sessions(&self, user_id: Uuid) -> Result<Vec<ReadingSession>>;
+    /// The user's sessions that ended in `[from, to)`
+    async fn get_reading_sessions_between(
+        &self,
+        user_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<ReadingSession>>;
     
     // Analytics
     async fn get_recommendation_click_rate(&self, user_id: Uuid) -> Result<f64>;
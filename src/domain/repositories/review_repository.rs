@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::entities::{
+    book::BookRating,
+    review::{ReviewVote, ReviewReport, ReportStatus},
+};
+
+#[async_trait]
+pub trait ReviewRepository: Send + Sync {
+    // Helpfulness votes
+    async fn find_vote(&self, rating_id: Uuid, user_id: Uuid) -> Result<Option<ReviewVote>>;
+    async fn save_vote(&self, vote: ReviewVote) -> Result<ReviewVote>;
+    async fn delete_vote(&self, id: Uuid) -> Result<()>;
+    /// Recount the review's helpful/not helpful votes from the vote rows and
+    /// rescore its helpfulness in the same write
+    async fn update_review_vote_counts(&self, rating_id: Uuid) -> Result<BookRating>;
+
+    // Abuse reports
+    async fn find_report(&self, rating_id: Uuid, reported_by: Uuid) -> Result<Option<ReviewReport>>;
+    async fn find_open_reports(&self, rating_id: Uuid) -> Result<Vec<ReviewReport>>;
+    async fn save_report(&self, report: ReviewReport) -> Result<ReviewReport>;
+    /// Close every open report on a review with the moderator's decision
+    async fn resolve_reports(&self, rating_id: Uuid, status: ReportStatus, resolved_by: Uuid) -> Result<()>;
+    /// Recount the review's open reports from the report rows
+    async fn update_review_report_count(&self, rating_id: Uuid) -> Result<BookRating>;
+    /// Reviews held by the moderation filters, oldest first
+    async fn find_pending_reviews(&self, limit: Option<usize>, offset: Option<usize>) -> Result<Vec<BookRating>>;
+    /// Reviews with open reports, most reported first
+    async fn find_reported_reviews(&self, limit: Option<usize>, offset: Option<usize>) -> Result<Vec<BookRating>>;
+}
@@ -0,0 +1,42 @@
+use async_trait::async_trait;
+use anyhow::Result;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+
+use crate::domain::entities::shelf::{Shelf, ShelfEntry, ShelfStatus, ShelfStatusChange};
+
+#[async_trait]
+pub trait ShelfRepository: Send + Sync {
+    // Custom shelves
+    async fn create_shelf(&self, shelf: Shelf) -> Result<Shelf>;
+    async fn find_shelf(&self, id: Uuid) -> Result<Option<Shelf>>;
+    async fn find_shelves_for_user(&self, user_id: Uuid) -> Result<Vec<Shelf>>;
+    /// Delete the shelf and take it off every entry; the books stay shelved
+    async fn delete_shelf(&self, id: Uuid) -> Result<()>;
+
+    // Shelved books
+    async fn find_entry(&self, user_id: Uuid, book_id: Uuid) -> Result<Option<ShelfEntry>>;
+    /// Store the entry, logging the status change with it when there is one
+    async fn save_entry(&self, entry: ShelfEntry, change: Option<ShelfStatusChange>) -> Result<ShelfEntry>;
+    async fn delete_entry(&self, entry: ShelfEntry, change: ShelfStatusChange) -> Result<()>;
+    /// A user's entries, most recently updated first, on a standard and/or custom shelf
+    async fn find_entries(
+        &self,
+        user_id: Uuid,
+        status: Option<ShelfStatus>,
+        shelf_id: Option<Uuid>,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<ShelfEntry>>;
+    /// Every entry of the user, for export and recommendations
+    async fn find_all_entries(&self, user_id: Uuid) -> Result<Vec<ShelfEntry>>;
+    /// Books the users finished (on the read shelf) in `[from, to)`, for goals and challenges
+    async fn find_finished_between(
+        &self,
+        user_ids: &[Uuid],
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<ShelfEntry>>;
+    /// A book's moves between shelves, oldest first
+    async fn find_status_changes(&self, user_id: Uuid, book_id: Uuid) -> Result<Vec<ShelfStatusChange>>;
+}
@@ -0,0 +1,30 @@
+use async_trait::async_trait;
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::entities::tag::{BookTag, TagVote, BlockedTag, TagStatus};
+
+#[async_trait]
+pub trait TagRepository: Send + Sync {
+    // Book tag operations
+    async fn save_tag(&self, tag: BookTag) -> Result<BookTag>;
+    async fn find_tag(&self, id: Uuid) -> Result<Option<BookTag>>;
+    async fn find_tag_for_book(&self, book_id: Uuid, tag: &str) -> Result<Option<BookTag>>;
+    async fn find_tags_for_book(&self, book_id: Uuid, status: Option<TagStatus>) -> Result<Vec<BookTag>>;
+    async fn find_tags_by_name(&self, tag: &str) -> Result<Vec<BookTag>>;
+    async fn find_pending_tags(&self, limit: Option<usize>, offset: Option<usize>) -> Result<Vec<BookTag>>;
+    async fn update_tag(&self, tag: BookTag) -> Result<BookTag>;
+
+    // Vote operations
+    async fn find_vote(&self, book_tag_id: Uuid, user_id: Uuid) -> Result<Option<TagVote>>;
+    async fn save_vote(&self, vote: TagVote) -> Result<TagVote>;
+    async fn delete_vote(&self, id: Uuid) -> Result<()>;
+    /// Recount the tag's up/down votes from the vote rows
+    async fn update_tag_vote_counts(&self, book_tag_id: Uuid) -> Result<BookTag>;
+
+    // Blocklist operations
+    async fn find_blocked_tag(&self, tag: &str) -> Result<Option<BlockedTag>>;
+    async fn find_blocked_tags(&self) -> Result<Vec<BlockedTag>>;
+    async fn save_blocked_tag(&self, blocked: BlockedTag) -> Result<BlockedTag>;
+    async fn delete_blocked_tag(&self, tag: &str) -> Result<()>;
+}
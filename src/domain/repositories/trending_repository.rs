@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use anyhow::Result;
+
+use crate::domain::entities::trending::{TrendingEntry, TrendingList};
+
+#[async_trait]
+pub trait TrendingRepository: Send + Sync {
+    /// Swap a list's entries for freshly computed ones in one transaction, so
+    /// readers never see a half-written list
+    async fn replace_list(&self, list: TrendingList, entries: Vec<TrendingEntry>) -> Result<()>;
+    /// A list's entries as of the last refresh, best ranked first
+    async fn find_list(&self, list: TrendingList, limit: usize) -> Result<Vec<TrendingEntry>>;
+}
@@ -1,6 +1,7 @@
 use async_trait::async_trait;
 use anyhow::Result;
 use uuid::Uuid;
+use chrono::{DateTime, Utc};
 
 use crate::domain::entities::user::User;
 
@@ -8,10 +9,20 @@ use crate::domain::entities::user::User;
 pub trait UserRepository: Send + Sync {
     async fn save(&self, user: User) -> Result<User>;
     async fn find_by_id(&self, id: Uuid) -> Result<Option<User>>;
+    /// Also finds deleted users, so their email stays reserved until purge
     async fn find_by_email(&self, email: &str) -> Result<Option<User>>;
+    /// Also finds deleted users, so their username stays reserved until purge
     async fn find_by_username(&self, username: &str) -> Result<Option<User>>;
     async fn update(&self, user: User) -> Result<User>;
+    /// Soft delete: the user is deactivated and hidden but can be restored
+    /// until they are purged
     async fn delete(&self, id: Uuid) -> Result<()>;
+    async fn find_deleted(&self, limit: Option<usize>, offset: Option<usize>) -> Result<Vec<User>>;
+    async fn restore(&self, id: Uuid) -> Result<Option<User>>;
+    /// Hard delete users deleted before `deleted_before`, with their ratings,
+    /// recommendations, preferences, reading sessions and tag votes.
+    /// Returns the number of users purged.
+    async fn purge_deleted(&self, deleted_before: DateTime<Utc>) -> Result<usize>;
     async fn find_all(&self, limit: Option<usize>, offset: Option<usize>) -> Result<Vec<User>>;
     async fn update_preferences(&self, user_id: Uuid, preferences: Vec<String>) -> Result<()>;
     async fn deactivate_user(&self, id: Uuid) -> Result<()>;
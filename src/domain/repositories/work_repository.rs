@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::entities::{
+    book::Book,
+    work::{Work, Series},
+};
+
+#[async_trait]
+pub trait WorkRepository: Send + Sync {
+    async fn save(&self, work: Work) -> Result<Work>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Work>>;
+    async fn update(&self, work: Work) -> Result<Work>;
+    async fn find_editions(&self, work_id: Uuid) -> Result<Vec<Book>>;
+    async fn update_work_rating_stats(&self, work_id: Uuid) -> Result<()>;
+
+    // Series operations
+    async fn save_series(&self, series: Series) -> Result<Series>;
+    async fn find_series_by_id(&self, id: Uuid) -> Result<Option<Series>>;
+    async fn find_works_in_series(&self, series_id: Uuid) -> Result<Vec<Work>>;
+}
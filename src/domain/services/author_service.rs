@@ -0,0 +1,105 @@
+use crate::domain::entities::book::Book;
+
+/// Domain service for matching and aggregating authors and publishers
+pub struct AuthorService;
+
+impl AuthorService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Matching key for a person or company name: lowercase letters and digits
+    /// only, so "J.R.R. Tolkien" and "J. R. R. Tolkien" share a key
+    pub fn normalize_name(name: &str) -> String {
+        name.to_lowercase()
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .collect()
+    }
+
+    /// Matching keys for a name and its aliases, without duplicates
+    pub fn name_keys(&self, name: &str, aliases: &[String]) -> Vec<String> {
+        let mut keys = Vec::new();
+        for candidate in std::iter::once(name).chain(aliases.iter().map(String::as_str)) {
+            let key = Self::normalize_name(candidate);
+            if !key.is_empty() && !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+        keys
+    }
+
+    /// Split a free-text credit such as "Neil Gaiman & Terry Pratchett" into
+    /// individual names. A single "Last, First" credit is turned around.
+    pub fn split_credit(&self, credit: &str) -> Vec<String> {
+        let comma_parts: Vec<&str> = credit.split(',').map(str::trim).collect();
+        if comma_parts.len() == 2
+            && !comma_parts[0].is_empty()
+            && !comma_parts[0].contains(char::is_whitespace)
+            && !comma_parts[1].contains('&')
+            && !comma_parts[1].contains(" and ")
+        {
+            return vec![format!("{} {}", comma_parts[1], comma_parts[0]).trim().to_string()];
+        }
+
+        credit
+            .replace(" and ", ",")
+            .split(|c| c == ',' || c == ';' || c == '&')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(String::from)
+            .collect()
+    }
+
+    /// Average rating across books, weighted by how many ratings each has
+    pub fn aggregate_rating(&self, books: &[Book]) -> (f64, i32) {
+        let ratings_count: i32 = books.iter().map(|book| book.ratings_count).sum();
+        if ratings_count == 0 {
+            return (0.0, 0);
+        }
+
+        let total: f64 = books
+            .iter()
+            .map(|book| book.average_rating * book.ratings_count as f64)
+            .sum();
+
+        (total / ratings_count as f64, ratings_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_name_keys_match_spelling_variants() {
+        let service = AuthorService::new();
+        let keys = service.name_keys("J.R.R. Tolkien", &["J. R. R. Tolkien".to_string(), "John Ronald Reuel Tolkien".to_string()]);
+        assert_eq!(keys, vec!["jrrtolkien", "johnronaldreueltolkien"]);
+    }
+
+    #[test]
+    fn test_split_credit() {
+        let service = AuthorService::new();
+        assert_eq!(service.split_credit("Neil Gaiman & Terry Pratchett"), vec!["Neil Gaiman", "Terry Pratchett"]);
+        assert_eq!(service.split_credit("Tolkien, J.R.R."), vec!["J.R.R. Tolkien"]);
+        assert_eq!(service.split_credit("Douglas Preston, Lincoln Child"), vec!["Douglas Preston", "Lincoln Child"]);
+        assert_eq!(service.split_credit("Ursula K. Le Guin"), vec!["Ursula K. Le Guin"]);
+    }
+
+    #[test]
+    fn test_aggregate_rating_is_weighted() {
+        let service = AuthorService::new();
+        let mut popular = Book::new("A".into(), "X".into(), "d".into(), vec![], 2000, "P".into(), "en".into(), 1, Uuid::new_v4());
+        popular.average_rating = 4.0;
+        popular.ratings_count = 300;
+        let mut niche = popular.clone();
+        niche.average_rating = 5.0;
+        niche.ratings_count = 100;
+
+        let (average, count) = service.aggregate_rating(&[popular, niche]);
+        assert_eq!(count, 400);
+        assert!((average - 4.25).abs() < f64::EPSILON);
+    }
+}
@@ -0,0 +1,145 @@
+use anyhow::Result;
+use serde_json::{Map, Value};
+
+use crate::domain::entities::{book::Book, revision::FieldChange};
+
+/// Book fields that are bookkeeping, derived from ratings, or point at stored
+/// cover renditions, not catalog data. They are neither diffed nor restored on
+/// revert; replaced renditions are deleted, so a revert must not bring them back.
+const UNTRACKED_FIELDS: &[&str] = &[
+    "id",
+    "cover_images",
+    "cover_image_url",
+    "cover_hash",
+    "cover_placeholder",
+    "average_rating",
+    "ratings_count",
+    "rating_sum",
+    "rating_histogram",
+    "community_tags",
+    "created_at",
+    "updated_at",
+    "created_by",
+    "updated_by",
+    "deleted_at",
+];
+
+/// Domain service for book revisions: field-level diffs and reverts
+pub struct BookHistoryService;
+
+impl BookHistoryService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Tracked fields of a book as a JSON object
+    pub fn snapshot(book: &Book) -> Result<Value> {
+        let mut fields = match serde_json::to_value(book)? {
+            Value::Object(fields) => fields,
+            _ => Map::new(),
+        };
+        fields.retain(|field, _| !UNTRACKED_FIELDS.contains(&field.as_str()));
+        Ok(Value::Object(fields))
+    }
+
+    /// Field-level changes from `old` to `new`. A missing `old` (a new book)
+    /// lists every tracked field with a null old value.
+    pub fn diff(old: Option<&Book>, new: &Book) -> Result<Vec<FieldChange>> {
+        let old_fields = match old.map(Self::snapshot).transpose()? {
+            Some(Value::Object(fields)) => fields,
+            _ => Map::new(),
+        };
+        let new_fields = match Self::snapshot(new)? {
+            Value::Object(fields) => fields,
+            _ => Map::new(),
+        };
+
+        Ok(new_fields
+            .into_iter()
+            .filter_map(|(field, new_value)| {
+                let old_value = old_fields.get(&field).cloned().unwrap_or(Value::Null);
+                (old_value != new_value).then(|| FieldChange { field, old_value, new_value })
+            })
+            .collect())
+    }
+
+    /// Apply a revision snapshot onto the current book, keeping its ID,
+    /// rating stats and bookkeeping fields
+    pub fn restore(current: &Book, snapshot: &Value) -> Result<Book> {
+        let mut fields = match serde_json::to_value(current)? {
+            Value::Object(fields) => fields,
+            _ => Map::new(),
+        };
+
+        if let Value::Object(snapshot_fields) = snapshot {
+            for (field, value) in snapshot_fields {
+                if !UNTRACKED_FIELDS.contains(&field.as_str()) {
+                    fields.insert(field.clone(), value.clone());
+                }
+            }
+        }
+
+        Ok(serde_json::from_value(Value::Object(fields))?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn book() -> Book {
+        Book::new(
+            "Dune".to_string(),
+            "Frank Herbert".to_string(),
+            "Desert planet".to_string(),
+            vec!["Science Fiction".to_string()],
+            1965,
+            "Chilton".to_string(),
+            "English".to_string(),
+            412,
+            Uuid::new_v4(),
+        )
+    }
+
+    #[test]
+    fn test_diff_reports_changed_fields_only() {
+        let old = book();
+        let mut new = old.clone();
+        new.title = "Dune (Deluxe Edition)".to_string();
+        new.average_rating = 4.5;
+        new.ratings_count = 10;
+
+        let changes = BookHistoryService::diff(Some(&old), &new).unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].field, "title");
+        assert_eq!(changes[0].old_value, Value::from("Dune"));
+        assert_eq!(changes[0].new_value, Value::from("Dune (Deluxe Edition)"));
+    }
+
+    #[test]
+    fn test_diff_of_new_book_lists_tracked_fields() {
+        let changes = BookHistoryService::diff(None, &book()).unwrap();
+        assert!(changes.iter().any(|c| c.field == "title" && c.old_value.is_null()));
+        assert!(!changes.iter().any(|c| c.field == "average_rating" || c.field == "id"));
+    }
+
+    #[test]
+    fn test_restore_keeps_rating_stats_and_cover() {
+        let mut original = book();
+        original.cover_hash = Some("00ff00ff00ff00ff".to_string());
+        let snapshot = BookHistoryService::snapshot(&original).unwrap();
+
+        let mut current = original.clone();
+        current.title = "Vandalized".to_string();
+        current.average_rating = 4.2;
+        current.ratings_count = 7;
+        current.cover_hash = Some("ff00ff00ff00ff00".to_string());
+
+        let restored = BookHistoryService::restore(&current, &snapshot).unwrap();
+        assert_eq!(restored.title, "Dune");
+        assert_eq!(restored.ratings_count, 7);
+        assert_eq!(restored.cover_hash.as_deref(), Some("ff00ff00ff00ff00"));
+        assert_eq!(restored.id, original.id);
+    }
+}
@@ -1,7 +1,12 @@
 use anyhow::Result;
+use chrono::{Datelike, Utc};
 use uuid::Uuid;
 
 use crate::domain::entities::book::{Book, BookRating};
+use crate::domain::services::rating_service::RatingPrior;
+
+/// Earliest publication year a book can have
+pub const MIN_PUBLICATION_YEAR: i32 = 1000;
 
 /// Domain service for book-related business logic
 pub struct BookService;
@@ -28,15 +33,10 @@ impl BookService {
         new_total / new_count as f64
     }
 
-    /// Update book rating statistics
-    pub fn update_book_rating_stats(&self, book: &mut Book, new_rating: f64) {
-        book.average_rating = Self::calculate_new_average_rating(
-            book.average_rating,
-            book.ratings_count,
-            new_rating,
-        );
-        book.ratings_count += 1;
-        book.updated_at = chrono::Utc::now();
+    /// Latest publication year a book can have: announced titles are
+    /// catalogued up to two years ahead of release
+    pub fn latest_publication_year() -> i32 {
+        Utc::now().year() + 2
     }
 
     /// Validate book data before saving
@@ -49,7 +49,7 @@ impl BookService {
             return Err(anyhow::anyhow!("Book author cannot be empty"));
         }
 
-        if book.publication_year < 1000 || book.publication_year > 2024 {
+        if book.publication_year < MIN_PUBLICATION_YEAR || book.publication_year > Self::latest_publication_year() {
             return Err(anyhow::anyhow!("Invalid publication year"));
         }
 
@@ -60,16 +60,27 @@ impl BookService {
         Ok(())
     }
 
+    /// ISBN as stored and looked up: digits and a trailing check character
+    /// "X", without hyphens or spaces ("0-306-40615-x" -> "030640615X")
+    pub fn normalize_isbn(isbn: &str) -> String {
+        isbn.chars()
+            .filter(|c| c.is_ascii_digit() || c.eq_ignore_ascii_case(&'x'))
+            .map(|c| c.to_ascii_uppercase())
+            .collect()
+    }
+
     /// Check if user can rate a book (hasn't rated it before)
     pub fn can_user_rate_book(&self, existing_rating: Option<BookRating>) -> bool {
         existing_rating.is_none()
     }
 
-    /// Generate book recommendation reason
+    /// Generate book recommendation reason. Only books whose weighted rating
+    /// is high are called highly rated, not ones with a few glowing ratings.
     pub fn generate_recommendation_reason(
         book: &Book,
         user_preferences: &[String],
         score: f64,
+        rating_prior: &RatingPrior,
     ) -> String {
         let matching_genres: Vec<&String> = book.genre
             .iter()
@@ -82,7 +93,7 @@ impl BookService {
                 matching_genres.join(", "),
                 score * 100.0
             )
-        } else if book.average_rating > 4.0 {
+        } else if rating_prior.weighted_rating(book.average_rating, book.ratings_count) > 4.0 {
             format!(
                 "Highly rated book ({}★) that might interest you (confidence: {:.1}%)",
                 book.average_rating,
@@ -115,4 +126,34 @@ mod tests {
         let average = BookService::calculate_new_average_rating(4.0, 2, 5.0);
         assert!((average - 4.333333333333333).abs() < f64::EPSILON);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_validate_book_accepts_announced_titles() {
+        let service = BookService::new();
+        let mut book = Book::new(
+            "Dune".to_string(),
+            "Frank Herbert".to_string(),
+            "Desert planet".to_string(),
+            vec!["Science Fiction".to_string()],
+            Utc::now().year() + 1,
+            "Chilton".to_string(),
+            "English".to_string(),
+            412,
+            Uuid::new_v4(),
+        );
+        assert!(service.validate_book(&book).is_ok());
+
+        book.publication_year = BookService::latest_publication_year() + 1;
+        assert!(service.validate_book(&book).is_err());
+
+        book.publication_year = MIN_PUBLICATION_YEAR - 1;
+        assert!(service.validate_book(&book).is_err());
+    }
+
+    #[test]
+    fn test_normalize_isbn() {
+        assert_eq!(BookService::normalize_isbn("978-0-306-40615-7"), "9780306406157");
+        assert_eq!(BookService::normalize_isbn(" 0 306 40615 x "), "030640615X");
+        assert_eq!(BookService::normalize_isbn("n/a"), "");
+    }
+}
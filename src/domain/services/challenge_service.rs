@@ -0,0 +1,263 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        book::Book,
+        challenge::{ChallengeProgress, ChallengeRule, ChallengeRuleProgress},
+    },
+    errors::domain_error::DomainError,
+    services::genre_taxonomy::GenreTaxonomy,
+};
+
+pub const MAX_CHALLENGE_NAME_LENGTH: usize = 100;
+pub const MAX_CHALLENGE_RULES: usize = 10;
+
+/// Domain service for community challenges. Rules are checked against the
+/// books a participant finished while the challenge ran.
+pub struct ChallengeService;
+
+impl ChallengeService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn check_challenge(
+        &self,
+        name: &str,
+        rules: &[ChallengeRule],
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+    ) -> Result<()> {
+        let name = name.trim();
+        if name.is_empty() || name.chars().count() > MAX_CHALLENGE_NAME_LENGTH {
+            return Err(DomainError::InvalidInput(format!(
+                "Challenge names must be 1 to {} characters", MAX_CHALLENGE_NAME_LENGTH
+            )).into());
+        }
+        if rules.is_empty() || rules.len() > MAX_CHALLENGE_RULES {
+            return Err(DomainError::InvalidInput(format!(
+                "A challenge needs 1 to {} rules", MAX_CHALLENGE_RULES
+            )).into());
+        }
+        if rules.iter().any(|rule| rule.target() < 1) {
+            return Err(DomainError::InvalidInput("Rule counts must be at least 1".to_string()).into());
+        }
+        if rules.iter().any(|rule| matches!(rule, ChallengeRule::BooksInGenre { genre, .. } if GenreTaxonomy::normalize_key(genre).is_empty())) {
+            return Err(DomainError::InvalidInput("Genre rules need a genre".to_string()).into());
+        }
+        if ends_at <= starts_at {
+            return Err(DomainError::InvalidInput("ends_at must be after starts_at".to_string()).into());
+        }
+
+        Ok(())
+    }
+
+    /// Progress on every rule from the finished books; the challenge is
+    /// complete once all of them are met
+    pub fn evaluate(&self, rules: &[ChallengeRule], books: &[Book]) -> ChallengeProgress {
+        let rules: Vec<ChallengeRuleProgress> = rules
+            .iter()
+            .map(|rule| {
+                let current = match rule {
+                    ChallengeRule::BooksRead { .. } => books.len() as i32,
+                    ChallengeRule::PagesRead { .. } => books.iter().map(|book| book.page_count.max(0)).sum(),
+                    ChallengeRule::DistinctGenres { .. } => Self::distinct_genres(books) as i32,
+                    ChallengeRule::BooksInGenre { genre, .. } => {
+                        let key = GenreTaxonomy::normalize_key(genre);
+                        books
+                            .iter()
+                            .filter(|book| book.genre.iter().any(|g| GenreTaxonomy::normalize_key(g) == key))
+                            .count() as i32
+                    }
+                    ChallengeRule::DistinctAuthors { .. } => books
+                        .iter()
+                        .map(|book| book.author.trim().to_lowercase())
+                        .filter(|author| !author.is_empty())
+                        .collect::<HashSet<_>>()
+                        .len() as i32,
+                };
+
+                ChallengeRuleProgress {
+                    rule: rule.clone(),
+                    current,
+                    met: current >= rule.target(),
+                }
+            })
+            .collect();
+
+        let progress = if rules.is_empty() {
+            0.0
+        } else {
+            rules
+                .iter()
+                .map(|rule| (rule.current as f64 / rule.rule.target().max(1) as f64).min(1.0))
+                .sum::<f64>()
+                / rules.len() as f64
+        };
+
+        ChallengeProgress {
+            completed: !rules.is_empty() && rules.iter().all(|rule| rule.met),
+            rules,
+            books_counted: books.len() as i32,
+            progress: (progress * 1000.0).round() / 1000.0,
+        }
+    }
+
+    /// Leaderboard order: most progress, then most books; equal standings
+    /// share a rank ("1, 2, 2, 4")
+    pub fn rank(&self, mut standings: Vec<(Uuid, ChallengeProgress)>) -> Vec<(usize, Uuid, ChallengeProgress)> {
+        standings.sort_by(|a, b| {
+            b.1.progress
+                .partial_cmp(&a.1.progress)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(b.1.books_counted.cmp(&a.1.books_counted))
+        });
+
+        let mut ranked: Vec<(usize, Uuid, ChallengeProgress)> = Vec::with_capacity(standings.len());
+        for (index, (user_id, progress)) in standings.into_iter().enumerate() {
+            let rank = match ranked.last() {
+                Some((rank, _, previous))
+                    if previous.progress == progress.progress && previous.books_counted == progress.books_counted => *rank,
+                _ => index + 1,
+            };
+            ranked.push((rank, user_id, progress));
+        }
+
+        ranked
+    }
+
+    /// Number of genres that can each be covered by a different book, so a
+    /// single book tagged with five genres counts once (bipartite matching)
+    fn distinct_genres(books: &[Book]) -> usize {
+        let book_genres: Vec<Vec<String>> = books
+            .iter()
+            .map(|book| {
+                book.genre
+                    .iter()
+                    .map(|genre| GenreTaxonomy::normalize_key(genre))
+                    .filter(|key| !key.is_empty())
+                    .collect()
+            })
+            .collect();
+
+        let mut matched: HashMap<String, usize> = HashMap::new(); // Genre -> book covering it
+        for book in 0..book_genres.len() {
+            let mut visited = HashSet::new();
+            Self::assign_genre(book, &book_genres, &mut matched, &mut visited);
+        }
+
+        matched.len()
+    }
+
+    fn assign_genre(
+        book: usize,
+        book_genres: &[Vec<String>],
+        matched: &mut HashMap<String, usize>,
+        visited: &mut HashSet<String>,
+    ) -> bool {
+        for genre in &book_genres[book] {
+            if !visited.insert(genre.clone()) {
+                continue;
+            }
+            let free = match matched.get(genre) {
+                None => true,
+                Some(&other) => Self::assign_genre(other, book_genres, matched, visited),
+            };
+            if free {
+                matched.insert(genre.clone(), book);
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn book(author: &str, genres: &[&str], page_count: i32) -> Book {
+        Book::new(
+            "Title".to_string(),
+            author.to_string(),
+            "".to_string(),
+            genres.iter().map(|genre| genre.to_string()).collect(),
+            2024,
+            "Publisher".to_string(),
+            "English".to_string(),
+            page_count,
+            Uuid::new_v4(),
+        )
+    }
+
+    #[test]
+    fn test_check_challenge() {
+        let service = ChallengeService::new();
+        let now = Utc::now();
+        let rules = vec![ChallengeRule::BooksRead { count: 5 }];
+
+        assert!(service.check_challenge("Genre sampler", &rules, now, now + Duration::days(30)).is_ok());
+        assert!(service.check_challenge("  ", &rules, now, now + Duration::days(30)).is_err());
+        assert!(service.check_challenge("Genre sampler", &[], now, now + Duration::days(30)).is_err());
+        assert!(service.check_challenge("Genre sampler", &rules, now, now).is_err());
+        assert!(service
+            .check_challenge("Genre sampler", &[ChallengeRule::BooksInGenre { genre: "".to_string(), count: 2 }], now, now + Duration::days(1))
+            .is_err());
+    }
+
+    #[test]
+    fn test_evaluate_genre_rules() {
+        let service = ChallengeService::new();
+        let rules = vec![
+            ChallengeRule::BooksRead { count: 3 },
+            ChallengeRule::DistinctGenres { count: 3 },
+            ChallengeRule::BooksInGenre { genre: "Sci-Fi".to_string(), count: 1 },
+        ];
+
+        // One book tagged with many genres only covers one of them
+        let books = vec![
+            book("Ann Leckie", &["SciFi", "Space Opera", "Mystery"], 400),
+            book("Agatha Christie", &["Mystery"], 250),
+        ];
+        let progress = service.evaluate(&rules, &books);
+        assert_eq!(progress.rules[1].current, 2);
+        assert_eq!(progress.rules[2].current, 1);
+        assert!(!progress.completed);
+        assert!((progress.progress - 0.778).abs() < 1e-9);
+
+        let mut books = books;
+        books.push(book("Terry Pratchett", &["Fantasy"], 300));
+        let progress = service.evaluate(&rules, &books);
+        assert!(progress.completed);
+        assert_eq!(progress.progress, 1.0);
+    }
+
+    #[test]
+    fn test_rank_shares_ties() {
+        let service = ChallengeService::new();
+        let rules = vec![ChallengeRule::BooksRead { count: 4 }, ChallengeRule::DistinctAuthors { count: 2 }];
+        let one = vec![book("A", &[], 100)];
+        let two = vec![book("A", &[], 100), book("A", &[], 100)];
+        let three = vec![book("A", &[], 100), book("B", &[], 100), book("C", &[], 100)];
+
+        let (first, second, third, fourth) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let ranked = service.rank(vec![
+            (first, service.evaluate(&rules, &one)),
+            (second, service.evaluate(&rules, &three)),
+            (third, service.evaluate(&rules, &two)),
+            (fourth, service.evaluate(&rules, &one)),
+        ]);
+
+        let order: Vec<(usize, Uuid)> = ranked.iter().map(|(rank, user_id, _)| (*rank, *user_id)).collect();
+        assert_eq!(order[0], (1, second));
+        assert_eq!(order[1], (2, third));
+        assert_eq!(order[2].0, 3);
+        assert_eq!(order[3].0, 3);
+    }
+}
@@ -0,0 +1,394 @@
+use std::collections::{HashMap, HashSet};
+
+use uuid::Uuid;
+
+use crate::domain::entities::book::Book;
+
+/// Largest Hamming distance between 64-bit cover hashes that still counts as
+/// the same picture; re-encodes and resizes of one cover stay well below it
+pub const COVER_MATCH_MAX_DISTANCE: u32 = 6;
+
+/// Hashes with fewer set or unset bits than this come from blank or
+/// near-uniform covers, which all hash alike and are never matched
+pub const MIN_COVER_HASH_BITS: u32 = 4;
+
+/// Domain service for spotting books that were entered more than once
+pub struct DuplicateDetectionService {
+    threshold: f64,
+}
+
+/// A pair of books that scored above the duplicate threshold
+#[derive(Debug, Clone)]
+pub struct DuplicateMatch {
+    pub similarity: f64,
+    pub reasons: Vec<String>,
+}
+
+impl DuplicateDetectionService {
+    pub fn new(threshold: f64) -> Self {
+        Self { threshold }
+    }
+
+    /// Lowercase, strip punctuation and leading articles, collapse whitespace
+    pub fn normalize_title(title: &str) -> String {
+        let cleaned: String = title
+            .to_lowercase()
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+            .collect();
+
+        let mut words: Vec<&str> = cleaned.split_whitespace().collect();
+        if words.len() > 1 && matches!(words[0], "the" | "a" | "an") {
+            words.remove(0);
+        }
+
+        words.join(" ")
+    }
+
+    /// Reduce an author name to its letters, so "J.R.R. Tolkien" and
+    /// "J. R. R. Tolkien" produce the same key
+    pub fn normalize_author(author: &str) -> String {
+        author
+            .to_lowercase()
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .collect()
+    }
+
+    /// Blocking key used to limit pairwise comparison to plausible pairs
+    pub fn author_block_key(author: &str) -> String {
+        author
+            .split(|c: char| c == ',' || c == '&' || c == ';')
+            .next()
+            .and_then(|first| first.split_whitespace().last())
+            .map(Self::normalize_author)
+            .unwrap_or_default()
+    }
+
+    /// Jaccard similarity of character trigrams, 0.0 to 1.0
+    pub fn text_similarity(a: &str, b: &str) -> f64 {
+        if a == b {
+            return 1.0;
+        }
+
+        let trigrams = |s: &str| -> HashSet<String> {
+            let padded: Vec<char> = format!("  {} ", s).chars().collect();
+            padded.windows(3).map(|w| w.iter().collect()).collect()
+        };
+
+        let a_grams = trigrams(a);
+        let b_grams = trigrams(b);
+        let union = a_grams.union(&b_grams).count();
+
+        if union == 0 {
+            return 0.0;
+        }
+
+        a_grams.intersection(&b_grams).count() as f64 / union as f64
+    }
+
+    /// Compare two books, returning a match if they look like the same work
+    pub fn compare(&self, book: &Book, other: &Book) -> Option<DuplicateMatch> {
+        if book.id == other.id {
+            return None;
+        }
+
+        if let (Some(isbn), Some(other_isbn)) = (&book.isbn, &other.isbn) {
+            if !isbn.is_empty() && isbn.replace('-', "") == other_isbn.replace('-', "") {
+                return Some(DuplicateMatch {
+                    similarity: 1.0,
+                    reasons: vec![format!("Same ISBN ({})", isbn)],
+                });
+            }
+        }
+
+        let title_similarity = Self::text_similarity(
+            &Self::normalize_title(&book.title),
+            &Self::normalize_title(&other.title),
+        );
+        let author_similarity = Self::text_similarity(
+            &Self::normalize_author(&book.author),
+            &Self::normalize_author(&other.author),
+        );
+
+        let similarity = title_similarity * 0.7 + author_similarity * 0.3;
+        if similarity < self.threshold {
+            return None;
+        }
+
+        let mut reasons = vec![format!("Title similarity {:.0}%", title_similarity * 100.0)];
+        if author_similarity >= 0.99 {
+            reasons.push("Same author".to_string());
+        } else {
+            reasons.push(format!("Author similarity {:.0}%", author_similarity * 100.0));
+        }
+
+        Some(DuplicateMatch { similarity, reasons })
+    }
+
+    /// Find duplicate pairs across a set of books. Only books sharing an ISBN
+    /// or an author surname are compared, which keeps a catalog scan tractable.
+    pub fn find_duplicates<'a>(&self, books: &'a [Book]) -> Vec<(&'a Book, &'a Book, DuplicateMatch)> {
+        let mut blocks: HashMap<String, Vec<&Book>> = HashMap::new();
+        for book in books {
+            blocks
+                .entry(format!("author:{}", Self::author_block_key(&book.author)))
+                .or_insert_with(Vec::new)
+                .push(book);
+
+            if let Some(isbn) = book.isbn.as_ref().filter(|isbn| !isbn.is_empty()) {
+                blocks
+                    .entry(format!("isbn:{}", isbn.replace('-', "")))
+                    .or_insert_with(Vec::new)
+                    .push(book);
+            }
+        }
+
+        let mut seen = HashSet::new();
+        let mut matches = Vec::new();
+
+        for block in blocks.values() {
+            for (i, book) in block.iter().enumerate() {
+                for other in &block[i + 1..] {
+                    let pair = if book.id < other.id { (book.id, other.id) } else { (other.id, book.id) };
+                    if !seen.insert(pair) {
+                        continue;
+                    }
+
+                    if let Some(found) = self.compare(book, other) {
+                        matches.push((*book, *other, found));
+                    }
+                }
+            }
+        }
+
+        matches
+    }
+
+    /// Hamming distance between two hex-encoded 64-bit cover hashes
+    pub fn cover_hash_distance(hash: &str, other: &str) -> Option<u32> {
+        let hash = u64::from_str_radix(hash, 16).ok()?;
+        let other = u64::from_str_radix(other, 16).ok()?;
+        Some((hash ^ other).count_ones())
+    }
+
+    /// A hex-encoded cover hash worth matching on: `None` when it is malformed
+    /// or degenerate
+    pub fn usable_cover_hash(hash: &str) -> Option<u64> {
+        let hash = u64::from_str_radix(hash, 16).ok()?;
+        let ones = hash.count_ones();
+        (MIN_COVER_HASH_BITS..=64 - MIN_COVER_HASH_BITS).contains(&ones).then_some(hash)
+    }
+
+    /// Books whose cover is within `max_distance` of `cover_hash`, closest
+    /// first. Editions of the same work are expected to share a cover and
+    /// are left out, and so are degenerate hashes.
+    pub fn find_cover_matches<'a>(
+        book: &Book,
+        cover_hash: &str,
+        candidates: &'a [Book],
+        max_distance: u32,
+    ) -> Vec<(&'a Book, u32)> {
+        let Some(hash) = Self::usable_cover_hash(cover_hash) else {
+            return Vec::new();
+        };
+
+        let mut matches: Vec<(&Book, u32)> = candidates
+            .iter()
+            .filter(|other| other.id != book.id)
+            .filter(|other| book.work_id.is_none() || other.work_id != book.work_id)
+            .filter_map(|other| {
+                let other_hash = Self::usable_cover_hash(other.cover_hash.as_deref()?)?;
+                let distance = (hash ^ other_hash).count_ones();
+                (distance <= max_distance).then_some((other, distance))
+            })
+            .collect();
+
+        matches.sort_by_key(|(_, distance)| *distance);
+        matches
+    }
+}
+
+/// Finds near-identical covers without comparing every pair of books. Two
+/// hashes within `max_distance` bits agree exactly on at least one of
+/// `max_distance + 1` segments, so only books sharing a segment value are
+/// compared.
+pub struct CoverHashIndex {
+    max_distance: u32,
+    segments: Vec<(u32, u32)>, // Shift and width in bits
+    buckets: HashMap<(usize, u64), Vec<usize>>,
+    entries: Vec<(Uuid, Option<Uuid>, u64)>, // Book, work and cover hash
+}
+
+impl CoverHashIndex {
+    pub fn new(max_distance: u32) -> Self {
+        let count = (max_distance + 1).min(64);
+        let segments = (0..count)
+            .map(|i| (i * 64 / count, (i + 1) * 64 / count - i * 64 / count))
+            .collect();
+
+        Self {
+            max_distance,
+            segments,
+            buckets: HashMap::new(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Index a book's cover and return the books indexed before it whose
+    /// cover is within `max_distance`, except editions of the same work.
+    /// Books without a usable cover hash are skipped.
+    pub fn insert(&mut self, book: &Book) -> Vec<(Uuid, u32)> {
+        let Some(hash) = book.cover_hash.as_deref().and_then(DuplicateDetectionService::usable_cover_hash) else {
+            return Vec::new();
+        };
+        let keys: Vec<(usize, u64)> = self.segments
+            .iter()
+            .enumerate()
+            .map(|(segment, (shift, width))| (segment, (hash >> shift) & (u64::MAX >> (64 - width))))
+            .collect();
+
+        let mut compared = HashSet::new();
+        let mut matches = Vec::new();
+        for key in &keys {
+            for &index in self.buckets.get(key).into_iter().flatten() {
+                if !compared.insert(index) {
+                    continue;
+                }
+                let (other_id, other_work_id, other_hash) = self.entries[index];
+                if book.work_id.is_some() && other_work_id == book.work_id {
+                    continue;
+                }
+                let distance = (hash ^ other_hash).count_ones();
+                if distance <= self.max_distance {
+                    matches.push((other_id, distance));
+                }
+            }
+        }
+
+        let index = self.entries.len();
+        self.entries.push((book.id, book.work_id, hash));
+        for key in keys {
+            self.buckets.entry(key).or_default().push(index);
+        }
+
+        matches
+    }
+}
+
+impl Default for DuplicateDetectionService {
+    fn default() -> Self {
+        Self::new(0.85)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn book(title: &str, author: &str, isbn: Option<&str>) -> Book {
+        let mut book = Book::new(
+            title.to_string(),
+            author.to_string(),
+            "Test description".to_string(),
+            vec!["Fiction".to_string()],
+            2001,
+            "Test Publisher".to_string(),
+            "English".to_string(),
+            300,
+            Uuid::new_v4(),
+        );
+        book.isbn = isbn.map(String::from);
+        book
+    }
+
+    #[test]
+    fn test_normalization() {
+        assert_eq!(DuplicateDetectionService::normalize_title("The Hobbit: or, There and Back Again"),
+                   "hobbit or there and back again");
+        assert_eq!(DuplicateDetectionService::normalize_author("J.R.R. Tolkien"),
+                   DuplicateDetectionService::normalize_author("J. R. R. Tolkien"));
+    }
+
+    #[test]
+    fn test_detects_near_identical_titles() {
+        let service = DuplicateDetectionService::default();
+        let a = book("The Fellowship of the Ring", "J.R.R. Tolkien", None);
+        let b = book("Fellowship of the Ring", "J. R. R. Tolkien", None);
+        let c = book("The Two Towers", "J.R.R. Tolkien", None);
+
+        assert!(service.compare(&a, &b).is_some());
+        assert!(service.compare(&a, &c).is_none());
+    }
+
+    #[test]
+    fn test_isbn_match_wins() {
+        let service = DuplicateDetectionService::default();
+        let a = book("Some Title", "Someone", Some("978-0-00-000000-2"));
+        let b = book("Completely Different", "Someone Else", Some("9780000000002"));
+
+        let found = service.compare(&a, &b).unwrap();
+        assert_eq!(found.similarity, 1.0);
+        assert_eq!(service.find_duplicates(&[a, b]).len(), 1);
+    }
+
+    #[test]
+    fn test_cover_matches() {
+        let mut original = book("A Title", "Someone", None);
+        original.cover_hash = Some("f0f0f0f0f0f0f0f0".to_string());
+        let mut reupload = book("Another Title", "Someone Else", None);
+        reupload.cover_hash = Some("f0f0f0f0f0f0f0f1".to_string());
+        let mut different = book("Third Title", "Nobody", None);
+        different.cover_hash = Some("0f0f0f0f0f0f0f0f".to_string());
+        let no_cover = book("Fourth Title", "Nobody", None);
+        // Blank covers all hash to zero and must not match each other
+        let mut blank = book("Fifth Title", "Nobody", None);
+        blank.cover_hash = Some("0000000000000000".to_string());
+        let mut other_blank = book("Sixth Title", "Nobody", None);
+        other_blank.cover_hash = Some("0000000000000001".to_string());
+
+        let mut index = CoverHashIndex::new(COVER_MATCH_MAX_DISTANCE);
+        let pairs: Vec<(Uuid, Uuid, u32)> = [&original, &reupload, &different, &no_cover, &blank, &other_blank]
+            .into_iter()
+            .flat_map(|book| index.insert(book).into_iter().map(|(other, distance)| (other, book.id, distance)))
+            .collect();
+
+        assert_eq!(pairs, vec![(original.id, reupload.id, 1)]);
+        assert!(DuplicateDetectionService::find_cover_matches(&blank, "0000000000000000", &[other_blank], 16).is_empty());
+        assert_eq!(DuplicateDetectionService::cover_hash_distance("f0f0f0f0f0f0f0f0", "0f0f0f0f0f0f0f0f"), Some(64));
+        assert_eq!(DuplicateDetectionService::cover_hash_distance("not hex", "0f0f0f0f0f0f0f0f"), None);
+    }
+
+    #[test]
+    fn test_cover_index_finds_every_pair_within_distance() {
+        let hashes: Vec<u64> = (0..200u64)
+            .map(|i| i.wrapping_mul(0x9e37_79b9_7f4a_7c15).rotate_left(17) ^ 0x5555_0000_aaaa_ffff)
+            .collect();
+        let books: Vec<Book> = hashes
+            .iter()
+            .enumerate()
+            .flat_map(|(i, hash)| {
+                // Each cover plus a copy with a few bits flipped
+                [*hash, hash ^ (0b1011 << (i % 60))].map(|hash| {
+                    let mut book = book("Title", "Author", None);
+                    book.cover_hash = Some(format!("{:016x}", hash));
+                    book
+                })
+            })
+            .collect();
+
+        let mut index = CoverHashIndex::new(COVER_MATCH_MAX_DISTANCE);
+        let mut found = 0;
+        for book in &books {
+            found += index.insert(book).len();
+        }
+
+        let mut expected = 0;
+        for (i, book) in books.iter().enumerate() {
+            let hash = book.cover_hash.as_deref().unwrap();
+            expected += DuplicateDetectionService::find_cover_matches(book, hash, &books[..i], COVER_MATCH_MAX_DISTANCE).len();
+        }
+        assert_eq!(found, expected);
+        assert!(found >= hashes.len());
+    }
+}
@@ -0,0 +1,189 @@
+use anyhow::Result;
+use uuid::Uuid;
+use std::collections::HashMap;
+
+use crate::domain::entities::genre::Genre;
+
+/// In-memory view of the genre tree, used to map free-text genres onto
+/// canonical ones and to walk parent/child relations
+pub struct GenreTaxonomy {
+    genres: HashMap<Uuid, Genre>,
+    by_key: HashMap<String, Uuid>, // Normalized name, slug and synonyms -> genre
+}
+
+impl GenreTaxonomy {
+    pub fn new(genres: Vec<Genre>) -> Self {
+        let mut by_key = HashMap::new();
+        for genre in &genres {
+            by_key.insert(Self::normalize_key(&genre.slug), genre.id);
+            by_key.insert(Self::normalize_key(&genre.name), genre.id);
+            for synonym in &genre.synonyms {
+                by_key.entry(Self::normalize_key(synonym)).or_insert(genre.id);
+            }
+        }
+
+        Self {
+            genres: genres.into_iter().map(|genre| (genre.id, genre)).collect(),
+            by_key,
+        }
+    }
+
+    /// URL-safe slug: "Science Fiction & Fantasy" -> "science-fiction-fantasy"
+    pub fn slugify(name: &str) -> String {
+        name.to_lowercase()
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+
+    /// Lookup key ignoring case, spacing and punctuation, so "Sci-Fi",
+    /// "sci fi" and "SciFi" are the same key
+    pub fn normalize_key(value: &str) -> String {
+        value
+            .to_lowercase()
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .collect()
+    }
+
+    pub fn get(&self, id: Uuid) -> Option<&Genre> {
+        self.genres.get(&id)
+    }
+
+    pub fn resolve(&self, input: &str) -> Option<&Genre> {
+        self.by_key
+            .get(&Self::normalize_key(input))
+            .and_then(|id| self.genres.get(id))
+    }
+
+    /// Map free-text genres onto canonical genre names, dropping duplicates.
+    /// Unknown genres are kept as entered (trimmed) so no data is lost.
+    pub fn canonicalize(&self, inputs: &[String]) -> Vec<String> {
+        let mut canonical: Vec<String> = Vec::new();
+        for input in inputs {
+            let name = match self.resolve(input) {
+                Some(genre) => genre.name.clone(),
+                None => input.trim().to_string(),
+            };
+
+            if !name.is_empty() && !canonical.iter().any(|existing| existing.eq_ignore_ascii_case(&name)) {
+                canonical.push(name);
+            }
+        }
+        canonical
+    }
+
+    /// Parents of a genre, nearest first
+    pub fn ancestors(&self, id: Uuid) -> Vec<&Genre> {
+        let mut ancestors = Vec::new();
+        let mut current = self.genres.get(&id).and_then(|genre| genre.parent_id);
+
+        while let Some(parent_id) = current {
+            match self.genres.get(&parent_id) {
+                // Guard against cycles in stored data
+                Some(parent) if !ancestors.iter().any(|a: &&Genre| a.id == parent.id) => {
+                    ancestors.push(parent);
+                    current = parent.parent_id;
+                }
+                _ => break,
+            }
+        }
+
+        ancestors
+    }
+
+    /// Check that giving `genre_id` the parent `parent_id` keeps the tree acyclic
+    pub fn validate_parent(&self, genre_id: Uuid, parent_id: Uuid) -> Result<()> {
+        if genre_id == parent_id {
+            return Err(anyhow::anyhow!("A genre cannot be its own parent"));
+        }
+
+        if !self.genres.contains_key(&parent_id) {
+            return Err(anyhow::anyhow!("Parent genre does not exist"));
+        }
+
+        if self.ancestors(parent_id).iter().any(|ancestor| ancestor.id == genre_id) {
+            return Err(anyhow::anyhow!("Parent genre would create a cycle"));
+        }
+
+        Ok(())
+    }
+
+    /// Propagate genre scores up the tree. Each ancestor receives the child's
+    /// score multiplied by `weight` per level, keeping the highest score when
+    /// several children (or a direct score) contribute.
+    pub fn roll_up_scores(&self, scores: &HashMap<String, f64>, weight: f64) -> HashMap<String, f64> {
+        let mut rolled_up = scores.clone();
+
+        for (genre_name, score) in scores {
+            let genre = match self.resolve(genre_name) {
+                Some(genre) => genre,
+                None => continue,
+            };
+
+            let mut inherited = *score;
+            for ancestor in self.ancestors(genre.id) {
+                inherited *= weight;
+                let entry = rolled_up.entry(ancestor.name.clone()).or_insert(0.0);
+                *entry = entry.max(inherited);
+            }
+        }
+
+        rolled_up
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn taxonomy() -> (GenreTaxonomy, Uuid, Uuid, Uuid) {
+        let fiction = Genre::new("Fiction".into(), "fiction".into(), None, vec![]);
+        let speculative = Genre::new("Speculative Fiction".into(), "speculative-fiction".into(), Some(fiction.id), vec![]);
+        let scifi = Genre::new(
+            "Science Fiction".into(),
+            "science-fiction".into(),
+            Some(speculative.id),
+            vec!["Sci-Fi".into(), "SF".into()],
+        );
+        let ids = (fiction.id, speculative.id, scifi.id);
+        (GenreTaxonomy::new(vec![fiction, speculative, scifi]), ids.0, ids.1, ids.2)
+    }
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(GenreTaxonomy::slugify("Science Fiction & Fantasy"), "science-fiction-fantasy");
+    }
+
+    #[test]
+    fn test_canonicalize_synonyms() {
+        let (taxonomy, _, _, _) = taxonomy();
+        let canonical = taxonomy.canonicalize(&[
+            "Sci-Fi".to_string(),
+            "science fiction".to_string(),
+            "Science Fiction".to_string(),
+            " Cozy Mystery ".to_string(),
+        ]);
+        assert_eq!(canonical, vec!["Science Fiction", "Cozy Mystery"]);
+    }
+
+    #[test]
+    fn test_roll_up_scores() {
+        let (taxonomy, _, _, _) = taxonomy();
+        let mut scores = HashMap::new();
+        scores.insert("Science Fiction".to_string(), 0.8);
+
+        let rolled = taxonomy.roll_up_scores(&scores, 0.5);
+        assert!((rolled["Speculative Fiction"] - 0.4).abs() < f64::EPSILON);
+        assert!((rolled["Fiction"] - 0.2).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_validate_parent_rejects_cycles() {
+        let (taxonomy, fiction, _, scifi) = taxonomy();
+        assert!(taxonomy.validate_parent(fiction, scifi).is_err());
+        assert!(taxonomy.validate_parent(scifi, fiction).is_ok());
+        assert!(taxonomy.validate_parent(scifi, scifi).is_err());
+    }
+}
@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use chrono_tz::Tz;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        book::Book,
+        goal::{GoalMetric, GoalPeriod, GoalProgress, ReadingGoal},
+        recommendation::ReadingSession,
+    },
+    errors::domain_error::DomainError,
+};
+
+/// Domain service for reading goals. Books count once finished (moved to the
+/// read shelf); pages come from reading sessions, and a finished book adds the
+/// pages no session of it ever logged. Periods follow the reader's calendar.
+pub struct GoalService;
+
+impl GoalService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn check_goal(&self, period: GoalPeriod, year: i32, month: Option<u32>, target: i32) -> Result<()> {
+        if target < 1 {
+            return Err(DomainError::InvalidInput("target must be at least 1".to_string()).into());
+        }
+        if !(1900..=2100).contains(&year) {
+            return Err(DomainError::InvalidInput("year must be between 1900 and 2100".to_string()).into());
+        }
+        match (period, month) {
+            (GoalPeriod::Year, None) => Ok(()),
+            (GoalPeriod::Month, Some(1..=12)) => Ok(()),
+            (GoalPeriod::Year, Some(_)) => Err(DomainError::InvalidInput("Yearly goals take no month".to_string()).into()),
+            (GoalPeriod::Month, _) => Err(DomainError::InvalidInput("Monthly goals need a month from 1 to 12".to_string()).into()),
+        }
+    }
+
+    /// Start (inclusive) and end (exclusive) of the goal's period in the
+    /// reader's time zone, as UTC instants
+    pub fn period_range(&self, goal: &ReadingGoal, time_zone: Tz) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        let (start, end) = match goal.period {
+            GoalPeriod::Year => (
+                NaiveDate::from_ymd_opt(goal.year, 1, 1)?,
+                NaiveDate::from_ymd_opt(goal.year + 1, 1, 1)?,
+            ),
+            GoalPeriod::Month => {
+                let month = goal.month?;
+                let (next_year, next_month) = if month == 12 { (goal.year + 1, 1) } else { (goal.year, month + 1) };
+                (
+                    NaiveDate::from_ymd_opt(goal.year, month, 1)?,
+                    NaiveDate::from_ymd_opt(next_year, next_month, 1)?,
+                )
+            }
+        };
+
+        Some((Self::start_of_day(start, time_zone)?, Self::start_of_day(end, time_zone)?))
+    }
+
+    /// First instant of a local day; where the clocks skip midnight the day
+    /// starts an hour later
+    fn start_of_day(date: NaiveDate, time_zone: Tz) -> Option<DateTime<Utc>> {
+        let midnight = date.and_hms_opt(0, 0, 0)?;
+        time_zone
+            .from_local_datetime(&midnight)
+            .earliest()
+            .or_else(|| time_zone.from_local_datetime(&(midnight + chrono::Duration::hours(1))).earliest())
+            .map(|start| start.with_timezone(&Utc))
+    }
+
+    /// Pages logged per book across all of a reader's sessions
+    pub fn logged_pages(sessions: &[ReadingSession]) -> HashMap<Uuid, i32> {
+        let mut logged: HashMap<Uuid, i32> = HashMap::new();
+        for session in sessions {
+            *logged.entry(session.book_id).or_default() += session.pages_read.max(0);
+        }
+        logged
+    }
+
+    /// Pages a finished book adds on top of its sessions: the part of it no
+    /// session logged, so no page is counted twice
+    pub fn unlogged_pages(book: &Book, logged_pages: &HashMap<Uuid, i32>) -> i32 {
+        (book.page_count - logged_pages.get(&book.id).copied().unwrap_or(0)).max(0)
+    }
+
+    /// Progress from the books finished and the sessions ended within the
+    /// period. `logged_pages` covers every session of the finished books,
+    /// including ones outside the period.
+    pub fn progress(
+        &self,
+        goal: &ReadingGoal,
+        finished_books: &[Book],
+        sessions: &[ReadingSession],
+        logged_pages: &HashMap<Uuid, i32>,
+        time_zone: Tz,
+        now: DateTime<Utc>,
+    ) -> GoalProgress {
+        let current = match goal.metric {
+            GoalMetric::Books => finished_books.len() as i32,
+            GoalMetric::Pages => {
+                let session_pages: i32 = sessions.iter().map(|session| session.pages_read.max(0)).sum();
+                let unlogged_pages: i32 = finished_books
+                    .iter()
+                    .map(|book| Self::unlogged_pages(book, logged_pages))
+                    .sum();
+                session_pages + unlogged_pages
+            }
+        };
+
+        let elapsed = match self.period_range(goal, time_zone) {
+            Some((_, end)) if now >= end => 1.0,
+            Some((start, end)) if now > start => {
+                (now - start).num_seconds() as f64 / (end - start).num_seconds() as f64
+            }
+            _ => 0.0,
+        };
+        let expected_by_now = (goal.target as f64 * elapsed * 10.0).round() / 10.0;
+        let completed = current >= goal.target;
+
+        GoalProgress {
+            current,
+            target: goal.target,
+            percent_complete: ((current as f64 / goal.target.max(1) as f64 * 1000.0).round() / 10.0).min(100.0),
+            completed,
+            expected_by_now,
+            on_track: completed || current as f64 >= expected_by_now.floor(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use uuid::Uuid;
+
+    fn book(page_count: i32) -> Book {
+        Book::new(
+            "Title".to_string(),
+            "Author".to_string(),
+            "".to_string(),
+            vec![],
+            2024,
+            "Publisher".to_string(),
+            "English".to_string(),
+            page_count,
+            Uuid::new_v4(),
+        )
+    }
+
+    #[test]
+    fn test_check_goal() {
+        let service = GoalService::new();
+
+        assert!(service.check_goal(GoalPeriod::Year, 2025, None, 24).is_ok());
+        assert!(service.check_goal(GoalPeriod::Month, 2025, Some(12), 2).is_ok());
+        assert!(service.check_goal(GoalPeriod::Month, 2025, None, 2).is_err());
+        assert!(service.check_goal(GoalPeriod::Month, 2025, Some(13), 2).is_err());
+        assert!(service.check_goal(GoalPeriod::Year, 2025, None, 0).is_err());
+    }
+
+    #[test]
+    fn test_books_goal_pace() {
+        let service = GoalService::new();
+        let goal = ReadingGoal::new(Uuid::new_v4(), GoalMetric::Books, GoalPeriod::Year, 2025, None, 12);
+        let books = vec![book(200), book(300)];
+
+        // Halfway through the year (2 July) with 2 of 12 books
+        let now = Utc.with_ymd_and_hms(2025, 7, 2, 12, 0, 0).unwrap();
+        let progress = service.progress(&goal, &books, &[], &HashMap::new(), chrono_tz::UTC, now);
+
+        assert_eq!(progress.current, 2);
+        assert!((progress.expected_by_now - 6.0).abs() < 0.1);
+        assert!(!progress.on_track);
+        assert!((progress.percent_complete - 16.7).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_pages_goal_counts_sessions_and_unlogged_pages() {
+        let service = GoalService::new();
+        let goal = ReadingGoal::new(Uuid::new_v4(), GoalMetric::Pages, GoalPeriod::Month, 2025, Some(12), 600);
+        let logged = book(400);
+        let unlogged = book(250);
+        let mut earlier = ReadingSession::new(Uuid::new_v4(), logged.id, 0);
+        earlier.pages_read = 100; // Ended in November
+        let mut session = ReadingSession::new(Uuid::new_v4(), logged.id, 100);
+        session.pages_read = 120;
+        let logged_pages = GoalService::logged_pages(&[earlier, session.clone()]);
+
+        // 120 pages in sessions, the 180 left of the logged book and the whole unlogged one
+        let now = Utc.with_ymd_and_hms(2026, 1, 5, 0, 0, 0).unwrap();
+        let progress = service.progress(&goal, &[logged, unlogged], &[session], &logged_pages, chrono_tz::UTC, now);
+
+        assert_eq!(progress.current, 550);
+        assert_eq!(progress.expected_by_now, 600.0);
+        assert!(!progress.completed);
+        assert_eq!(
+            service.period_range(&goal, chrono_tz::UTC).unwrap().1,
+            Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            service.period_range(&goal, chrono_tz::America::New_York).unwrap().1,
+            Utc.with_ymd_and_hms(2026, 1, 1, 5, 0, 0).unwrap()
+        );
+    }
+}
@@ -0,0 +1,160 @@
+use crate::domain::entities::book::{Book, BookTranslation};
+
+/// Domain service for picking a book's translation from the reader's
+/// preferred locales
+pub struct LocalizationService;
+
+impl LocalizationService {
+    /// Canonical BCP 47 form: "en_us" and "EN-us" become "en-US". Returns
+    /// `None` for wildcards and malformed tags.
+    pub fn normalize_locale(tag: &str) -> Option<String> {
+        let mut parts = tag.trim().split(['-', '_']);
+        let language = parts.next()?.to_lowercase();
+        if language.len() < 2 || language.len() > 3 || !language.chars().all(|c| c.is_ascii_alphabetic()) {
+            return None;
+        }
+
+        let mut locale = language;
+        for part in parts {
+            if part.is_empty() || part.len() > 8 || !part.chars().all(|c| c.is_ascii_alphanumeric()) {
+                return None;
+            }
+            locale.push('-');
+            match part.len() {
+                2 => locale.push_str(&part.to_uppercase()), // Region, e.g. "BR"
+                4 => {
+                    // Script, e.g. "Hant"
+                    locale.push_str(&part[..1].to_uppercase());
+                    locale.push_str(&part[1..].to_lowercase());
+                }
+                _ => locale.push_str(&part.to_lowercase()),
+            }
+        }
+
+        Some(locale)
+    }
+
+    /// Primary language subtag: "pt-BR" -> "pt"
+    pub fn language_of(locale: &str) -> &str {
+        locale.split('-').next().unwrap_or(locale)
+    }
+
+    /// Parse an `Accept-Language` header into locales, most preferred first.
+    /// Entries with `q=0` and wildcards are dropped.
+    pub fn parse_accept_language(header: &str) -> Vec<String> {
+        let mut weighted: Vec<(String, f32)> = header
+            .split(',')
+            .filter_map(|entry| {
+                let mut parts = entry.split(';');
+                let locale = Self::normalize_locale(parts.next()?)?;
+                let quality = parts
+                    .find_map(|param| param.trim().strip_prefix("q="))
+                    .and_then(|q| q.trim().parse::<f32>().ok())
+                    .unwrap_or(1.0);
+
+                (quality > 0.0).then_some((locale, quality))
+            })
+            .collect();
+
+        // Stable sort keeps header order between equal weights
+        weighted.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+
+        let mut locales: Vec<String> = Vec::new();
+        for (locale, _) in weighted {
+            if !locales.contains(&locale) {
+                locales.push(locale);
+            }
+        }
+        locales
+    }
+
+    /// Pick a translation for the preferred locales. For each preferred locale
+    /// in turn: an exact match, then the bare language ("pt" for "pt-BR"),
+    /// then any regional variant of the language. `None` means the book's own
+    /// title and description should be served.
+    pub fn select_translation<'a>(
+        translations: &'a [BookTranslation],
+        preferred: &[String],
+    ) -> Option<&'a BookTranslation> {
+        for locale in preferred {
+            let language = Self::language_of(locale);
+
+            let found = translations
+                .iter()
+                .find(|t| t.locale.eq_ignore_ascii_case(locale))
+                .or_else(|| translations.iter().find(|t| t.locale.eq_ignore_ascii_case(language)))
+                .or_else(|| {
+                    translations
+                        .iter()
+                        .find(|t| Self::language_of(&t.locale).eq_ignore_ascii_case(language))
+                });
+
+            if found.is_some() {
+                return found;
+            }
+        }
+
+        None
+    }
+
+    /// Replace a book's title, subtitle and description with the best
+    /// translation. Returns the locale served, if a translation was used.
+    pub fn localize(book: &mut Book, preferred: &[String]) -> Option<String> {
+        let translation = Self::select_translation(&book.translations, preferred)?.clone();
+
+        book.title = translation.title;
+        if translation.subtitle.is_some() {
+            book.subtitle = translation.subtitle;
+        }
+        if let Some(description) = translation.description {
+            book.description = description;
+        }
+
+        Some(translation.locale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn translation(locale: &str, title: &str) -> BookTranslation {
+        BookTranslation {
+            locale: locale.to_string(),
+            title: title.to_string(),
+            subtitle: None,
+            description: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_accept_language() {
+        let locales = LocalizationService::parse_accept_language("fr-ch, fr;q=0.9, en;q=0.8, de;q=0, *;q=0.5");
+        assert_eq!(locales, vec!["fr-CH", "fr", "en"]);
+        assert_eq!(LocalizationService::normalize_locale("zh_hant_tw"), Some("zh-Hant-TW".to_string()));
+    }
+
+    #[test]
+    fn test_select_translation_falls_back_to_language() {
+        let translations = vec![translation("pt-PT", "O Hobbit (PT)"), translation("pt", "O Hobbit")];
+
+        let exact = LocalizationService::select_translation(&translations, &["pt-PT".to_string()]);
+        assert_eq!(exact.unwrap().title, "O Hobbit (PT)");
+
+        let language = LocalizationService::select_translation(&translations, &["pt-BR".to_string()]);
+        assert_eq!(language.unwrap().title, "O Hobbit");
+
+        let regional = LocalizationService::select_translation(&translations[..1], &["pt-BR".to_string()]);
+        assert_eq!(regional.unwrap().locale, "pt-PT");
+    }
+
+    #[test]
+    fn test_select_translation_respects_preference_order() {
+        let translations = vec![translation("de", "Der Hobbit"), translation("es", "El Hobbit")];
+        let preferred = vec!["fr".to_string(), "es".to_string(), "de".to_string()];
+
+        let selected = LocalizationService::select_translation(&translations, &preferred);
+        assert_eq!(selected.unwrap().title, "El Hobbit");
+        assert!(LocalizationService::select_translation(&translations, &["ja".to_string()]).is_none());
+    }
+}
@@ -6,7 +6,33 @@
 pub mod auth_service;
 pub mod book_service;
 pub mod recommendation_service;
+pub mod duplicate_detection_service;
+pub mod work_service;
+pub mod author_service;
+pub mod genre_taxonomy;
+pub mod tag_service;
+pub mod book_history_service;
+pub mod localization_service;
+pub mod review_service;
+pub mod review_moderation_service;
+pub mod rating_service;
+pub mod similarity_service;
+pub mod trending_service;
+pub mod reading_service;
+pub mod shelf_service;
+pub mod goal_service;
+pub mod challenge_service;
+pub mod stats_service;
 
 pub use auth_service::*;
 pub use book_service::*;
-pub use recommendation_service::*;
\ No newline at end of file
+pub use recommendation_service::*;
+pub use duplicate_detection_service::*;
+pub use work_service::*;
+pub use author_service::*;
+pub use genre_taxonomy::*;
+pub use tag_service::*;
+pub use book_history_service::*;
+pub use localization_service::*;
+pub use review_service::*;
+pub use review_moderation_service::*;
\ No newline at end of file
@@ -0,0 +1,72 @@
+pub const DEFAULT_PRIOR_MEAN: f64 = 3.5;
+pub const DEFAULT_PRIOR_WEIGHT: f64 = 10.0;
+
+/// Prior belief about a book's rating before anyone has rated it. Every book
+/// starts as if it had `weight` ratings averaging `mean`, so a handful of
+/// ratings can't outrank a well-established average.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RatingPrior {
+    pub mean: f64,
+    pub weight: f64, // Number of ratings the prior counts as
+}
+
+impl Default for RatingPrior {
+    fn default() -> Self {
+        Self {
+            mean: DEFAULT_PRIOR_MEAN,
+            weight: DEFAULT_PRIOR_WEIGHT,
+        }
+    }
+}
+
+impl RatingPrior {
+    /// A negative weight is treated as no prior at all
+    pub fn new(mean: f64, weight: f64) -> Self {
+        Self {
+            mean,
+            weight: weight.max(0.0),
+        }
+    }
+
+    /// Bayesian (IMDb-style) average: (v·R + m·C) / (v + m)
+    pub fn weighted_rating(&self, average_rating: f64, ratings_count: i32) -> f64 {
+        let count = ratings_count.max(0) as f64;
+        if count + self.weight == 0.0 {
+            return self.mean;
+        }
+
+        (count * average_rating + self.weight * self.mean) / (count + self.weight)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unrated_book_gets_the_prior_mean() {
+        let prior = RatingPrior::default();
+
+        assert_eq!(prior.weighted_rating(0.0, 0), DEFAULT_PRIOR_MEAN);
+        assert_eq!(RatingPrior { mean: 3.0, weight: 0.0 }.weighted_rating(0.0, 0), 3.0);
+    }
+
+    #[test]
+    fn test_few_ratings_do_not_outrank_many() {
+        let prior = RatingPrior::default();
+
+        let single_five = prior.weighted_rating(5.0, 1);
+        let established = prior.weighted_rating(4.8, 2000);
+
+        assert!(established > single_five);
+        assert!((established - 4.8).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_zero_weight_is_the_plain_average() {
+        let prior = RatingPrior { mean: 3.5, weight: 0.0 };
+
+        assert!((prior.weighted_rating(4.2, 7) - 4.2).abs() < 1e-9);
+        assert_eq!(RatingPrior::new(3.5, -5.0), prior);
+    }
+}
@@ -0,0 +1,180 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+
+use crate::domain::{
+    entities::{
+        book::Book,
+        recommendation::{ReadingProgress, ReadingSession},
+    },
+    errors::domain_error::DomainError,
+};
+
+/// Domain service for reading sessions and the progress derived from them
+pub struct ReadingService;
+
+impl ReadingService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Close a session at `end_page`, or `pages_read` pages after where it
+    /// started when the reader only counted pages. Pages read are always
+    /// derived from the pages covered, so both can't disagree.
+    pub fn end_session(
+        &self,
+        session: &mut ReadingSession,
+        book: &Book,
+        end_page: Option<i32>,
+        pages_read: Option<i32>,
+        now: DateTime<Utc>,
+    ) -> Result<()> {
+        if !session.is_active() {
+            return Err(DomainError::ReadingSessionAlreadyEnded.into());
+        }
+        if pages_read.is_some_and(|pages| pages < 0) {
+            return Err(DomainError::InvalidInput("pages_read cannot be negative".to_string()).into());
+        }
+
+        let end_page = end_page
+            .or_else(|| pages_read.map(|pages| session.start_page + pages))
+            .ok_or_else(|| DomainError::InvalidInput("Either end_page or pages_read is required".to_string()))?;
+        self.check_page(book, end_page)?;
+
+        let pages_covered = (end_page - session.start_page).max(0);
+        if pages_read.is_some_and(|pages| pages != pages_covered) {
+            return Err(DomainError::InvalidInput(format!(
+                "pages_read must be {} when reading from page {} to {}",
+                pages_covered, session.start_page, end_page
+            )).into());
+        }
+
+        session.end_time = Some(now);
+        session.end_page = Some(end_page);
+        session.pages_read = pages_covered;
+        session.session_duration_minutes = Some((now - session.start_time).num_minutes().max(0) as i32);
+        Ok(())
+    }
+
+    /// Pages run from 0 (not started) to the book's page count, when known
+    pub fn check_page(&self, book: &Book, page: i32) -> Result<()> {
+        if page < 0 || (book.page_count > 0 && page > book.page_count) {
+            return Err(DomainError::InvalidInput(format!("Page must be between 0 and {}", book.page_count)).into());
+        }
+
+        Ok(())
+    }
+
+    /// The page of the most recently ended session is where the reader is,
+    /// even if they went back; `None` before their first session
+    pub fn progress(&self, book: &Book, sessions: &[ReadingSession]) -> Option<ReadingProgress> {
+        if sessions.is_empty() {
+            return None;
+        }
+
+        let latest_ended = sessions
+            .iter()
+            .filter(|session| !session.is_active())
+            .max_by_key(|session| session.end_time);
+        let current_page = match latest_ended {
+            Some(session) => session.end_page.unwrap_or(session.start_page + session.pages_read),
+            None => sessions.iter().map(|session| session.start_page).max().unwrap_or(0),
+        };
+        let current_page = if book.page_count > 0 { current_page.min(book.page_count) } else { current_page };
+
+        let percent_complete = (book.page_count > 0)
+            .then(|| (current_page as f64 / book.page_count as f64 * 1000.0).round() / 10.0);
+
+        Some(ReadingProgress {
+            current_page,
+            page_count: book.page_count,
+            percent_complete,
+            pages_read: sessions.iter().map(|session| session.pages_read).sum(),
+            minutes_read: sessions.iter().filter_map(|session| session.session_duration_minutes).sum(),
+            sessions_count: sessions.len() as i32,
+            last_read_at: sessions
+                .iter()
+                .map(|session| session.end_time.unwrap_or(session.start_time))
+                .max(),
+            finished: book.page_count > 0 && current_page >= book.page_count,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use uuid::Uuid;
+
+    fn book(page_count: i32) -> Book {
+        Book::new(
+            "Title".to_string(),
+            "Author".to_string(),
+            "".to_string(),
+            vec![],
+            2024,
+            "Publisher".to_string(),
+            "English".to_string(),
+            page_count,
+            Uuid::new_v4(),
+        )
+    }
+
+    fn ended(book: &Book, start_page: i32, end_page: i32, ended_at: DateTime<Utc>) -> ReadingSession {
+        let mut session = ReadingSession::new(Uuid::new_v4(), book.id, start_page);
+        session.start_time = ended_at - Duration::minutes(30);
+        ReadingService::new()
+            .end_session(&mut session, book, Some(end_page), None, ended_at)
+            .unwrap();
+        session
+    }
+
+    #[test]
+    fn test_end_session_from_pages_read() {
+        let service = ReadingService::new();
+        let book = book(300);
+        let mut session = ReadingSession::new(Uuid::new_v4(), book.id, 40);
+        let now = session.start_time + Duration::minutes(45);
+
+        service.end_session(&mut session, &book, None, Some(25), now).unwrap();
+
+        assert_eq!(session.end_page, Some(65));
+        assert_eq!(session.pages_read, 25);
+        assert_eq!(session.session_duration_minutes, Some(45));
+        assert!(service.end_session(&mut session, &book, Some(70), None, now).is_err());
+    }
+
+    #[test]
+    fn test_end_session_rejects_invalid_pages() {
+        let service = ReadingService::new();
+        let book = book(300);
+        let mut session = ReadingSession::new(Uuid::new_v4(), book.id, 290);
+
+        assert!(service.end_session(&mut session, &book, Some(301), None, Utc::now()).is_err());
+        assert!(service.end_session(&mut session, &book, None, None, Utc::now()).is_err());
+        assert!(service.end_session(&mut session, &book, Some(295), Some(40), Utc::now()).is_err());
+        assert!(session.is_active());
+    }
+
+    #[test]
+    fn test_progress_follows_the_latest_session() {
+        let service = ReadingService::new();
+        let book = book(200);
+        let now = Utc::now();
+        let sessions = vec![
+            ended(&book, 0, 120, now - Duration::days(2)),
+            ended(&book, 100, 150, now - Duration::days(1)), // Went back a little
+            ReadingSession::new(Uuid::new_v4(), book.id, 150),
+        ];
+
+        let progress = service.progress(&book, &sessions).unwrap();
+
+        assert_eq!(progress.current_page, 150);
+        assert_eq!(progress.percent_complete, Some(75.0));
+        assert_eq!(progress.pages_read, 170);
+        assert_eq!(progress.minutes_read, 60);
+        assert_eq!(progress.sessions_count, 3);
+        assert!(!progress.finished);
+        assert!(service.progress(&book, &[]).is_none());
+    }
+}
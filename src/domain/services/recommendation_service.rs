@@ -6,14 +6,23 @@ use crate::domain::entities::{
     book::Book,
     user::User,
     recommendation::{Recommendation, UserPreference},
+    shelf::{ShelfEntry, ShelfStatus},
 };
+use crate::domain::services::{genre_taxonomy::GenreTaxonomy, rating_service::RatingPrior};
 
-/// Domain service for recommendation business logic
-pub struct RecommendationService;
+/// Domain service for recommendation business logic. Book ratings are ranked
+/// by their Bayesian-weighted score, so a few ratings can't outrank many.
+pub struct RecommendationService {
+    rating_prior: RatingPrior,
+}
 
 impl RecommendationService {
-    pub fn new() -> Self {
-        Self
+    pub fn new(rating_prior: RatingPrior) -> Self {
+        Self { rating_prior }
+    }
+
+    pub fn rating_prior(&self) -> &RatingPrior {
+        &self.rating_prior
     }
 
     /// Calculate user preference scores based on reading history
@@ -45,6 +54,28 @@ impl RecommendationService {
             .collect()
     }
 
+    /// Calculate preference scores on canonical genres, then roll them up the
+    /// genre tree so liking "Science Fiction" also counts towards its parents.
+    /// `rollup_weight` (0.0 to 1.0) is applied once per level.
+    pub fn calculate_preference_scores_with_taxonomy(
+        &self,
+        user_ratings: &[(Book, f64)],
+        taxonomy: &GenreTaxonomy,
+        rollup_weight: f64,
+    ) -> HashMap<String, f64> {
+        let canonical_ratings: Vec<(Book, f64)> = user_ratings
+            .iter()
+            .map(|(book, rating)| {
+                let mut book = book.clone();
+                book.genre = taxonomy.canonicalize(&book.genre);
+                (book, *rating)
+            })
+            .collect();
+
+        let scores = self.calculate_preference_scores(&canonical_ratings);
+        taxonomy.roll_up_scores(&scores, rollup_weight)
+    }
+
     /// Filter books based on user preferences and reading history
     pub fn filter_candidate_books(
         &self,
@@ -59,13 +90,44 @@ impl RecommendationService {
                 // Not already read
                 !read_book_ids.contains(&book.id) &&
                 // Meets minimum rating threshold
-                book.average_rating >= min_rating &&
+                self.rating_prior.weighted_rating(book.average_rating, book.ratings_count) >= min_rating &&
                 // Has overlapping genres with user preferences
                 book.genre.iter().any(|genre| user.preferences.contains(genre))
             })
             .collect()
     }
 
+    /// Keep one edition per work, preserving ranking order, and drop works the
+    /// user has already read in any edition. Books without a work pass through.
+    pub fn collapse_editions(&self, books: Vec<Book>, read_work_ids: &[Uuid]) -> Vec<Book> {
+        let mut seen_works = Vec::new();
+
+        books
+            .into_iter()
+            .filter(|book| match book.work_id {
+                Some(work_id) if read_work_ids.contains(&work_id) || seen_works.contains(&work_id) => false,
+                Some(work_id) => {
+                    seen_works.push(work_id);
+                    true
+                }
+                None => true,
+            })
+            .collect()
+    }
+
+    /// Drop books the user is reading, has read or gave up on, and move the
+    /// ones they want to read to the front, otherwise keeping the order
+    pub fn apply_shelves(&self, books: Vec<Book>, entries: &[ShelfEntry]) -> Vec<Book> {
+        let status = |book: &Book| entries.iter().find(|entry| entry.book_id == book.id).map(|entry| entry.status);
+
+        let (wanted, others): (Vec<Book>, Vec<Book>) = books
+            .into_iter()
+            .filter(|book| matches!(status(book), None | Some(ShelfStatus::WantToRead)))
+            .partition(|book| status(book) == Some(ShelfStatus::WantToRead));
+
+        wanted.into_iter().chain(others).collect()
+    }
+
     /// Score a book for a specific user
     pub fn score_book_for_user(
         &self,
@@ -88,7 +150,8 @@ impl RecommendationService {
 
         // Book rating score (weight: 0.3)
         let rating_weight = 0.3;
-        let rating_score = (book.average_rating - 1.0) / 4.0; // Normalize to 0-1
+        let weighted_rating = self.rating_prior.weighted_rating(book.average_rating, book.ratings_count);
+        let rating_score = (weighted_rating - 1.0) / 4.0; // Normalize to 0-1
         score += rating_score * rating_weight;
         weight_sum += rating_weight;
 
@@ -151,7 +214,7 @@ impl RecommendationService {
                 top_matching_genres.join(" and "),
                 (score * 100.0) as i32
             )
-        } else if book.average_rating > 4.5 {
+        } else if self.rating_prior.weighted_rating(book.average_rating, book.ratings_count) > 4.5 {
             format!(
                 "Highly rated {} book ({}★) - {}% match based on your preferences",
                 book.genre.join("/"),
@@ -192,32 +255,47 @@ impl RecommendationService {
 mod tests {
     use super::*;
     use chrono::Utc;
+    use crate::domain::entities::work::EditionFormat;
 
     fn create_test_book(title: &str, genres: Vec<&str>, rating: f64) -> Book {
         Book {
             id: Uuid::new_v4(),
             title: title.to_string(),
+            subtitle: None,
             author: "Test Author".to_string(),
             isbn: None,
             description: "Test description".to_string(),
+            translations: vec![],
             genre: genres.into_iter().map(String::from).collect(),
             tags: vec![],
+            catalog_tags: vec![],
+            community_tags: false,
             publication_year: 2023,
             publisher: "Test Publisher".to_string(),
+            publisher_id: None,
             language: "English".to_string(),
             page_count: 300,
             cover_image_url: None,
+            cover_images: vec![],
+            cover_placeholder: None,
+            cover_hash: None,
+            work_id: None,
+            format: EditionFormat::Unknown,
             average_rating: rating,
             ratings_count: 100,
+            rating_sum: rating * 100.0,
+            rating_histogram: vec![0; 5],
             created_at: Utc::now(),
             updated_at: Utc::now(),
             created_by: Uuid::new_v4(),
+            updated_by: None,
+            deleted_at: None,
         }
     }
 
     #[test]
     fn test_calculate_preference_scores() {
-        let service = RecommendationService::new();
+        let service = RecommendationService::new(RatingPrior::default());
         
         let book1 = create_test_book("Book 1", vec!["Fiction", "Romance"], 4.0);
         let book2 = create_test_book("Book 2", vec!["Fiction", "Mystery"], 5.0);
@@ -241,9 +319,76 @@ mod tests {
         assert!((scores["Mystery"] - 1.0).abs() < 0.01);
     }
 
+    #[test]
+    fn test_preference_scores_with_taxonomy() {
+        use crate::domain::entities::genre::Genre;
+
+        let service = RecommendationService::new(RatingPrior::default());
+        let fiction = Genre::new("Fiction".into(), "fiction".into(), None, vec![]);
+        let scifi = Genre::new("Science Fiction".into(), "science-fiction".into(), Some(fiction.id), vec!["Sci-Fi".into()]);
+        let taxonomy = GenreTaxonomy::new(vec![fiction, scifi]);
+
+        let user_ratings = vec![
+            (create_test_book("Book 1", vec!["Sci-Fi"], 5.0), 5.0),
+            (create_test_book("Book 2", vec!["science fiction"], 3.0), 3.0),
+        ];
+
+        let scores = service.calculate_preference_scores_with_taxonomy(&user_ratings, &taxonomy, 0.5);
+
+        // Both books land on "Science Fiction": (5.0 + 3.0) / 2 = 4.0 -> 0.75
+        assert!((scores["Science Fiction"] - 0.75).abs() < 0.01);
+        assert!((scores["Fiction"] - 0.375).abs() < 0.01);
+        assert!(!scores.contains_key("Sci-Fi"));
+    }
+
+    #[test]
+    fn test_collapse_editions() {
+        let service = RecommendationService::new(RatingPrior::default());
+        let work_id = Uuid::new_v4();
+        let read_work_id = Uuid::new_v4();
+
+        let mut hardcover = create_test_book("Hardcover", vec!["Fiction"], 4.0);
+        hardcover.work_id = Some(work_id);
+        let mut paperback = create_test_book("Paperback", vec!["Fiction"], 4.0);
+        paperback.work_id = Some(work_id);
+        let mut already_read = create_test_book("Translation", vec!["Fiction"], 4.0);
+        already_read.work_id = Some(read_work_id);
+        let standalone = create_test_book("Standalone", vec!["Fiction"], 4.0);
+
+        let collapsed = service.collapse_editions(
+            vec![hardcover, paperback, already_read, standalone],
+            &[read_work_id],
+        );
+
+        let titles: Vec<&str> = collapsed.iter().map(|b| b.title.as_str()).collect();
+        assert_eq!(titles, vec!["Hardcover", "Standalone"]);
+    }
+
+    #[test]
+    fn test_apply_shelves() {
+        let service = RecommendationService::new(RatingPrior::default());
+        let user_id = Uuid::new_v4();
+        let books = vec![
+            create_test_book("Unshelved", vec!["Fiction"], 4.0),
+            create_test_book("Read", vec!["Fiction"], 4.0),
+            create_test_book("Wanted", vec!["Fiction"], 4.0),
+            create_test_book("Abandoned", vec!["Fiction"], 4.0),
+        ];
+        let entries = vec![
+            ShelfEntry::new(user_id, books[1].id, ShelfStatus::Read),
+            ShelfEntry::new(user_id, books[2].id, ShelfStatus::WantToRead),
+            ShelfEntry::new(user_id, books[3].id, ShelfStatus::Abandoned),
+        ];
+
+        let shelved = service.apply_shelves(books, &entries);
+
+        let titles: Vec<&str> = shelved.iter().map(|b| b.title.as_str()).collect();
+        assert_eq!(titles, vec!["Wanted", "Unshelved"]);
+    }
+
     #[test]
     fn test_score_book_for_user() {
-        let service = RecommendationService::new();
+        let service = RecommendationService::new(RatingPrior::default());
         
         let book = create_test_book("Test Book", vec!["Fiction"], 4.5);
         
@@ -255,4 +400,20 @@ mod tests {
         // Should be a weighted average of genre preference (0.8), rating score, and popularity
         assert!(score > 0.0 && score <= 1.0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_score_prefers_established_ratings() {
+        let service = RecommendationService::new(RatingPrior::default());
+        let preferences = HashMap::from([("Fiction".to_string(), 0.8)]);
+
+        let mut single_rating = create_test_book("One Review", vec!["Fiction"], 5.0);
+        single_rating.ratings_count = 1;
+        let mut established = create_test_book("Classic", vec!["Fiction"], 4.8);
+        established.ratings_count = 2000;
+
+        assert!(
+            service.score_book_for_user(&established, &preferences, 4.0)
+                > service.score_book_for_user(&single_rating, &preferences, 4.0)
+        );
+    }
+}
@@ -0,0 +1,298 @@
+use std::collections::HashSet;
+
+/// What a filter, or the whole chain, decides about a review
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ModerationVerdict {
+    Approve,
+    Hold,   // Publish only once a moderator approves
+    Reject,
+}
+
+/// A filter's verdict with the reason shown to moderators and, on rejection,
+/// to the author
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilterOutcome {
+    pub verdict: ModerationVerdict,
+    pub reason: Option<String>,
+}
+
+impl FilterOutcome {
+    pub fn approve() -> Self {
+        Self { verdict: ModerationVerdict::Approve, reason: None }
+    }
+
+    pub fn hold(reason: impl Into<String>) -> Self {
+        Self { verdict: ModerationVerdict::Hold, reason: Some(reason.into()) }
+    }
+
+    pub fn reject(reason: impl Into<String>) -> Self {
+        Self { verdict: ModerationVerdict::Reject, reason: Some(reason.into()) }
+    }
+}
+
+/// The review being moderated and the author's other reviews
+pub struct ReviewContext<'a> {
+    pub text: &'a str,
+    pub previous_reviews: &'a [String],
+}
+
+/// One step of the moderation chain
+pub trait ReviewFilter: Send + Sync {
+    fn name(&self) -> &'static str;
+    fn check(&self, review: &ReviewContext) -> FilterOutcome;
+}
+
+/// The chain's decision and every reason given for it
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModerationDecision {
+    pub verdict: ModerationVerdict,
+    pub reasons: Vec<String>,
+}
+
+/// Lowercased words of a text, punctuation stripped
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric() && c != '\'')
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_lowercase())
+        .collect()
+}
+
+/// Holds or rejects reviews containing listed words
+pub struct ProfanityFilter {
+    hold_words: HashSet<String>,
+    reject_words: HashSet<String>,
+}
+
+impl ProfanityFilter {
+    pub fn new(hold_words: Vec<String>, reject_words: Vec<String>) -> Self {
+        let normalize = |list: Vec<String>| -> HashSet<String> {
+            list.into_iter()
+                .map(|word| word.trim().to_lowercase())
+                .filter(|word| !word.is_empty())
+                .collect()
+        };
+        Self {
+            hold_words: normalize(hold_words),
+            reject_words: normalize(reject_words),
+        }
+    }
+}
+
+impl ReviewFilter for ProfanityFilter {
+    fn name(&self) -> &'static str {
+        "profanity"
+    }
+
+    fn check(&self, review: &ReviewContext) -> FilterOutcome {
+        let words = words(review.text);
+
+        if words.iter().any(|word| self.reject_words.contains(word)) {
+            FilterOutcome::reject("Contains language that is not allowed")
+        } else if words.iter().any(|word| self.hold_words.contains(word)) {
+            FilterOutcome::hold("Contains language that needs review")
+        } else {
+            FilterOutcome::approve()
+        }
+    }
+}
+
+/// Link and shouting heuristics for spam
+pub struct LinkSpamFilter {
+    pub links_to_hold: usize,
+    pub links_to_reject: usize,
+    pub blocked_domains: Vec<String>,
+    pub max_uppercase_ratio: f64, // Share of uppercase letters above which a review is held
+}
+
+impl LinkSpamFilter {
+    fn links(text: &str) -> Vec<String> {
+        text.split_whitespace()
+            .map(|token| token.to_lowercase())
+            .filter(|token| token.starts_with("http://") || token.starts_with("https://") || token.starts_with("www."))
+            .collect()
+    }
+}
+
+impl ReviewFilter for LinkSpamFilter {
+    fn name(&self) -> &'static str {
+        "links"
+    }
+
+    fn check(&self, review: &ReviewContext) -> FilterOutcome {
+        let links = Self::links(review.text);
+
+        let blocked = links.iter().any(|link| {
+            self.blocked_domains.iter().any(|domain| link.contains(&domain.to_lowercase()))
+        });
+        if blocked {
+            return FilterOutcome::reject("Links to a blocked site");
+        }
+        if self.links_to_reject > 0 && links.len() >= self.links_to_reject {
+            return FilterOutcome::reject(format!("Contains {} links", links.len()));
+        }
+        if self.links_to_hold > 0 && links.len() >= self.links_to_hold {
+            return FilterOutcome::hold("Contains links");
+        }
+
+        let letters: Vec<char> = review.text.chars().filter(|c| c.is_alphabetic()).collect();
+        if letters.len() >= 20 {
+            let uppercase = letters.iter().filter(|c| c.is_uppercase()).count();
+            if uppercase as f64 / letters.len() as f64 > self.max_uppercase_ratio {
+                return FilterOutcome::hold("Mostly uppercase");
+            }
+        }
+
+        FilterOutcome::approve()
+    }
+}
+
+/// Catches keyboard mashing, the same word over and over, and reviews pasted
+/// onto several books
+pub struct RepeatedTextFilter {
+    pub max_char_run: usize,
+    pub min_unique_word_ratio: f64, // Applies to reviews of 10 words or more
+    pub duplicate_verdict: ModerationVerdict,
+}
+
+impl ReviewFilter for RepeatedTextFilter {
+    fn name(&self) -> &'static str {
+        "repeated_text"
+    }
+
+    fn check(&self, review: &ReviewContext) -> FilterOutcome {
+        let words = words(review.text);
+
+        let duplicate = review
+            .previous_reviews
+            .iter()
+            .any(|previous| !words.is_empty() && self::words(previous) == words);
+        if duplicate {
+            return match self.duplicate_verdict {
+                ModerationVerdict::Reject => FilterOutcome::reject("Same text as another review by the same author"),
+                ModerationVerdict::Hold => FilterOutcome::hold("Same text as another review by the same author"),
+                ModerationVerdict::Approve => FilterOutcome::approve(),
+            };
+        }
+
+        let mut longest_run = 0;
+        let mut run = 0;
+        let mut previous = None;
+        for c in review.text.chars().filter(|c| !c.is_whitespace()) {
+            run = if Some(c) == previous { run + 1 } else { 1 };
+            longest_run = longest_run.max(run);
+            previous = Some(c);
+        }
+        if self.max_char_run > 0 && longest_run > self.max_char_run {
+            return FilterOutcome::hold("Repeated characters");
+        }
+
+        if words.len() >= 10 {
+            let unique: HashSet<&String> = words.iter().collect();
+            if (unique.len() as f64 / words.len() as f64) < self.min_unique_word_ratio {
+                return FilterOutcome::hold("Repeated words");
+            }
+        }
+
+        FilterOutcome::approve()
+    }
+}
+
+/// Domain service running reviews through a chain of filters. The most
+/// severe verdict wins, and the chain stops at the first rejection.
+pub struct ReviewModerationService {
+    filters: Vec<Box<dyn ReviewFilter>>,
+}
+
+impl ReviewModerationService {
+    pub fn new(filters: Vec<Box<dyn ReviewFilter>>) -> Self {
+        Self { filters }
+    }
+
+    pub fn filter_names(&self) -> Vec<&'static str> {
+        self.filters.iter().map(|filter| filter.name()).collect()
+    }
+
+    pub fn moderate(&self, review: &ReviewContext) -> ModerationDecision {
+        let mut decision = ModerationDecision {
+            verdict: ModerationVerdict::Approve,
+            reasons: Vec::new(),
+        };
+
+        for filter in &self.filters {
+            let outcome = filter.check(review);
+            if let Some(reason) = outcome.reason {
+                decision.reasons.push(format!("{}: {}", filter.name(), reason));
+            }
+            decision.verdict = decision.verdict.max(outcome.verdict);
+
+            if decision.verdict == ModerationVerdict::Reject {
+                break;
+            }
+        }
+
+        decision
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn moderate(service: &ReviewModerationService, text: &str, previous: &[String]) -> ModerationDecision {
+        service.moderate(&ReviewContext { text, previous_reviews: previous })
+    }
+
+    fn service() -> ReviewModerationService {
+        ReviewModerationService::new(vec![
+            Box::new(ProfanityFilter::new(vec!["darn".to_string()], vec!["slur".to_string()])),
+            Box::new(LinkSpamFilter {
+                links_to_hold: 1,
+                links_to_reject: 3,
+                blocked_domains: vec!["spam.example".to_string()],
+                max_uppercase_ratio: 0.7,
+            }),
+            Box::new(RepeatedTextFilter {
+                max_char_run: 8,
+                min_unique_word_ratio: 0.3,
+                duplicate_verdict: ModerationVerdict::Hold,
+            }),
+        ])
+    }
+
+    #[test]
+    fn test_clean_review_is_approved() {
+        let decision = moderate(&service(), "A slow start, but the last act is wonderful.", &[]);
+
+        assert_eq!(decision.verdict, ModerationVerdict::Approve);
+        assert!(decision.reasons.is_empty());
+    }
+
+    #[test]
+    fn test_most_severe_verdict_wins() {
+        let held = moderate(&service(), "Darn good book, more at https://blog.example/post", &[]);
+        assert_eq!(held.verdict, ModerationVerdict::Hold);
+        assert_eq!(held.reasons.len(), 2);
+
+        let rejected = moderate(&service(), "Buy now at https://spam.example/deal", &[]);
+        assert_eq!(rejected.verdict, ModerationVerdict::Reject);
+        assert_eq!(rejected.reasons, vec!["links: Links to a blocked site".to_string()]);
+
+        let shouting = moderate(&service(), "THIS IS THE BEST BOOK EVER WRITTEN", &[]);
+        assert_eq!(shouting.verdict, ModerationVerdict::Hold);
+    }
+
+    #[test]
+    fn test_repeated_text() {
+        let service = service();
+
+        assert_eq!(moderate(&service, "Loved it!!!!!!!!!!!!", &[]).verdict, ModerationVerdict::Hold);
+        assert_eq!(
+            moderate(&service, "good good good good good good good good good good book", &[]).verdict,
+            ModerationVerdict::Hold
+        );
+
+        let previous = vec!["Great read, highly recommended.".to_string()];
+        let pasted = moderate(&service, "great read -- highly recommended", &previous);
+        assert_eq!(pasted.verdict, ModerationVerdict::Hold);
+    }
+}
@@ -0,0 +1,85 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{book::BookRating, review::ReviewStatus},
+    errors::domain_error::DomainError,
+};
+
+/// Open reports that hide a review until a moderator decides
+pub const REPORTS_TO_HIDE: i32 = 3;
+
+/// Domain service for review votes and abuse reports
+pub struct ReviewService;
+
+impl ReviewService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Whether a published review has collected enough reports to be hidden
+    pub fn should_hide(&self, rating: &BookRating) -> bool {
+        rating.status == ReviewStatus::Published && rating.report_count >= REPORTS_TO_HIDE
+    }
+
+    /// Votes and reports only apply to published reviews by someone else
+    pub fn check_can_respond(&self, rating: &BookRating, user_id: Uuid) -> Result<()> {
+        if rating.review.is_none() || rating.status != ReviewStatus::Published {
+            return Err(DomainError::ReviewNotFound.into());
+        }
+        if rating.user_id == user_id {
+            return Err(DomainError::InvalidInput("You cannot vote on or report your own review".to_string()).into());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn review(helpful_count: i32, not_helpful_count: i32, report_count: i32) -> BookRating {
+        BookRating {
+            id: Uuid::new_v4(),
+            book_id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            rating: 4.0,
+            review: Some("A thoughtful review".to_string()),
+            created_at: Utc::now(),
+            updated_at: None,
+            status: ReviewStatus::Published,
+            helpful_count,
+            not_helpful_count,
+            helpfulness: 0.0,
+            report_count,
+            moderation_reasons: vec![],
+        }
+    }
+
+    #[test]
+    fn test_should_hide_at_report_threshold() {
+        let service = ReviewService::new();
+
+        assert!(!service.should_hide(&review(0, 0, REPORTS_TO_HIDE - 1)));
+        assert!(service.should_hide(&review(0, 0, REPORTS_TO_HIDE)));
+
+        let mut already_hidden = review(0, 0, REPORTS_TO_HIDE + 1);
+        already_hidden.status = ReviewStatus::Hidden;
+        assert!(!service.should_hide(&already_hidden));
+    }
+
+    #[test]
+    fn test_check_can_respond() {
+        let service = ReviewService::new();
+        let rating = review(0, 0, 0);
+
+        assert!(service.check_can_respond(&rating, Uuid::new_v4()).is_ok());
+        assert!(service.check_can_respond(&rating, rating.user_id).is_err());
+
+        let mut without_text = review(0, 0, 0);
+        without_text.review = None;
+        assert!(service.check_can_respond(&without_text, Uuid::new_v4()).is_err());
+    }
+}
@@ -0,0 +1,185 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::shelf::{Shelf, ShelfEntry, ShelfStatus, ShelfStatusChange},
+    errors::domain_error::DomainError,
+};
+
+pub const MAX_SHELF_NAME_LENGTH: usize = 50;
+pub const MAX_SHELVES_PER_USER: usize = 100;
+
+/// Domain service for reading shelves
+pub struct ShelfService;
+
+impl ShelfService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Trimmed name for a new custom shelf, unique per user regardless of case
+    /// and not clashing with a standard shelf
+    pub fn check_shelf_name(&self, name: &str, existing: &[Shelf]) -> Result<String> {
+        let name = name.trim();
+        if name.is_empty() || name.chars().count() > MAX_SHELF_NAME_LENGTH {
+            return Err(DomainError::InvalidInput(format!(
+                "Shelf names must be 1 to {} characters", MAX_SHELF_NAME_LENGTH
+            )).into());
+        }
+        let normalized = name.to_lowercase().replace([' ', '-'], "_");
+        if ShelfStatus::ALL.iter().any(|status| status.as_str() == normalized) {
+            return Err(DomainError::InvalidInput(format!("\"{}\" is a standard shelf", name)).into());
+        }
+        if existing.iter().any(|shelf| shelf.name.to_lowercase() == name.to_lowercase()) {
+            return Err(DomainError::InvalidInput(format!("You already have a shelf named \"{}\"", name)).into());
+        }
+        if existing.len() >= MAX_SHELVES_PER_USER {
+            return Err(DomainError::InvalidInput(format!("At most {} custom shelves", MAX_SHELVES_PER_USER)).into());
+        }
+
+        Ok(name.to_string())
+    }
+
+    /// Put a book on a standard shelf, creating the entry if needed. Dates
+    /// default to `now` when the book is started, finished or abandoned;
+    /// moving back to want-to-read clears them. Returns the status change to
+    /// log, if the status changed.
+    pub fn shelve(
+        &self,
+        entry: Option<ShelfEntry>,
+        user_id: Uuid,
+        book_id: Uuid,
+        status: ShelfStatus,
+        started_at: Option<DateTime<Utc>>,
+        finished_at: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> Result<(ShelfEntry, Option<ShelfStatusChange>)> {
+        if started_at.is_some_and(|date| date > now) || finished_at.is_some_and(|date| date > now) {
+            return Err(DomainError::InvalidInput("Dates cannot be in the future".to_string()).into());
+        }
+
+        let previous = entry.as_ref().map(|entry| entry.status);
+        let mut entry = entry.unwrap_or_else(|| ShelfEntry::new(user_id, book_id, status));
+        let changed = previous != Some(status);
+
+        match status {
+            ShelfStatus::WantToRead => {
+                entry.started_at = None;
+                entry.finished_at = None;
+            }
+            ShelfStatus::CurrentlyReading => {
+                // Starting again after finishing or abandoning is a re-read
+                if changed {
+                    entry.started_at = Some(now);
+                    entry.finished_at = None;
+                }
+            }
+            ShelfStatus::Read | ShelfStatus::Abandoned => {
+                if changed {
+                    entry.finished_at = Some(now);
+                }
+            }
+        }
+        if status != ShelfStatus::WantToRead {
+            entry.started_at = started_at.or(entry.started_at);
+        }
+        if matches!(status, ShelfStatus::Read | ShelfStatus::Abandoned) {
+            entry.finished_at = finished_at.or(entry.finished_at);
+        }
+        if let (Some(started), Some(finished)) = (entry.started_at, entry.finished_at) {
+            if finished < started {
+                return Err(DomainError::InvalidInput("finished_at cannot be before started_at".to_string()).into());
+            }
+        }
+
+        entry.status = status;
+        entry.updated_at = now;
+        let change = changed.then(|| ShelfStatusChange {
+            changed_at: now,
+            ..ShelfStatusChange::new(user_id, book_id, previous, Some(status))
+        });
+
+        Ok((entry, change))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_shelf_names_are_unique_and_not_standard() {
+        let service = ShelfService::new();
+        let user_id = Uuid::new_v4();
+        let existing = vec![Shelf::new(user_id, "Book Club".to_string())];
+
+        assert_eq!(service.check_shelf_name("  Favorites ", &existing).unwrap(), "Favorites");
+        assert!(service.check_shelf_name("book club", &existing).is_err());
+        assert!(service.check_shelf_name("Want to read", &existing).is_err());
+        assert!(service.check_shelf_name("   ", &existing).is_err());
+    }
+
+    #[test]
+    fn test_shelve_records_dates_and_changes() {
+        let service = ShelfService::new();
+        let (user_id, book_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let now = Utc::now();
+
+        let (entry, change) = service
+            .shelve(None, user_id, book_id, ShelfStatus::WantToRead, None, None, now)
+            .unwrap();
+        assert!(entry.started_at.is_none());
+        assert_eq!(change.unwrap().from_status, None);
+
+        let (entry, change) = service
+            .shelve(Some(entry), user_id, book_id, ShelfStatus::CurrentlyReading, None, None, now)
+            .unwrap();
+        assert_eq!(entry.started_at, Some(now));
+        assert_eq!(change.unwrap().from_status, Some(ShelfStatus::WantToRead));
+
+        let finished = now + Duration::days(3);
+        let (entry, change) = service
+            .shelve(Some(entry), user_id, book_id, ShelfStatus::Read, None, None, finished)
+            .unwrap();
+        assert_eq!(entry.started_at, Some(now));
+        assert_eq!(entry.finished_at, Some(finished));
+        assert_eq!(change.unwrap().to_status, Some(ShelfStatus::Read));
+
+        // Fixing a date isn't a status change
+        let (entry, change) = service
+            .shelve(Some(entry), user_id, book_id, ShelfStatus::Read, None, Some(now + Duration::days(2)), finished)
+            .unwrap();
+        assert_eq!(entry.finished_at, Some(now + Duration::days(2)));
+        assert!(change.is_none());
+    }
+
+    #[test]
+    fn test_shelve_rejects_inconsistent_dates() {
+        let service = ShelfService::new();
+        let now = Utc::now();
+
+        let finished_before_started = service.shelve(
+            None,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            ShelfStatus::Read,
+            Some(now - Duration::days(1)),
+            Some(now - Duration::days(5)),
+            now,
+        );
+        let in_the_future = service.shelve(
+            None,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            ShelfStatus::CurrentlyReading,
+            Some(now + Duration::days(1)),
+            None,
+            now,
+        );
+
+        assert!(finished_before_started.is_err());
+        assert!(in_the_future.is_err());
+    }
+}
@@ -0,0 +1,318 @@
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::domain::entities::book::{Book, BookRating};
+use crate::domain::services::duplicate_detection_service::DuplicateDetectionService;
+
+/// Share of the score from the books themselves; the rest comes from readers
+/// who rated both
+const CONTENT_WEIGHT: f64 = 0.6;
+const GENRE_WEIGHT: f64 = 0.35;
+const TAG_WEIGHT: f64 = 0.2;
+const AUTHOR_WEIGHT: f64 = 0.2;
+const DESCRIPTION_WEIGHT: f64 = 0.25;
+
+/// Co-raters needed before co-rating similarity counts at half strength, so
+/// two readers who happen to agree don't make books look alike
+const CO_RATING_SHRINKAGE: f64 = 5.0;
+
+const STOP_WORDS: &[&str] = &[
+    "the", "and", "for", "with", "that", "this", "from", "his", "her", "their", "they", "she",
+    "was", "were", "are", "has", "have", "had", "but", "not", "into", "its", "who", "whom",
+    "what", "when", "where", "which", "will", "would", "can", "one", "all", "out", "about",
+    "book", "novel", "story",
+];
+
+/// A book ranked by how much it resembles another, with the parts of the
+/// score and a short reason shown to the reader
+#[derive(Debug, Clone)]
+pub struct SimilarBook {
+    pub book: Book,
+    pub score: f64,
+    pub content_score: f64,
+    pub co_rating_score: f64,
+    pub explanation: String,
+}
+
+/// Domain service for "more like this": content similarity (genres, tags,
+/// author, TF-IDF over descriptions) blended with co-rating similarity
+pub struct SimilarityService;
+
+impl SimilarityService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Rank candidates by similarity to `book`. `ratings` are the book's own
+    /// ratings and `co_ratings` the ratings its raters gave other books.
+    /// Other editions of the same work are left out.
+    pub fn rank(
+        &self,
+        book: &Book,
+        candidates: Vec<Book>,
+        ratings: &[BookRating],
+        co_ratings: &[BookRating],
+        limit: usize,
+    ) -> Vec<SimilarBook> {
+        let candidates: Vec<Book> = candidates
+            .into_iter()
+            .filter(|candidate| candidate.id != book.id)
+            .filter(|candidate| book.work_id.is_none() || candidate.work_id != book.work_id)
+            .collect();
+
+        let mut documents = vec![Self::terms(&book.description)];
+        documents.extend(candidates.iter().map(|candidate| Self::terms(&candidate.description)));
+        let idf = Self::inverse_document_frequencies(&documents);
+        let book_vector = Self::tf_idf(&documents[0], &idf);
+
+        let own_ratings: HashMap<Uuid, f64> = ratings
+            .iter()
+            .map(|rating| (rating.user_id, rating.rating))
+            .collect();
+        let mut ratings_by_book: HashMap<Uuid, Vec<(f64, f64)>> = HashMap::new();
+        for rating in co_ratings {
+            if let Some(&own) = own_ratings.get(&rating.user_id) {
+                ratings_by_book.entry(rating.book_id).or_default().push((own, rating.rating));
+            }
+        }
+
+        let mut similar: Vec<SimilarBook> = candidates
+            .into_iter()
+            .zip(documents.iter().skip(1))
+            .filter_map(|(candidate, terms)| {
+                let genres = Self::shared(&book.genre, &candidate.genre);
+                let tags = Self::shared(&book.tags, &candidate.tags);
+                let same_author = !book.author.trim().is_empty()
+                    && DuplicateDetectionService::normalize_author(&book.author)
+                        == DuplicateDetectionService::normalize_author(&candidate.author);
+                let description = Self::cosine(&book_vector, &Self::tf_idf(terms, &idf));
+
+                let genre_score = Self::jaccard(&book.genre, &candidate.genre) * GENRE_WEIGHT;
+                let tag_score = Self::jaccard(&book.tags, &candidate.tags) * TAG_WEIGHT;
+                let author_score = if same_author { AUTHOR_WEIGHT } else { 0.0 };
+                let description_score = description * DESCRIPTION_WEIGHT;
+                let content_score = genre_score + tag_score + author_score + description_score;
+
+                let pairs = ratings_by_book.get(&candidate.id).map(Vec::as_slice).unwrap_or(&[]);
+                let co_rating_score = Self::co_rating_similarity(pairs);
+
+                let score = content_score * CONTENT_WEIGHT + co_rating_score * (1.0 - CONTENT_WEIGHT);
+                if score <= 0.0 {
+                    return None;
+                }
+
+                // The strongest two signals explain the match
+                let liked_both = pairs.iter().filter(|(own, other)| *own >= 4.0 && *other >= 4.0).count();
+                let mut reasons: Vec<(f64, String)> = Vec::new();
+                if same_author {
+                    reasons.push((author_score * CONTENT_WEIGHT, format!("Also by {}", candidate.author)));
+                }
+                if !genres.is_empty() {
+                    reasons.push((genre_score * CONTENT_WEIGHT, format!("Also in {}", genres.join(", "))));
+                }
+                if !tags.is_empty() {
+                    reasons.push((tag_score * CONTENT_WEIGHT, format!("Tagged {}", tags.join(", "))));
+                }
+                if description >= 0.1 {
+                    reasons.push((description_score * CONTENT_WEIGHT, "Similar description".to_string()));
+                }
+                if liked_both > 0 {
+                    let readers = if liked_both == 1 { "reader" } else { "readers" };
+                    reasons.push((
+                        co_rating_score * (1.0 - CONTENT_WEIGHT),
+                        format!("{} {} who liked this book also liked it", liked_both, readers),
+                    ));
+                }
+                reasons.sort_by(|a, b| b.0.total_cmp(&a.0));
+                let explanation = reasons
+                    .into_iter()
+                    .take(2)
+                    .map(|(_, reason)| reason)
+                    .collect::<Vec<_>>()
+                    .join("; ");
+
+                Some(SimilarBook {
+                    book: candidate,
+                    score,
+                    content_score,
+                    co_rating_score,
+                    explanation,
+                })
+            })
+            .collect();
+
+        similar.sort_by(|a, b| b.score.total_cmp(&a.score));
+        similar.truncate(limit);
+        similar
+    }
+
+    /// Centered cosine over the ratings both books got from the same readers,
+    /// shrunk towards zero while there are few of them. Disagreement scores 0.
+    pub fn co_rating_similarity(pairs: &[(f64, f64)]) -> f64 {
+        if pairs.is_empty() {
+            return 0.0;
+        }
+
+        let (mut dot, mut own_norm, mut other_norm) = (0.0, 0.0, 0.0);
+        for (own, other) in pairs {
+            let (own, other) = (own - 3.0, other - 3.0);
+            dot += own * other;
+            own_norm += own * own;
+            other_norm += other * other;
+        }
+        if own_norm == 0.0 || other_norm == 0.0 {
+            return 0.0;
+        }
+
+        let count = pairs.len() as f64;
+        let cosine = dot / (own_norm.sqrt() * other_norm.sqrt());
+        cosine.max(0.0) * count / (count + CO_RATING_SHRINKAGE)
+    }
+
+    /// Lowercased description words, without stop words and very short words
+    fn terms(text: &str) -> Vec<String> {
+        text.split(|c: char| !c.is_alphanumeric())
+            .map(|word| word.to_lowercase())
+            .filter(|word| word.chars().count() >= 3 && !STOP_WORDS.contains(&word.as_str()))
+            .collect()
+    }
+
+    fn inverse_document_frequencies(documents: &[Vec<String>]) -> HashMap<String, f64> {
+        let mut document_frequencies: HashMap<&str, usize> = HashMap::new();
+        for document in documents {
+            let unique: HashSet<&str> = document.iter().map(String::as_str).collect();
+            for term in unique {
+                *document_frequencies.entry(term).or_default() += 1;
+            }
+        }
+
+        let count = documents.len() as f64;
+        document_frequencies
+            .into_iter()
+            .map(|(term, frequency)| (term.to_string(), (count / frequency as f64).ln() + 1.0))
+            .collect()
+    }
+
+    fn tf_idf(terms: &[String], idf: &HashMap<String, f64>) -> HashMap<String, f64> {
+        let mut vector: HashMap<String, f64> = HashMap::new();
+        for term in terms {
+            *vector.entry(term.clone()).or_default() += 1.0;
+        }
+        for (term, weight) in vector.iter_mut() {
+            *weight *= idf.get(term).copied().unwrap_or(0.0);
+        }
+        vector
+    }
+
+    fn cosine(a: &HashMap<String, f64>, b: &HashMap<String, f64>) -> f64 {
+        let dot: f64 = a.iter().filter_map(|(term, weight)| b.get(term).map(|other| weight * other)).sum();
+        let norm = |vector: &HashMap<String, f64>| vector.values().map(|w| w * w).sum::<f64>().sqrt();
+        let norms = norm(a) * norm(b);
+
+        if norms == 0.0 { 0.0 } else { dot / norms }
+    }
+
+    fn jaccard(a: &[String], b: &[String]) -> f64 {
+        let a: HashSet<String> = a.iter().map(|value| value.to_lowercase()).collect();
+        let b: HashSet<String> = b.iter().map(|value| value.to_lowercase()).collect();
+        let union = a.union(&b).count();
+
+        if union == 0 { 0.0 } else { a.intersection(&b).count() as f64 / union as f64 }
+    }
+
+    /// Values of `b` that also appear in `a`, ignoring case
+    fn shared(a: &[String], b: &[String]) -> Vec<String> {
+        let a: HashSet<String> = a.iter().map(|value| value.to_lowercase()).collect();
+        b.iter().filter(|value| a.contains(&value.to_lowercase())).cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn book(title: &str, author: &str, description: &str, genre: Vec<&str>) -> Book {
+        Book::new(
+            title.to_string(),
+            author.to_string(),
+            description.to_string(),
+            genre.into_iter().map(String::from).collect(),
+            2001,
+            "Test Publisher".to_string(),
+            "English".to_string(),
+            300,
+            Uuid::new_v4(),
+        )
+    }
+
+    fn rating(book_id: Uuid, user_id: Uuid, value: f64) -> BookRating {
+        BookRating {
+            id: Uuid::new_v4(),
+            book_id,
+            user_id,
+            rating: value,
+            review: None,
+            created_at: Utc::now(),
+            updated_at: None,
+            status: Default::default(),
+            helpful_count: 0,
+            not_helpful_count: 0,
+            helpfulness: 0.0,
+            report_count: 0,
+            moderation_reasons: vec![],
+        }
+    }
+
+    #[test]
+    fn test_content_overlap_ranks_first() {
+        let service = SimilarityService::new();
+        let dune = book("Dune", "Frank Herbert", "A desert planet, spice and a galactic empire", vec!["Science Fiction"]);
+        let messiah = book("Dune Messiah", "Frank Herbert", "The emperor rules the desert planet", vec!["Science Fiction"]);
+        let foundation = book("Foundation", "Isaac Asimov", "The fall of a galactic empire", vec!["Science Fiction"]);
+        let cookbook = book("Salt", "Someone Else", "Recipes for weeknight dinners", vec!["Cooking"]);
+
+        let similar = service.rank(&dune, vec![cookbook, foundation, messiah], &[], &[], 10);
+
+        let titles: Vec<&str> = similar.iter().map(|s| s.book.title.as_str()).collect();
+        assert_eq!(titles, vec!["Dune Messiah", "Foundation"]);
+        assert!(similar[0].explanation.contains("Also by Frank Herbert"));
+        assert_eq!(similar[0].co_rating_score, 0.0);
+    }
+
+    #[test]
+    fn test_co_ratings_lift_books_readers_liked_together() {
+        let service = SimilarityService::new();
+        let target = book("Target", "A", "", vec!["Fantasy"]);
+        let loved = book("Loved", "B", "", vec!["Fantasy"]);
+        let disliked = book("Disliked", "C", "", vec!["Fantasy"]);
+
+        let readers: Vec<Uuid> = (0..6).map(|_| Uuid::new_v4()).collect();
+        let ratings: Vec<BookRating> = readers.iter().map(|user| rating(target.id, *user, 5.0)).collect();
+        let mut co_ratings: Vec<BookRating> = readers.iter().map(|user| rating(loved.id, *user, 5.0)).collect();
+        co_ratings.extend(readers.iter().map(|user| rating(disliked.id, *user, 1.0)));
+
+        let similar = service.rank(&target, vec![disliked, loved], &ratings, &co_ratings, 10);
+
+        assert_eq!(similar[0].book.title, "Loved");
+        assert!(similar[0].co_rating_score > 0.5);
+        assert!(similar[0].explanation.contains("6 readers who liked this book also liked it"));
+        assert_eq!(similar[1].co_rating_score, 0.0);
+    }
+
+    #[test]
+    fn test_editions_of_the_same_work_are_excluded() {
+        let service = SimilarityService::new();
+        let work_id = Some(Uuid::new_v4());
+        let mut hardcover = book("Emma", "Jane Austen", "Matchmaking in Highbury", vec!["Classics"]);
+        hardcover.work_id = work_id;
+        let mut paperback = hardcover.clone();
+        paperback.id = Uuid::new_v4();
+        let persuasion = book("Persuasion", "Jane Austen", "A second chance", vec!["Classics"]);
+
+        let similar = service.rank(&hardcover, vec![paperback, persuasion], &[], &[], 10);
+
+        assert_eq!(similar.len(), 1);
+        assert_eq!(similar[0].book.title, "Persuasion");
+    }
+}
@@ -0,0 +1,387 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use anyhow::Result;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use chrono_tz::Tz;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        book::Book,
+        recommendation::ReadingSession,
+        stats::{
+            BookHighlight, BreakdownEntry, PeriodStats, ReadingStats, ReadingStreaks, ReadingTotals, YearInReview,
+        },
+    },
+    services::{genre_taxonomy::GenreTaxonomy, goal_service::GoalService},
+    errors::domain_error::DomainError,
+};
+
+pub const MAX_BREAKDOWN_ENTRIES: usize = 10;
+
+/// A book on the read shelf and when it was finished
+pub struct FinishedBook {
+    pub book: Book,
+    pub finished_at: DateTime<Utc>,
+}
+
+/// The reader's time zone from its IANA name, UTC when none is given
+pub fn parse_time_zone(name: Option<&str>) -> Result<Tz> {
+    match name {
+        Some(name) => name
+            .parse::<Tz>()
+            .map_err(|_| DomainError::InvalidInput(format!("Unknown time zone \"{}\"", name)).into()),
+        None => Ok(Tz::UTC),
+    }
+}
+
+/// Domain service for personal reading statistics. Every date is bucketed by
+/// the reader's local calendar; pages follow the goal rules, sessions plus
+/// the pages of a finished book that no session of it logged.
+pub struct StatsService;
+
+impl StatsService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn compute(
+        &self,
+        finished: &[FinishedBook],
+        sessions: &[ReadingSession],
+        ratings: &HashMap<Uuid, f64>, // Book -> the reader's rating
+        time_zone: Tz,
+        year: i32,
+        now: DateTime<Utc>,
+    ) -> ReadingStats {
+        let local = |time: DateTime<Utc>| time.with_timezone(&time_zone).date_naive();
+        let logged_pages = GoalService::logged_pages(sessions);
+        let finished: Vec<(&Book, NaiveDate, DateTime<Utc>)> = finished
+            .iter()
+            .map(|entry| (&entry.book, local(entry.finished_at), entry.finished_at))
+            .collect();
+        let sessions: Vec<(&ReadingSession, NaiveDate)> = sessions
+            .iter()
+            .filter_map(|session| session.end_time.map(|end_time| (session, local(end_time))))
+            .collect();
+
+        let by_year = Self::periods(&finished, &sessions, &logged_pages, |date| date.year().to_string());
+        let by_month = Self::periods(&finished, &sessions, &logged_pages, |date| date.format("%Y-%m").to_string());
+        let reading_days: BTreeSet<NaiveDate> = sessions.iter().map(|(_, date)| *date).collect();
+
+        let durations: Vec<i32> = sessions
+            .iter()
+            .filter_map(|(session, _)| session.session_duration_minutes)
+            .collect();
+        let totals = ReadingTotals {
+            books_read: finished.len() as i32,
+            pages_read: by_year.iter().map(|period| period.pages_read).sum(),
+            sessions_count: sessions.len() as i32,
+            minutes_read: durations.iter().sum(),
+            average_session_minutes: (!durations.is_empty())
+                .then(|| Self::round(durations.iter().sum::<i32>() as f64 / durations.len() as f64)),
+        };
+
+        let books: Vec<&Book> = finished.iter().map(|(book, _, _)| *book).collect();
+        let mut rating_distribution = vec![0; 5];
+        for rating in ratings.values() {
+            rating_distribution[(rating.round() as usize).clamp(1, 5) - 1] += 1;
+        }
+
+        ReadingStats {
+            time_zone: time_zone.name().to_string(),
+            totals,
+            streaks: Self::streaks(&reading_days, local(now)),
+            genres: Self::top(Self::breakdown(books.iter().flat_map(|book| book.genre.iter().map(String::as_str)))),
+            authors: Self::top(Self::breakdown(books.iter().map(|book| book.author.as_str()))),
+            decades: Self::decades(&books),
+            rating_distribution,
+            average_rating: (!ratings.is_empty())
+                .then(|| Self::round(ratings.values().sum::<f64>() / ratings.len() as f64)),
+            year_in_review: Self::year_in_review(year, &finished, &sessions, &by_year, &by_month, &reading_days, ratings),
+            by_year,
+            by_month,
+        }
+    }
+
+    fn periods(
+        finished: &[(&Book, NaiveDate, DateTime<Utc>)],
+        sessions: &[(&ReadingSession, NaiveDate)],
+        logged_pages: &HashMap<Uuid, i32>,
+        key: impl Fn(NaiveDate) -> String,
+    ) -> Vec<PeriodStats> {
+        let empty = |period: String| PeriodStats { period, books_read: 0, pages_read: 0, minutes_read: 0 };
+        let mut periods: BTreeMap<String, PeriodStats> = BTreeMap::new();
+
+        for (session, date) in sessions {
+            let period = key(*date);
+            let stats = periods.entry(period.clone()).or_insert_with(|| empty(period));
+            stats.pages_read += session.pages_read.max(0);
+            stats.minutes_read += session.session_duration_minutes.unwrap_or(0).max(0);
+        }
+        for (book, date, _) in finished {
+            let period = key(*date);
+            let stats = periods.entry(period.clone()).or_insert_with(|| empty(period));
+            stats.books_read += 1;
+            stats.pages_read += GoalService::unlogged_pages(book, logged_pages);
+        }
+
+        periods.into_values().collect()
+    }
+
+    fn streaks(days: &BTreeSet<NaiveDate>, today: NaiveDate) -> ReadingStreaks {
+        let longest = Self::longest_run(days);
+
+        let mut current_days = 0;
+        let mut day = if days.contains(&today) { today } else { today - Duration::days(1) };
+        while days.contains(&day) {
+            current_days += 1;
+            day -= Duration::days(1);
+        }
+
+        ReadingStreaks {
+            current_days,
+            longest_days: longest.map(|(start, end)| (end - start).num_days() as i32 + 1).unwrap_or(0),
+            longest_started_on: longest.map(|(start, _)| start),
+            longest_ended_on: longest.map(|(_, end)| end),
+        }
+    }
+
+    /// First and last day of the longest run of consecutive days; the
+    /// earliest run wins a tie
+    fn longest_run(days: &BTreeSet<NaiveDate>) -> Option<(NaiveDate, NaiveDate)> {
+        let mut longest: Option<(NaiveDate, NaiveDate)> = None;
+        let mut run: Option<(NaiveDate, NaiveDate)> = None;
+        for day in days {
+            let (start, end) = match run {
+                Some((start, end)) if *day == end + Duration::days(1) => (start, *day),
+                _ => (*day, *day),
+            };
+            run = Some((start, end));
+            if longest.is_none_or(|(longest_start, longest_end)| end - start > longest_end - longest_start) {
+                longest = Some((start, end));
+            }
+        }
+
+        longest
+    }
+
+    /// Books per name, most first; names are grouped ignoring case, spacing
+    /// and punctuation and shown as first seen
+    fn breakdown<'a>(names: impl Iterator<Item = &'a str>) -> Vec<BreakdownEntry> {
+        let mut entries: Vec<BreakdownEntry> = Vec::new();
+        let mut index: HashMap<String, usize> = HashMap::new();
+        for name in names {
+            let key = GenreTaxonomy::normalize_key(name);
+            if key.is_empty() {
+                continue;
+            }
+            match index.get(&key) {
+                Some(&position) => entries[position].books_read += 1,
+                None => {
+                    index.insert(key, entries.len());
+                    entries.push(BreakdownEntry { name: name.trim().to_string(), books_read: 1 });
+                }
+            }
+        }
+
+        entries.sort_by(|a, b| b.books_read.cmp(&a.books_read).then_with(|| a.name.cmp(&b.name)));
+        entries
+    }
+
+    fn top(mut entries: Vec<BreakdownEntry>) -> Vec<BreakdownEntry> {
+        entries.truncate(MAX_BREAKDOWN_ENTRIES);
+        entries
+    }
+
+    /// Books per publication decade, oldest first
+    fn decades(books: &[&Book]) -> Vec<BreakdownEntry> {
+        let mut decades: BTreeMap<i32, i32> = BTreeMap::new();
+        for book in books.iter().filter(|book| book.publication_year > 0) {
+            *decades.entry(book.publication_year / 10 * 10).or_default() += 1;
+        }
+
+        decades
+            .into_iter()
+            .map(|(decade, books_read)| BreakdownEntry { name: format!("{}s", decade), books_read })
+            .collect()
+    }
+
+    fn year_in_review(
+        year: i32,
+        finished: &[(&Book, NaiveDate, DateTime<Utc>)],
+        sessions: &[(&ReadingSession, NaiveDate)],
+        by_year: &[PeriodStats],
+        by_month: &[PeriodStats],
+        reading_days: &BTreeSet<NaiveDate>,
+        ratings: &HashMap<Uuid, f64>,
+    ) -> YearInReview {
+        let year_key = year.to_string();
+        let totals = by_year.iter().find(|period| period.period == year_key);
+        let mut books: Vec<(&Book, DateTime<Utc>)> = finished
+            .iter()
+            .filter(|(_, date, _)| date.year() == year)
+            .map(|(book, _, finished_at)| (*book, *finished_at))
+            .collect();
+        books.sort_by_key(|(_, finished_at)| *finished_at);
+
+        let highlight = |book: &Book| BookHighlight {
+            book_id: book.id,
+            title: book.title.clone(),
+            author: book.author.clone(),
+            page_count: book.page_count,
+            my_rating: ratings.get(&book.id).copied(),
+        };
+        let with_pages = || books.iter().map(|(book, _)| *book).filter(|book| book.page_count > 0);
+        let days: BTreeSet<NaiveDate> = reading_days.iter().filter(|day| day.year() == year).copied().collect();
+
+        YearInReview {
+            year,
+            books_read: books.len() as i32,
+            pages_read: totals.map(|period| period.pages_read).unwrap_or(0),
+            minutes_read: sessions
+                .iter()
+                .filter(|(_, date)| date.year() == year)
+                .map(|(session, _)| session.session_duration_minutes.unwrap_or(0).max(0))
+                .sum(),
+            longest_streak_days: Self::longest_run(&days)
+                .map(|(start, end)| (end - start).num_days() as i32 + 1)
+                .unwrap_or(0),
+            busiest_month: by_month
+                .iter()
+                .filter(|period| period.period.starts_with(&format!("{}-", year)) && period.books_read > 0)
+                .fold(None::<&PeriodStats>, |best, period| match best {
+                    Some(best) if best.books_read >= period.books_read => Some(best),
+                    _ => Some(period),
+                })
+                .map(|period| period.period.clone()),
+            top_genre: Self::breakdown(books.iter().flat_map(|(book, _)| book.genre.iter().map(String::as_str)))
+                .into_iter()
+                .next()
+                .map(|entry| entry.name),
+            top_author: Self::breakdown(books.iter().map(|(book, _)| book.author.as_str()))
+                .into_iter()
+                .next()
+                .map(|entry| entry.name),
+            longest_book: with_pages().max_by_key(|book| book.page_count).map(highlight),
+            shortest_book: with_pages().min_by_key(|book| book.page_count).map(highlight),
+            highest_rated: books
+                .iter()
+                .filter_map(|(book, _)| ratings.get(&book.id).map(|rating| (*book, *rating)))
+                .fold(None::<(&Book, f64)>, |best, (book, rating)| match best {
+                    Some((_, best_rating)) if best_rating >= rating => best,
+                    _ => Some((book, rating)),
+                })
+                .map(|(book, _)| highlight(book)),
+            first_book: books.first().map(|(book, _)| highlight(book)),
+            last_book: books.last().map(|(book, _)| highlight(book)),
+        }
+    }
+
+    fn round(value: f64) -> f64 {
+        (value * 10.0).round() / 10.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn book(author: &str, genres: &[&str], publication_year: i32, page_count: i32) -> Book {
+        Book::new(
+            "Title".to_string(),
+            author.to_string(),
+            "".to_string(),
+            genres.iter().map(|genre| genre.to_string()).collect(),
+            publication_year,
+            "Publisher".to_string(),
+            "English".to_string(),
+            page_count,
+            Uuid::new_v4(),
+        )
+    }
+
+    fn session(book_id: Uuid, end_time: DateTime<Utc>, pages_read: i32, minutes: i32) -> ReadingSession {
+        let mut session = ReadingSession::new(Uuid::new_v4(), book_id, 0);
+        session.start_time = end_time - Duration::minutes(minutes as i64);
+        session.end_time = Some(end_time);
+        session.pages_read = pages_read;
+        session.session_duration_minutes = Some(minutes);
+        session
+    }
+
+    #[test]
+    fn test_periods_follow_the_readers_time_zone() {
+        let service = StatsService::new();
+        let logged = book("A", &[], 2001, 300);
+        let unlogged = book("B", &[], 2001, 200);
+        // 23:30 UTC on New Year's Eve is already 2025 in Berlin
+        let new_years_eve = Utc.with_ymd_and_hms(2024, 12, 31, 23, 30, 0).unwrap();
+        let sessions = vec![session(logged.id, new_years_eve, 120, 45)];
+        let finished = vec![
+            FinishedBook { book: logged, finished_at: new_years_eve },
+            FinishedBook { book: unlogged, finished_at: new_years_eve },
+        ];
+        let now = Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap();
+
+        let berlin = service.compute(&finished, &sessions, &HashMap::new(), chrono_tz::Europe::Berlin, 2025, now);
+        assert_eq!(berlin.by_month.len(), 1);
+        assert_eq!(berlin.by_month[0].period, "2025-01");
+        // 120 logged, the other 180 of that book and all of the unlogged one
+        assert_eq!(berlin.by_month[0].pages_read, 500);
+        assert_eq!(berlin.year_in_review.books_read, 2);
+
+        let utc = service.compute(&finished, &sessions, &HashMap::new(), chrono_tz::UTC, 2025, now);
+        assert_eq!(utc.by_year[0].period, "2024");
+        assert_eq!(utc.year_in_review.books_read, 0);
+        assert_eq!(utc.totals.average_session_minutes, Some(45.0));
+    }
+
+    #[test]
+    fn test_streaks() {
+        let service = StatsService::new();
+        let book_id = Uuid::new_v4();
+        let day = |d: u32| Utc.with_ymd_and_hms(2025, 3, d, 18, 0, 0).unwrap();
+        // Runs of 3 (1-3) and 2 (9-10), then reading again yesterday (the 14th)
+        let sessions: Vec<ReadingSession> = [1, 2, 3, 9, 10, 14]
+            .into_iter()
+            .map(|d| session(book_id, day(d), 10, 20))
+            .collect();
+
+        let stats = service.compute(&[], &sessions, &HashMap::new(), chrono_tz::UTC, 2025, day(15));
+        assert_eq!(stats.streaks.current_days, 1);
+        assert_eq!(stats.streaks.longest_days, 3);
+        assert_eq!(stats.streaks.longest_started_on, NaiveDate::from_ymd_opt(2025, 3, 1));
+        assert_eq!(stats.year_in_review.longest_streak_days, 3);
+    }
+
+    #[test]
+    fn test_breakdowns_and_ratings() {
+        let service = StatsService::new();
+        let finished_at = Utc.with_ymd_and_hms(2025, 5, 1, 12, 0, 0).unwrap();
+        let books = vec![
+            book("Ursula K. Le Guin", &["Sci-Fi", "Fantasy"], 1969, 300),
+            book("ursula k le guin", &["SciFi"], 1974, 350),
+            book("Agatha Christie", &["Mystery"], 1934, 250),
+        ];
+        let ratings = HashMap::from([(books[0].id, 5.0), (books[2].id, 3.4)]);
+        let finished: Vec<FinishedBook> = books
+            .into_iter()
+            .map(|book| FinishedBook { book, finished_at })
+            .collect();
+
+        let stats = service.compute(&finished, &[], &ratings, chrono_tz::UTC, 2025, finished_at);
+        assert_eq!(stats.genres[0], BreakdownEntry { name: "Sci-Fi".to_string(), books_read: 2 });
+        assert_eq!(stats.authors[0].books_read, 2);
+        assert_eq!(
+            stats.decades.iter().map(|entry| entry.name.as_str()).collect::<Vec<_>>(),
+            vec!["1930s", "1960s", "1970s"]
+        );
+        assert_eq!(stats.rating_distribution, vec![0, 0, 1, 0, 1]);
+        assert_eq!(stats.average_rating, Some(4.2));
+        assert_eq!(stats.year_in_review.highest_rated.as_ref().unwrap().my_rating, Some(5.0));
+        assert_eq!(stats.year_in_review.longest_book.as_ref().unwrap().page_count, 350);
+        assert_eq!(stats.year_in_review.busiest_month.as_deref(), Some("2025-05"));
+    }
+}
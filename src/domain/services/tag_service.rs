@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+
+use crate::domain::entities::tag::{BookTag, TagStatus};
+
+/// Tags shown on a book, highest ranked first
+pub const MAX_DISPLAYED_TAGS: usize = 10;
+
+/// Longest tag accepted, in characters
+pub const MAX_TAG_LENGTH: usize = 40;
+
+/// Lower bound of the Wilson score interval (95%) for the share of positive
+/// votes, 0.0 when there are no votes
+pub fn wilson_lower_bound(positive: i32, negative: i32) -> f64 {
+    let total = (positive + negative) as f64;
+    if total <= 0.0 {
+        return 0.0;
+    }
+
+    let z = 1.96;
+    let p = positive as f64 / total;
+    let denominator = 1.0 + z * z / total;
+    let centre = p + z * z / (2.0 * total);
+    let margin = z * ((p * (1.0 - p) + z * z / (4.0 * total)) / total).sqrt();
+
+    ((centre - margin) / denominator).max(0.0)
+}
+
+/// Domain service for normalizing and ranking reader-suggested tags
+pub struct TagService;
+
+impl TagService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Lowercase, drop punctuation other than '-' and collapse whitespace, so
+    /// "Found Family!" and "found  family" are the same tag. Returns `None`
+    /// for tags that are empty or too long after normalization.
+    pub fn normalize_tag(tag: &str) -> Option<String> {
+        let cleaned: String = tag
+            .to_lowercase()
+            .chars()
+            .map(|c| if c.is_alphanumeric() || c == '-' { c } else { ' ' })
+            .collect();
+        let normalized = cleaned.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        if normalized.is_empty() || normalized.chars().count() > MAX_TAG_LENGTH {
+            None
+        } else {
+            Some(normalized)
+        }
+    }
+
+    /// Lower bound of the Wilson score interval (95%) for the share of
+    /// upvotes. Unlike the raw score it does not let a tag with 2 votes
+    /// outrank one with 200.
+    pub fn rank(&self, tag: &BookTag) -> f64 {
+        wilson_lower_bound(tag.upvotes, tag.downvotes)
+    }
+
+    /// Approved tags with a positive score, best ranked first
+    pub fn top_tags<'a>(&self, tags: &'a [BookTag], limit: usize) -> Vec<&'a BookTag> {
+        let mut visible: Vec<&BookTag> = tags
+            .iter()
+            .filter(|tag| tag.status == TagStatus::Approved && tag.score() > 0)
+            .collect();
+
+        visible.sort_by(|a, b| {
+            self.rank(b)
+                .partial_cmp(&self.rank(a))
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| b.score().cmp(&a.score()))
+        });
+        visible.truncate(limit);
+        visible
+    }
+
+    /// Tags to display on a book and whether they are community tags: the top
+    /// approved tags, or the catalog tags it was created with while no
+    /// approved tag has a positive score
+    pub fn displayed_tags(&self, tags: &[BookTag], catalog_tags: &[String], limit: usize) -> (Vec<String>, bool) {
+        let top: Vec<String> = self
+            .top_tags(tags, limit)
+            .into_iter()
+            .map(|tag| tag.tag.clone())
+            .collect();
+
+        if top.is_empty() {
+            (catalog_tags.to_vec(), false)
+        } else {
+            (top, true)
+        }
+    }
+
+    /// Tag weights for a book (0.0 to 1.0), relative to its best ranked tag.
+    /// Used as soft features for preference learning.
+    pub fn tag_weights(&self, tags: &[BookTag]) -> HashMap<String, f64> {
+        let ranked: Vec<(String, f64)> = self
+            .top_tags(tags, usize::MAX)
+            .into_iter()
+            .map(|tag| (tag.tag.clone(), self.rank(tag)))
+            .collect();
+
+        let max = ranked.iter().map(|(_, rank)| *rank).fold(0.0, f64::max);
+        if max <= 0.0 {
+            return HashMap::new();
+        }
+
+        ranked
+            .into_iter()
+            .map(|(tag, rank)| (tag, rank / max))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn tag(name: &str, upvotes: i32, downvotes: i32, status: TagStatus) -> BookTag {
+        let mut tag = BookTag::new(Uuid::new_v4(), name.to_string(), Uuid::new_v4());
+        tag.upvotes = upvotes;
+        tag.downvotes = downvotes;
+        tag.status = status;
+        tag
+    }
+
+    #[test]
+    fn test_normalize_tag() {
+        assert_eq!(TagService::normalize_tag("  Found   Family! "), Some("found family".to_string()));
+        assert_eq!(TagService::normalize_tag("slow-burn"), Some("slow-burn".to_string()));
+        assert_eq!(TagService::normalize_tag("!!!"), None);
+        assert_eq!(TagService::normalize_tag(&"a".repeat(MAX_TAG_LENGTH + 1)), None);
+    }
+
+    #[test]
+    fn test_top_tags_prefers_well_supported_tags() {
+        let service = TagService::new();
+        let tags = vec![
+            tag("few votes", 2, 0, TagStatus::Approved),
+            tag("many votes", 180, 20, TagStatus::Approved),
+            tag("pending", 500, 0, TagStatus::Pending),
+            tag("disliked", 1, 5, TagStatus::Approved),
+        ];
+
+        let top: Vec<&str> = service.top_tags(&tags, 10).iter().map(|t| t.tag.as_str()).collect();
+        assert_eq!(top, vec!["many votes", "few votes"]);
+    }
+
+    #[test]
+    fn test_displayed_tags_fall_back_after_last_tag_is_rejected() {
+        let service = TagService::new();
+        let catalog_tags = vec!["classic".to_string()];
+
+        let approved = vec![tag("cozy", 5, 0, TagStatus::Approved)];
+        assert_eq!(service.displayed_tags(&approved, &catalog_tags, 10), (vec!["cozy".to_string()], true));
+
+        // The tag repository only returns approved tags, none once it is rejected
+        assert_eq!(service.displayed_tags(&[], &catalog_tags, 10), (catalog_tags.clone(), false));
+    }
+
+    #[test]
+    fn test_displayed_tags_keep_catalog_tags_for_unvoted_approvals() {
+        let service = TagService::new();
+        let catalog_tags = vec!["classic".to_string(), "gothic".to_string()];
+        let approved = vec![tag("spooky", 0, 0, TagStatus::Approved)];
+
+        assert_eq!(service.displayed_tags(&approved, &catalog_tags, 10), (catalog_tags.clone(), false));
+    }
+
+    #[test]
+    fn test_tag_weights_are_relative() {
+        let service = TagService::new();
+        let tags = vec![
+            tag("cozy", 50, 0, TagStatus::Approved),
+            tag("witty", 10, 5, TagStatus::Approved),
+        ];
+
+        let weights = service.tag_weights(&tags);
+        assert!((weights["cozy"] - 1.0).abs() < f64::EPSILON);
+        assert!(weights["witty"] > 0.0 && weights["witty"] < 1.0);
+    }
+}
@@ -0,0 +1,223 @@
+use std::collections::HashMap;
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::{
+    book::{Book, BookRating},
+    recommendation::Recommendation,
+    trending::TrendingWindow,
+};
+use crate::domain::services::rating_service::RatingPrior;
+
+/// A book's standing in a discovery list
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrendingScore {
+    pub book_id: Uuid,
+    pub score: f64,
+    pub recent_ratings: i32,
+    pub recent_clicks: i32,
+}
+
+/// Domain service for the non-personalized discovery lists. Trending is
+/// rating and recommendation-click velocity with exponential time decay;
+/// new and notable ranks recent additions whose first ratings are strong.
+pub struct TrendingService {
+    pub list_size: usize,
+    pub click_weight: f64, // A click counts this much relative to a 5★ rating
+    pub new_release_days: i64,
+    pub new_and_notable_min_ratings: i32,
+    pub new_and_notable_min_average: f64,
+    pub rating_prior: RatingPrior,
+}
+
+impl Default for TrendingService {
+    fn default() -> Self {
+        Self {
+            list_size: 50,
+            click_weight: 0.5,
+            new_release_days: 90,
+            new_and_notable_min_ratings: 3,
+            new_and_notable_min_average: 4.0,
+            rating_prior: RatingPrior::default(),
+        }
+    }
+}
+
+impl TrendingService {
+    /// Default list settings ranked with `rating_prior`
+    pub fn new(rating_prior: RatingPrior) -> Self {
+        Self {
+            rating_prior,
+            ..Self::default()
+        }
+    }
+
+    /// Weight of activity `age` old, halving every `half_life`
+    pub fn decay(age: Duration, half_life: Duration) -> f64 {
+        let age = age.num_seconds().max(0) as f64;
+        let half_life = half_life.num_seconds().max(1) as f64;
+        0.5_f64.powf(age / half_life)
+    }
+
+    /// Rank books by decayed activity within the window. A rating counts in
+    /// proportion to its stars, so a burst of 1★ ratings doesn't trend.
+    pub fn trending(
+        &self,
+        window: TrendingWindow,
+        now: DateTime<Utc>,
+        ratings: &[BookRating],
+        clicks: &[Recommendation],
+    ) -> Vec<TrendingScore> {
+        let since = now - window.duration();
+
+        // Decayed score, ratings and clicks per book
+        let mut totals: HashMap<Uuid, (f64, i32, i32)> = HashMap::new();
+        for rating in ratings.iter().filter(|rating| rating.created_at >= since) {
+            let total = totals.entry(rating.book_id).or_default();
+            total.0 += Self::decay(now - rating.created_at, window.half_life()) * rating.rating / 5.0;
+            total.1 += 1;
+        }
+        for click in clicks {
+            let Some(clicked_at) = click.clicked_at.filter(|clicked_at| *clicked_at >= since) else {
+                continue;
+            };
+            let total = totals.entry(click.book_id).or_default();
+            total.0 += Self::decay(now - clicked_at, window.half_life()) * self.click_weight;
+            total.2 += 1;
+        }
+
+        let mut trending: Vec<TrendingScore> = totals
+            .into_iter()
+            .map(|(book_id, (score, recent_ratings, recent_clicks))| TrendingScore {
+                book_id,
+                score,
+                recent_ratings,
+                recent_clicks,
+            })
+            .collect();
+        trending.sort_by(|a, b| b.score.total_cmp(&a.score).then(b.recent_ratings.cmp(&a.recent_ratings)));
+        trending.truncate(self.list_size);
+        trending
+    }
+
+    /// Books added in the last `new_release_days` with enough strong early
+    /// ratings, best weighted rating first
+    pub fn new_and_notable(&self, now: DateTime<Utc>, books: &[Book]) -> Vec<TrendingScore> {
+        let since = now - Duration::days(self.new_release_days);
+
+        let mut notable: Vec<TrendingScore> = books
+            .iter()
+            .filter(|book| book.created_at >= since && book.deleted_at.is_none())
+            .filter(|book| book.ratings_count >= self.new_and_notable_min_ratings)
+            .filter(|book| book.average_rating >= self.new_and_notable_min_average)
+            .map(|book| TrendingScore {
+                book_id: book.id,
+                score: self.rating_prior.weighted_rating(book.average_rating, book.ratings_count),
+                recent_ratings: book.ratings_count,
+                recent_clicks: 0,
+            })
+            .collect();
+        notable.sort_by(|a, b| b.score.total_cmp(&a.score));
+        notable.truncate(self.list_size);
+        notable
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rating(book_id: Uuid, value: f64, created_at: DateTime<Utc>) -> BookRating {
+        BookRating {
+            id: Uuid::new_v4(),
+            book_id,
+            user_id: Uuid::new_v4(),
+            rating: value,
+            review: None,
+            created_at,
+            updated_at: None,
+            status: Default::default(),
+            helpful_count: 0,
+            not_helpful_count: 0,
+            helpfulness: 0.0,
+            report_count: 0,
+            moderation_reasons: vec![],
+        }
+    }
+
+    fn service() -> TrendingService {
+        TrendingService {
+            rating_prior: RatingPrior::default(),
+            ..TrendingService::default()
+        }
+    }
+
+    #[test]
+    fn test_recent_activity_outweighs_older_activity() {
+        let now = Utc::now();
+        let fresh = Uuid::new_v4();
+        let fading = Uuid::new_v4();
+        let ratings = vec![
+            rating(fresh, 5.0, now - Duration::hours(1)),
+            rating(fading, 5.0, now - Duration::days(6)),
+            rating(fading, 5.0, now - Duration::days(6)),
+            rating(fading, 5.0, now - Duration::days(10)), // Outside the window
+        ];
+
+        let trending = service().trending(TrendingWindow::Week, now, &ratings, &[]);
+
+        assert_eq!(trending[0].book_id, fresh);
+        assert_eq!(trending[1].book_id, fading);
+        assert_eq!(trending[1].recent_ratings, 2);
+        assert!((TrendingService::decay(TrendingWindow::Week.half_life(), TrendingWindow::Week.half_life()) - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_clicks_count_towards_trending() {
+        let now = Utc::now();
+        let clicked = Uuid::new_v4();
+        let mut click = Recommendation::new(Uuid::new_v4(), clicked, 0.9, "".to_string(), "v1".to_string());
+        click.mark_clicked();
+        let unclicked = Recommendation::new(Uuid::new_v4(), Uuid::new_v4(), 0.9, "".to_string(), "v1".to_string());
+
+        let trending = service().trending(TrendingWindow::Day, now, &[], &[click, unclicked]);
+
+        assert_eq!(trending.len(), 1);
+        assert_eq!(trending[0].book_id, clicked);
+        assert_eq!(trending[0].recent_clicks, 1);
+    }
+
+    #[test]
+    fn test_new_and_notable_needs_strong_early_ratings() {
+        let now = Utc::now();
+        let book = |title: &str, average: f64, count: i32, age_days: i64| {
+            let mut book = Book::new(
+                title.to_string(),
+                "Author".to_string(),
+                "".to_string(),
+                vec![],
+                2024,
+                "Publisher".to_string(),
+                "English".to_string(),
+                200,
+                Uuid::new_v4(),
+            );
+            book.average_rating = average;
+            book.ratings_count = count;
+            book.created_at = now - Duration::days(age_days);
+            book
+        };
+        let books = vec![
+            book("Debut", 4.6, 12, 10),
+            book("Breakout", 4.4, 80, 30),
+            book("Too few", 5.0, 1, 5),
+            book("Lukewarm", 3.2, 50, 5),
+            book("Backlist", 4.9, 500, 400),
+        ];
+
+        let notable = service().new_and_notable(now, &books);
+
+        let ids: Vec<Uuid> = notable.iter().map(|entry| entry.book_id).collect();
+        assert_eq!(ids, vec![books[1].id, books[0].id]);
+    }
+}
@@ -0,0 +1,99 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::entities::work::Work;
+
+/// Domain service for works, editions and series
+pub struct WorkService;
+
+impl WorkService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// The work that directly follows `current` in its series, if any.
+    /// `series_works` does not need to be sorted.
+    pub fn next_in_series<'a>(&self, series_works: &'a [Work], current: &Work) -> Option<&'a Work> {
+        let position = current.series_position?;
+
+        series_works
+            .iter()
+            .filter(|work| work.id != current.id && work.series_id == current.series_id)
+            .filter(|work| work.series_position.map_or(false, |p| p > position))
+            .min_by(|a, b| {
+                a.series_position
+                    .partial_cmp(&b.series_position)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+    }
+
+    /// Ensure no other work in the series already occupies `position`
+    pub fn validate_series_position(
+        &self,
+        series_works: &[Work],
+        work_id: Uuid,
+        position: f64,
+    ) -> Result<()> {
+        if position <= 0.0 {
+            return Err(anyhow::anyhow!("Series position must be positive"));
+        }
+
+        let taken = series_works
+            .iter()
+            .any(|work| work.id != work_id && work.series_position == Some(position));
+
+        if taken {
+            return Err(anyhow::anyhow!("Series position {} is already taken", position));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn work_in_series(series_id: Uuid, position: f64) -> Work {
+        let mut work = Work::new(
+            format!("Volume {}", position),
+            "Test Author".to_string(),
+            "Test description".to_string(),
+            vec!["Fantasy".to_string()],
+            "English".to_string(),
+            2000,
+            Uuid::new_v4(),
+        );
+        work.series_id = Some(series_id);
+        work.series_position = Some(position);
+        work
+    }
+
+    #[test]
+    fn test_next_in_series() {
+        let service = WorkService::new();
+        let series_id = Uuid::new_v4();
+        let works = vec![
+            work_in_series(series_id, 3.0),
+            work_in_series(series_id, 1.0),
+            work_in_series(series_id, 2.0),
+            work_in_series(series_id, 1.5),
+        ];
+
+        let next = service.next_in_series(&works, &works[1]).unwrap();
+        assert_eq!(next.series_position, Some(1.5));
+
+        assert!(service.next_in_series(&works, &works[0]).is_none());
+    }
+
+    #[test]
+    fn test_validate_series_position() {
+        let service = WorkService::new();
+        let series_id = Uuid::new_v4();
+        let works = vec![work_in_series(series_id, 1.0)];
+
+        assert!(service.validate_series_position(&works, Uuid::new_v4(), 1.0).is_err());
+        assert!(service.validate_series_position(&works, works[0].id, 1.0).is_ok());
+        assert!(service.validate_series_position(&works, Uuid::new_v4(), 2.0).is_ok());
+    }
+}
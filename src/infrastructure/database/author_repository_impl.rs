@@ -0,0 +1,124 @@
+use async_trait::async_trait;
+use anyhow::Result;
+use uuid::Uuid;
+use surrealdb::{Surreal, engine::remote::ws::Client};
+
+use crate::domain::{
+    entities::author::{Author, Publisher, BookContributor},
+    repositories::author_repository::AuthorRepository,
+};
+
+pub struct AuthorRepositoryImpl {
+    db: Surreal<Client>,
+}
+
+impl AuthorRepositoryImpl {
+    pub fn new(db: Surreal<Client>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl AuthorRepository for AuthorRepositoryImpl {
+    async fn save(&self, author: Author) -> Result<Author> {
+        let created: Option<Author> = self
+            .db
+            .create(("authors", author.id.to_string()))
+            .content(&author)
+            .await?;
+        
+        Ok(created.unwrap())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Author>> {
+        let author: Option<Author> = self
+            .db
+            .select(("authors", id.to_string()))
+            .await?;
+        
+        Ok(author)
+    }
+
+    async fn find_by_name_key(&self, name_key: &str) -> Result<Option<Author>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM authors WHERE $name_key IN name_keys LIMIT 1")
+            .bind(("name_key", name_key))
+            .await?;
+        
+        let authors: Vec<Author> = result.take(0)?;
+        Ok(authors.into_iter().next())
+    }
+
+    async fn update(&self, author: Author) -> Result<Author> {
+        let updated: Option<Author> = self
+            .db
+            .update(("authors", author.id.to_string()))
+            .content(&author)
+            .await?;
+        
+        Ok(updated.unwrap())
+    }
+
+    async fn save_contributor(&self, contributor: BookContributor) -> Result<BookContributor> {
+        let created: Option<BookContributor> = self
+            .db
+            .create(("book_contributors", contributor.id.to_string()))
+            .content(&contributor)
+            .await?;
+        
+        Ok(created.unwrap())
+    }
+
+    async fn get_contributors_for_book(&self, book_id: Uuid) -> Result<Vec<BookContributor>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM book_contributors WHERE book_id = $book_id ORDER BY position ASC")
+            .bind(("book_id", book_id))
+            .await?;
+        
+        let contributors: Vec<BookContributor> = result.take(0)?;
+        Ok(contributors)
+    }
+
+    async fn get_contributions_for_author(&self, author_id: Uuid) -> Result<Vec<BookContributor>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM book_contributors WHERE author_id = $author_id ORDER BY created_at ASC")
+            .bind(("author_id", author_id))
+            .await?;
+        
+        let contributors: Vec<BookContributor> = result.take(0)?;
+        Ok(contributors)
+    }
+
+    async fn save_publisher(&self, publisher: Publisher) -> Result<Publisher> {
+        let created: Option<Publisher> = self
+            .db
+            .create(("publishers", publisher.id.to_string()))
+            .content(&publisher)
+            .await?;
+        
+        Ok(created.unwrap())
+    }
+
+    async fn find_publisher_by_id(&self, id: Uuid) -> Result<Option<Publisher>> {
+        let publisher: Option<Publisher> = self
+            .db
+            .select(("publishers", id.to_string()))
+            .await?;
+        
+        Ok(publisher)
+    }
+
+    async fn find_publisher_by_name_key(&self, name_key: &str) -> Result<Option<Publisher>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM publishers WHERE $name_key IN name_keys LIMIT 1")
+            .bind(("name_key", name_key))
+            .await?;
+        
+        let publishers: Vec<Publisher> = result.take(0)?;
+        Ok(publishers.into_iter().next())
+    }
+}
@@ -1,12 +1,47 @@
 use async_trait::async_trait;
 use anyhow::Result;
 use uuid::Uuid;
+use chrono::{DateTime, Utc};
 use surrealdb::{Surreal, engine::remote::ws::Client};
 
 use crate::domain::{
-    entities::book::{Book, BookRating},
+    entities::{book::{Book, BookRating, ReviewSort}, revision::BookRevision},
     repositories::book_repository::BookRepository,
+    services::book_history_service::BookHistoryService,
+    errors::domain_error::DomainError,
 };
+use crate::infrastructure::database::work_repository_impl::RECOUNT_WORK_RATING_STATS;
+
+/// Restores the rating stats captured in `$stats` after a CONTENT write, so a
+/// book edit never overwrites ratings that landed while it was in flight
+const KEEP_RATING_STATS: &str = "
+    UPDATE type::thing('books', $book_key) SET
+        average_rating = $stats.average_rating,
+        ratings_count = $stats.ratings_count,
+        rating_sum = $stats.rating_sum,
+        rating_histogram = $stats.rating_histogram;
+";
+
+/// Folds a rating change into the running stats of `$book` and of its work.
+/// Expects `$book` and the rating values `$added` and `$removed` to be set,
+/// and must run inside the transaction that writes the rating.
+const ADJUST_RATING_STATS: &str = "
+    LET $histogram_delta = [1, 2, 3, 4, 5].map(|$star|
+        $added.filter(|$value| math::round($value) = $star).len()
+            - $removed.filter(|$value| math::round($value) = $star).len()
+    );
+    UPDATE $book SET
+        ratings_count += $added.len() - $removed.len(),
+        rating_sum += math::sum($added) - math::sum($removed),
+        rating_histogram = vector::add(rating_histogram, $histogram_delta),
+        average_rating = IF ratings_count > 0 THEN rating_sum / ratings_count ELSE 0.0 END;
+    IF $book.work_id IS NOT NONE {
+        UPDATE type::thing('works', <string> $book.work_id) SET
+            ratings_count += $added.len() - $removed.len(),
+            rating_sum += math::sum($added) - math::sum($removed),
+            average_rating = IF ratings_count > 0 THEN rating_sum / ratings_count ELSE 0.0 END;
+    };
+";
 
 pub struct BookRepositoryImpl {
     db: Surreal<Client>,
@@ -16,18 +51,58 @@ impl BookRepositoryImpl {
     pub fn new(db: Surreal<Client>) -> Self {
         Self { db }
     }
+
+    async fn next_revision_number(&self, book_id: Uuid) -> Result<i32> {
+        let mut result = self
+            .db
+            .query("SELECT VALUE revision_number FROM book_revisions WHERE book_id = $book_id ORDER BY revision_number DESC LIMIT 1")
+            .bind(("book_id", book_id))
+            .await?;
+
+        let latest: Vec<i32> = result.take(0)?;
+        Ok(latest.into_iter().next().unwrap_or(0) + 1)
+    }
+
+    /// Write the book and its revision in one transaction. The unique
+    /// (book_id, revision_number) index rejects a concurrent edit that raced
+    /// for the same revision number, and rating stats written since the book
+    /// was read are kept.
+    async fn write_with_revision(&self, statement: &str, book: Book, revision: BookRevision) -> Result<Book> {
+        let mut result = self
+            .db
+            .query(format!("
+                BEGIN TRANSACTION;
+                LET $stats = (SELECT average_rating, ratings_count, rating_sum, rating_histogram FROM ONLY type::thing('books', $book_key)) ?? $book;
+                {} type::thing('books', $book_key) CONTENT $book;
+                {}
+                CREATE type::thing('book_revisions', $revision_key) CONTENT $revision;
+                COMMIT TRANSACTION;
+            ", statement, KEEP_RATING_STATS))
+            .bind(("book_key", book.id.to_string()))
+            .bind(("book", book))
+            .bind(("revision_key", revision.id.to_string()))
+            .bind(("revision", revision))
+            .await?;
+
+        let books: Vec<Book> = result.take(2)?;
+        books.into_iter()
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("Book was not written"))
+    }
 }
 
 #[async_trait]
 impl BookRepository for BookRepositoryImpl {
     async fn save(&self, book: Book) -> Result<Book> {
-        let created: Option<Book> = self
-            .db
-            .create(("books", book.id.to_string()))
-            .content(&book)
-            .await?;
-        
-        Ok(created.unwrap())
+        let revision = BookRevision::new(
+            book.id,
+            1,
+            Some(book.created_by),
+            BookHistoryService::diff(None, &book)?,
+            BookHistoryService::snapshot(&book)?,
+        );
+
+        self.write_with_revision("CREATE", book, revision).await
     }
 
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Book>> {
@@ -36,13 +111,34 @@ impl BookRepository for BookRepositoryImpl {
             .select(("books", id.to_string()))
             .await?;
         
-        Ok(book)
+        Ok(book.filter(|book| book.deleted_at.is_none()))
+    }
+
+    async fn find_by_isbn(&self, isbn: &str) -> Result<Option<Book>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM books WHERE isbn = $isbn AND deleted_at IS NONE LIMIT 1")
+            .bind(("isbn", isbn))
+            .await?;
+        
+        let books: Vec<Book> = result.take(0)?;
+        Ok(books.into_iter().next())
     }
 
     async fn find_by_title(&self, title: &str) -> Result<Vec<Book>> {
         let mut result = self
             .db
-            .query("SELECT * FROM books WHERE string::lowercase(title) CONTAINS string::lowercase($title)")
+            .query("
+                SELECT * FROM books
+                WHERE (
+                    string::lowercase(title) CONTAINS string::lowercase($title)
+                    OR array::len(translations[WHERE
+                        string::lowercase(title) CONTAINS string::lowercase($title)
+                        OR string::lowercase(description ?? '') CONTAINS string::lowercase($title)
+                    ]) > 0
+                )
+                AND deleted_at IS NONE
+            ")
             .bind(("title", title))
             .await?;
         
@@ -53,7 +149,7 @@ impl BookRepository for BookRepositoryImpl {
     async fn find_by_author(&self, author: &str) -> Result<Vec<Book>> {
         let mut result = self
             .db
-            .query("SELECT * FROM books WHERE string::lowercase(author) CONTAINS string::lowercase($author)")
+            .query("SELECT * FROM books WHERE string::lowercase(author) CONTAINS string::lowercase($author) AND deleted_at IS NONE")
             .bind(("author", author))
             .await?;
         
@@ -64,7 +160,7 @@ impl BookRepository for BookRepositoryImpl {
     async fn find_by_genre(&self, genre: &str) -> Result<Vec<Book>> {
         let mut result = self
             .db
-            .query("SELECT * FROM books WHERE $genre IN genre")
+            .query("SELECT * FROM books WHERE $genre IN genre AND deleted_at IS NONE")
             .bind(("genre", genre))
             .await?;
         
@@ -78,7 +174,7 @@ impl BookRepository for BookRepositoryImpl {
         
         let mut result = self
             .db
-            .query("SELECT * FROM books ORDER BY created_at DESC LIMIT $limit START $offset")
+            .query("SELECT * FROM books WHERE deleted_at IS NONE ORDER BY created_at DESC LIMIT $limit START $offset")
             .bind(("limit", limit))
             .bind(("offset", offset))
             .await?;
@@ -87,33 +183,317 @@ impl BookRepository for BookRepositoryImpl {
         Ok(books)
     }
 
+    async fn find_page_by_id(&self, after_id: Option<Uuid>, limit: usize) -> Result<Vec<Book>> {
+        let mut result = match after_id {
+            Some(after_id) => self
+                .db
+                .query("SELECT * FROM books WHERE id > type::thing('books', $after_key) ORDER BY id ASC LIMIT $limit")
+                .bind(("after_key", after_id.to_string()))
+                .bind(("limit", limit))
+                .await?,
+            None => self
+                .db
+                .query("SELECT * FROM books ORDER BY id ASC LIMIT $limit")
+                .bind(("limit", limit))
+                .await?,
+        };
+
+        let books: Vec<Book> = result.take(0)?;
+        Ok(books)
+    }
+
+    async fn find_with_cover_hash(&self) -> Result<Vec<Book>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM books WHERE cover_hash IS NOT NONE AND deleted_at IS NONE")
+            .await?;
+
+        let books: Vec<Book> = result.take(0)?;
+        Ok(books)
+    }
+
+    async fn find_created_since(&self, since: DateTime<Utc>) -> Result<Vec<Book>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM books WHERE created_at >= $since AND deleted_at IS NONE")
+            .bind(("since", since))
+            .await?;
+
+        let books: Vec<Book> = result.take(0)?;
+        Ok(books)
+    }
+
+    async fn find_similar_candidates(&self, book: &Book, limit: usize) -> Result<Vec<Book>> {
+        let mut result = self
+            .db
+            .query("
+                SELECT * FROM books
+                WHERE deleted_at IS NONE
+                    AND id != type::thing('books', $book_key)
+                    AND (genre CONTAINSANY $genre OR tags CONTAINSANY $tags OR author = $author)
+                ORDER BY ratings_count DESC
+                LIMIT $limit
+            ")
+            .bind(("book_key", book.id.to_string()))
+            .bind(("genre", book.genre.clone()))
+            .bind(("tags", book.tags.clone()))
+            .bind(("author", book.author.clone()))
+            .bind(("limit", limit))
+            .await?;
+
+        let books: Vec<Book> = result.take(0)?;
+        Ok(books)
+    }
+
     async fn update(&self, book: Book) -> Result<Book> {
-        let updated: Option<Book> = self
+        let previous = self.find_by_id(book.id).await?;
+        let changes = BookHistoryService::diff(previous.as_ref(), &book)?;
+
+        // Rating stats and bookkeeping fields are not revisions
+        if changes.is_empty() {
+            let mut result = self
+                .db
+                .query(format!("
+                    BEGIN TRANSACTION;
+                    LET $stats = (SELECT average_rating, ratings_count, rating_sum, rating_histogram FROM ONLY type::thing('books', $book_key)) ?? $book;
+                    UPDATE type::thing('books', $book_key) CONTENT $book;
+                    {}
+                    COMMIT TRANSACTION;
+                ", KEEP_RATING_STATS))
+                .bind(("book_key", book.id.to_string()))
+                .bind(("book", book))
+                .await?;
+
+            let books: Vec<Book> = result.take(2)?;
+            return books.into_iter()
+                .next()
+                .ok_or_else(|| DomainError::BookNotFound.into());
+        }
+
+        let revision = BookRevision::new(
+            book.id,
+            self.next_revision_number(book.id).await?,
+            book.updated_by,
+            changes,
+            BookHistoryService::snapshot(&book)?,
+        );
+
+        self.write_with_revision("UPDATE", book, revision).await
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<()> {
+        // A deleted edition's ratings no longer count towards its work
+        let mut result = self
+            .db
+            .query(format!("
+                BEGIN TRANSACTION;
+                UPDATE type::thing('books', $book_key) SET deleted_at = time::now() WHERE deleted_at IS NONE;
+                LET $work_id = (SELECT VALUE work_id FROM ONLY type::thing('books', $book_key));
+                IF $work_id IS NOT NONE {{
+                    {}
+                }};
+                COMMIT TRANSACTION;
+            ", RECOUNT_WORK_RATING_STATS))
+            .bind(("book_key", id.to_string()))
+            .await?;
+
+        let _: Option<surrealdb::sql::Value> = result.take(2)?;
+        Ok(())
+    }
+
+    async fn find_deleted(&self, limit: Option<usize>, offset: Option<usize>) -> Result<Vec<Book>> {
+        let limit = limit.unwrap_or(20);
+        let offset = offset.unwrap_or(0);
+        
+        let mut result = self
             .db
-            .update(("books", book.id.to_string()))
-            .content(&book)
+            .query("SELECT * FROM books WHERE deleted_at IS NOT NONE ORDER BY deleted_at DESC LIMIT $limit START $offset")
+            .bind(("limit", limit))
+            .bind(("offset", offset))
             .await?;
         
-        Ok(updated.unwrap())
+        let books: Vec<Book> = result.take(0)?;
+        Ok(books)
     }
 
-    async fn delete(&self, id: Uuid) -> Result<()> {
-        let _: Option<Book> = self
+    async fn restore(&self, id: Uuid) -> Result<Option<Book>> {
+        let mut result = self
             .db
-            .delete(("books", id.to_string()))
+            .query(format!("
+                BEGIN TRANSACTION;
+                UPDATE type::thing('books', $book_key) SET deleted_at = NONE WHERE deleted_at IS NOT NONE;
+                LET $work_id = (SELECT VALUE work_id FROM ONLY type::thing('books', $book_key));
+                IF $work_id IS NOT NONE {{
+                    {}
+                }};
+                COMMIT TRANSACTION;
+            ", RECOUNT_WORK_RATING_STATS))
+            .bind(("book_key", id.to_string()))
             .await?;
         
-        Ok(())
+        let books: Vec<Book> = result.take(0)?;
+        Ok(books.into_iter().next())
     }
 
-    async fn save_rating(&self, rating: BookRating) -> Result<BookRating> {
-        let created: Option<BookRating> = self
+    async fn purge_deleted(&self, deleted_before: DateTime<Utc>) -> Result<usize> {
+        // Revisions outlive the book they describe and are kept
+        let mut result = self
             .db
-            .create(("book_ratings", rating.id.to_string()))
-            .content(&rating)
+            .query(format!("
+                BEGIN TRANSACTION;
+                LET $books = (SELECT VALUE id FROM books WHERE deleted_at IS NOT NONE AND deleted_at < $deleted_before);
+                LET $book_ids = $books.map(|$book| meta::id($book));
+                LET $rating_ids = (SELECT VALUE meta::id(id) FROM book_ratings WHERE book_id IN $book_ids);
+                LET $works = array::distinct((SELECT VALUE work_id FROM $books WHERE work_id IS NOT NONE));
+                DELETE review_votes WHERE rating_id IN $rating_ids;
+                DELETE review_reports WHERE rating_id IN $rating_ids;
+                DELETE book_ratings WHERE book_id IN $book_ids;
+                DELETE recommendations WHERE book_id IN $book_ids;
+                DELETE reading_sessions WHERE book_id IN $book_ids;
+                DELETE shelf_entries WHERE book_id IN $book_ids;
+                DELETE shelf_status_changes WHERE book_id IN $book_ids;
+                DELETE book_contributors WHERE book_id IN $book_ids;
+                DELETE tag_votes WHERE book_tag_id IN (SELECT VALUE meta::id(id) FROM book_tags WHERE book_id IN $book_ids);
+                DELETE book_tags WHERE book_id IN $book_ids;
+                DELETE duplicate_candidates WHERE book_id IN $book_ids OR duplicate_book_id IN $book_ids;
+                DELETE book_redirects WHERE to_book_id IN $book_ids;
+                DELETE notifications WHERE book_id IN $book_ids;
+                DELETE trending_books WHERE book_id IN $book_ids;
+                DELETE $books;
+                FOR $work_id IN $works {{
+                    {}
+                }};
+                RETURN array::len($books);
+                COMMIT TRANSACTION;
+            ", RECOUNT_WORK_RATING_STATS))
+            .bind(("deleted_before", deleted_before))
             .await?;
         
-        Ok(created.unwrap())
+        let purged: Option<usize> = result.take(20)?;
+        Ok(purged.unwrap_or(0))
+    }
+
+    async fn save_rating(&self, rating: BookRating) -> Result<BookRating> {
+        let mut result = self
+            .db
+            .query(format!("
+                BEGIN TRANSACTION;
+                LET $book = type::thing('books', $book_key);
+                CREATE type::thing('book_ratings', $rating_key) CONTENT $rating;
+                LET $added = [$rating.rating];
+                LET $removed = [];
+                {}
+                COMMIT TRANSACTION;
+            ", ADJUST_RATING_STATS))
+            .bind(("book_key", rating.book_id.to_string()))
+            .bind(("rating_key", rating.id.to_string()))
+            .bind(("rating", rating))
+            .await?;
+
+        let created: Vec<BookRating> = result.take(1)?;
+        created.into_iter()
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("Rating was not written"))
+    }
+
+    async fn find_rating(&self, id: Uuid) -> Result<Option<BookRating>> {
+        let rating: Option<BookRating> = self
+            .db
+            .select(("book_ratings", id.to_string()))
+            .await?;
+
+        Ok(rating)
+    }
+
+    async fn update_rating(&self, rating: BookRating) -> Result<BookRating> {
+        // The previous value is read inside the transaction so a concurrent
+        // edit of the same rating can't be folded in twice. Only the fields an
+        // edit or a moderation decision changes are written; vote and report
+        // tallies are maintained by their own recounts.
+        let mut result = self
+            .db
+            .query(format!("
+                BEGIN TRANSACTION;
+                LET $book = type::thing('books', $book_key);
+                LET $previous = (SELECT VALUE rating FROM ONLY type::thing('book_ratings', $rating_key));
+                IF $previous IS NONE {{ THROW 'Rating not found' }};
+                UPDATE type::thing('book_ratings', $rating_key) SET
+                    rating = $rating.rating,
+                    review = $rating.review,
+                    updated_at = $rating.updated_at,
+                    status = $rating.status,
+                    moderation_reasons = $rating.moderation_reasons;
+                LET $added = [$rating.rating];
+                LET $removed = [$previous];
+                {}
+                COMMIT TRANSACTION;
+            ", ADJUST_RATING_STATS))
+            .bind(("book_key", rating.book_id.to_string()))
+            .bind(("rating_key", rating.id.to_string()))
+            .bind(("rating", rating))
+            .await?;
+
+        let updated: Vec<BookRating> = result.take(3)?;
+        updated.into_iter()
+            .next()
+            .ok_or_else(|| DomainError::RatingNotFound.into())
+    }
+
+    async fn delete_rating(&self, rating: &BookRating) -> Result<()> {
+        let mut result = self
+            .db
+            .query(format!("
+                BEGIN TRANSACTION;
+                LET $book = type::thing('books', $book_key);
+                LET $deleted = (DELETE type::thing('book_ratings', $rating_key) RETURN BEFORE);
+                LET $added = [];
+                LET $removed = $deleted.rating;
+                {}
+                DELETE review_votes WHERE rating_id = $rating_id;
+                DELETE review_reports WHERE rating_id = $rating_id;
+                COMMIT TRANSACTION;
+            ", ADJUST_RATING_STATS))
+            .bind(("book_key", rating.book_id.to_string()))
+            .bind(("rating_key", rating.id.to_string()))
+            .bind(("rating_id", rating.id))
+            .await?;
+
+        let _: Option<surrealdb::sql::Value> = result.take(5)?;
+        Ok(())
+    }
+
+    async fn get_reviews_for_book(
+        &self,
+        book_id: Uuid,
+        sort: ReviewSort,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<BookRating>> {
+        let limit = limit.unwrap_or(20);
+        let offset = offset.unwrap_or(0);
+        let order = match sort {
+            ReviewSort::Newest => "created_at DESC",
+            ReviewSort::Oldest => "created_at ASC",
+            ReviewSort::HighestRated => "rating DESC, created_at DESC",
+            ReviewSort::LowestRated => "rating ASC, created_at DESC",
+            ReviewSort::MostHelpful => "helpfulness DESC, helpful_count DESC, created_at DESC",
+        };
+
+        let mut result = self
+            .db
+            .query(format!(
+                "SELECT * FROM book_ratings WHERE book_id = $book_id AND review IS NOT NONE AND status = 'published'
+                    AND user_id NOT IN (SELECT VALUE meta::id(id) FROM users WHERE deleted_at IS NOT NONE)
+                    ORDER BY {} LIMIT $limit START $offset",
+                order
+            ))
+            .bind(("book_id", book_id))
+            .bind(("limit", limit))
+            .bind(("offset", offset))
+            .await?;
+
+        let reviews: Vec<BookRating> = result.take(0)?;
+        Ok(reviews)
     }
 
     async fn get_ratings_for_book(&self, book_id: Uuid) -> Result<Vec<BookRating>> {
@@ -139,25 +519,53 @@ impl BookRepository for BookRepositoryImpl {
         Ok(ratings.into_iter().next())
     }
 
-    async fn update_book_rating_stats(&self, book_id: Uuid) -> Result<()> {
-        // Calculate new average rating and count
+    async fn get_ratings_since(&self, since: DateTime<Utc>) -> Result<Vec<BookRating>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM book_ratings WHERE created_at >= $since")
+            .bind(("since", since))
+            .await?;
+
+        let ratings: Vec<BookRating> = result.take(0)?;
+        Ok(ratings)
+    }
+
+    async fn get_co_ratings(&self, book_id: Uuid, max_raters: usize) -> Result<Vec<BookRating>> {
         let mut result = self
             .db
             .query("
-                LET $ratings = (SELECT rating FROM book_ratings WHERE book_id = $book_id);
-                LET $avg_rating = math::mean($ratings.rating);
-                LET $count = array::len($ratings);
-                UPDATE books SET 
-                    average_rating = $avg_rating,
-                    ratings_count = $count,
-                    updated_at = time::now()
-                WHERE id = $book_id;
+                LET $raters = (SELECT VALUE user_id FROM book_ratings WHERE book_id = $book_id ORDER BY created_at DESC LIMIT $max_raters);
+                SELECT * FROM book_ratings WHERE user_id IN $raters AND book_id != $book_id;
             ")
             .bind(("book_id", book_id))
+            .bind(("max_raters", max_raters))
             .await?;
-        
-        let _: Option<surrealdb::sql::Value> = result.take(2)?;
-        Ok(())
+
+        let ratings: Vec<BookRating> = result.take(1)?;
+        Ok(ratings)
+    }
+
+    async fn update_book_rating_stats(&self, book_id: Uuid) -> Result<Option<Book>> {
+        // Full recompute from the stored ratings, for merges and drift repair.
+        // updated_at is left alone since the catalog data did not change.
+        let mut result = self
+            .db
+            .query("
+                BEGIN TRANSACTION;
+                LET $ratings = (SELECT VALUE rating FROM book_ratings WHERE book_id = $book_id);
+                UPDATE type::thing('books', $book_key) SET
+                    ratings_count = array::len($ratings),
+                    rating_sum = math::sum($ratings),
+                    rating_histogram = [1, 2, 3, 4, 5].map(|$star| $ratings.filter(|$value| math::round($value) = $star).len()),
+                    average_rating = IF array::len($ratings) > 0 THEN math::mean($ratings) ELSE 0.0 END;
+                COMMIT TRANSACTION;
+            ")
+            .bind(("book_id", book_id))
+            .bind(("book_key", book_id.to_string()))
+            .await?;
+
+        let books: Vec<Book> = result.take(1)?;
+        Ok(books.into_iter().next())
     }
 
     async fn get_user_ratings(&self, user_id: Uuid) -> Result<Vec<BookRating>> {
@@ -170,4 +578,48 @@ impl BookRepository for BookRepositoryImpl {
         let ratings: Vec<BookRating> = result.take(0)?;
         Ok(ratings)
     }
+
+    async fn get_user_ratings_page(&self, user_id: Uuid, limit: Option<usize>, offset: Option<usize>) -> Result<Vec<BookRating>> {
+        let limit = limit.unwrap_or(20);
+        let offset = offset.unwrap_or(0);
+
+        let mut result = self
+            .db
+            .query("SELECT * FROM book_ratings WHERE user_id = $user_id ORDER BY created_at DESC LIMIT $limit START $offset")
+            .bind(("user_id", user_id))
+            .bind(("limit", limit))
+            .bind(("offset", offset))
+            .await?;
+
+        let ratings: Vec<BookRating> = result.take(0)?;
+        Ok(ratings)
+    }
+
+    async fn get_revisions(&self, book_id: Uuid, limit: Option<usize>, offset: Option<usize>) -> Result<Vec<BookRevision>> {
+        let limit = limit.unwrap_or(20);
+        let offset = offset.unwrap_or(0);
+
+        let mut result = self
+            .db
+            .query("SELECT * FROM book_revisions WHERE book_id = $book_id ORDER BY revision_number DESC LIMIT $limit START $offset")
+            .bind(("book_id", book_id))
+            .bind(("limit", limit))
+            .bind(("offset", offset))
+            .await?;
+
+        let revisions: Vec<BookRevision> = result.take(0)?;
+        Ok(revisions)
+    }
+
+    async fn get_revision(&self, book_id: Uuid, revision_number: i32) -> Result<Option<BookRevision>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM book_revisions WHERE book_id = $book_id AND revision_number = $revision_number LIMIT 1")
+            .bind(("book_id", book_id))
+            .bind(("revision_number", revision_number))
+            .await?;
+
+        let revisions: Vec<BookRevision> = result.take(0)?;
+        Ok(revisions.into_iter().next())
+    }
 }
\ No newline at end of file
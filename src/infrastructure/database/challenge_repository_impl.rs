@@ -0,0 +1,128 @@
+use async_trait::async_trait;
+use anyhow::Result;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use surrealdb::{Surreal, engine::remote::ws::Client};
+
+use crate::domain::{
+    entities::challenge::{Challenge, ChallengeParticipant},
+    repositories::challenge_repository::ChallengeRepository,
+};
+
+pub struct ChallengeRepositoryImpl {
+    db: Surreal<Client>,
+}
+
+impl ChallengeRepositoryImpl {
+    pub fn new(db: Surreal<Client>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl ChallengeRepository for ChallengeRepositoryImpl {
+    async fn save(&self, challenge: Challenge) -> Result<Challenge> {
+        let created: Option<Challenge> = self
+            .db
+            .create(("challenges", challenge.id.to_string()))
+            .content(&challenge)
+            .await?;
+
+        Ok(created.unwrap())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Challenge>> {
+        let challenge: Option<Challenge> = self
+            .db
+            .select(("challenges", id.to_string()))
+            .await?;
+
+        Ok(challenge)
+    }
+
+    async fn find_all(&self, active_at: Option<DateTime<Utc>>) -> Result<Vec<Challenge>> {
+        let filter = if active_at.is_some() { "WHERE starts_at <= $active_at AND ends_at > $active_at" } else { "" };
+
+        let mut result = self
+            .db
+            .query(format!("SELECT * FROM challenges {} ORDER BY starts_at DESC", filter))
+            .bind(("active_at", active_at))
+            .await?;
+
+        let challenges: Vec<Challenge> = result.take(0)?;
+        Ok(challenges)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<()> {
+        let mut result = self
+            .db
+            .query("
+                BEGIN TRANSACTION;
+                DELETE FROM challenge_participants WHERE challenge_id = $challenge_id;
+                DELETE type::thing('challenges', $challenge_key);
+                COMMIT TRANSACTION;
+            ")
+            .bind(("challenge_id", id))
+            .bind(("challenge_key", id.to_string()))
+            .await?;
+
+        let _: Option<surrealdb::sql::Value> = result.take(1)?;
+        Ok(())
+    }
+
+    async fn join(&self, participant: ChallengeParticipant) -> Result<ChallengeParticipant> {
+        let created: Option<ChallengeParticipant> = self
+            .db
+            .create(("challenge_participants", participant.id.to_string()))
+            .content(&participant)
+            .await?;
+
+        Ok(created.unwrap())
+    }
+
+    async fn leave(&self, challenge_id: Uuid, user_id: Uuid) -> Result<()> {
+        let mut result = self
+            .db
+            .query("DELETE FROM challenge_participants WHERE challenge_id = $challenge_id AND user_id = $user_id")
+            .bind(("challenge_id", challenge_id))
+            .bind(("user_id", user_id))
+            .await?;
+
+        let _: Option<surrealdb::sql::Value> = result.take(0)?;
+        Ok(())
+    }
+
+    async fn find_participant(&self, challenge_id: Uuid, user_id: Uuid) -> Result<Option<ChallengeParticipant>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM challenge_participants WHERE challenge_id = $challenge_id AND user_id = $user_id LIMIT 1")
+            .bind(("challenge_id", challenge_id))
+            .bind(("user_id", user_id))
+            .await?;
+
+        let participants: Vec<ChallengeParticipant> = result.take(0)?;
+        Ok(participants.into_iter().next())
+    }
+
+    async fn find_participants(&self, challenge_id: Uuid) -> Result<Vec<ChallengeParticipant>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM challenge_participants WHERE challenge_id = $challenge_id ORDER BY joined_at ASC")
+            .bind(("challenge_id", challenge_id))
+            .await?;
+
+        let participants: Vec<ChallengeParticipant> = result.take(0)?;
+        Ok(participants)
+    }
+
+    async fn count_participants(&self, challenge_id: Uuid) -> Result<usize> {
+        let mut result = self
+            .db
+            .query("RETURN array::len((SELECT id FROM challenge_participants WHERE challenge_id = $challenge_id))")
+            .bind(("challenge_id", challenge_id))
+            .await?;
+
+        let count: Option<usize> = result.take(0)?;
+        Ok(count.unwrap_or(0))
+    }
+}
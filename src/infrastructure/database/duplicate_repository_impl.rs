@@ -0,0 +1,171 @@
+use async_trait::async_trait;
+use anyhow::Result;
+use uuid::Uuid;
+use surrealdb::{Surreal, engine::remote::ws::Client};
+
+use crate::domain::{
+    entities::{
+        book::Book,
+        duplicate::{DuplicateCandidate, BookRedirect},
+        revision::BookRevision,
+    },
+    repositories::duplicate_repository::DuplicateRepository,
+};
+use crate::infrastructure::database::work_repository_impl::RECOUNT_WORK_RATING_STATS;
+
+pub struct DuplicateRepositoryImpl {
+    db: Surreal<Client>,
+}
+
+impl DuplicateRepositoryImpl {
+    pub fn new(db: Surreal<Client>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl DuplicateRepository for DuplicateRepositoryImpl {
+    async fn save_candidate(&self, candidate: DuplicateCandidate) -> Result<DuplicateCandidate> {
+        let created: Option<DuplicateCandidate> = self
+            .db
+            .create(("duplicate_candidates", candidate.id.to_string()))
+            .content(&candidate)
+            .await?;
+
+        Ok(created.unwrap())
+    }
+
+    async fn find_candidate(&self, id: Uuid) -> Result<Option<DuplicateCandidate>> {
+        let candidate: Option<DuplicateCandidate> = self
+            .db
+            .select(("duplicate_candidates", id.to_string()))
+            .await?;
+
+        Ok(candidate)
+    }
+
+    async fn find_candidate_for_pair(&self, book_id: Uuid, duplicate_book_id: Uuid) -> Result<Option<DuplicateCandidate>> {
+        let mut result = self
+            .db
+            .query("
+                SELECT * FROM duplicate_candidates
+                WHERE (book_id = $book_id AND duplicate_book_id = $duplicate_book_id)
+                   OR (book_id = $duplicate_book_id AND duplicate_book_id = $book_id)
+                LIMIT 1
+            ")
+            .bind(("book_id", book_id))
+            .bind(("duplicate_book_id", duplicate_book_id))
+            .await?;
+
+        let candidates: Vec<DuplicateCandidate> = result.take(0)?;
+        Ok(candidates.into_iter().next())
+    }
+
+    async fn find_pending_candidates(&self, limit: Option<usize>, offset: Option<usize>) -> Result<Vec<DuplicateCandidate>> {
+        let limit = limit.unwrap_or(20);
+        let offset = offset.unwrap_or(0);
+
+        let mut result = self
+            .db
+            .query("SELECT * FROM duplicate_candidates WHERE status = 'pending' ORDER BY similarity DESC, created_at ASC LIMIT $limit START $offset")
+            .bind(("limit", limit))
+            .bind(("offset", offset))
+            .await?;
+
+        let candidates: Vec<DuplicateCandidate> = result.take(0)?;
+        Ok(candidates)
+    }
+
+    async fn update_candidate(&self, candidate: DuplicateCandidate) -> Result<DuplicateCandidate> {
+        let updated: Option<DuplicateCandidate> = self
+            .db
+            .update(("duplicate_candidates", candidate.id.to_string()))
+            .content(&candidate)
+            .await?;
+
+        Ok(updated.unwrap())
+    }
+
+    async fn merge_books(&self, source_id: Uuid, survivor: Book, revision: Option<BookRevision>) -> Result<BookRedirect> {
+        let target_id = survivor.id;
+        let redirect = BookRedirect {
+            from_book_id: source_id,
+            to_book_id: target_id,
+            created_at: chrono::Utc::now(),
+        };
+
+        // Rows that would collide with the survivor's unique (user, book) rows
+        // are dropped, the survivor's own data wins, along with the votes and
+        // reports on dropped ratings. Only the catalog fields the merge copies
+        // are written; the works of both books are recounted once the source's
+        // ratings have moved.
+        let mut result = self
+            .db
+            .query(format!("
+                BEGIN TRANSACTION;
+                LET $works = array::distinct((SELECT VALUE work_id FROM books
+                    WHERE meta::id(id) IN [<string> $source_id, <string> $target_id] AND work_id IS NOT NONE));
+                LET $dropped_ratings = (SELECT VALUE meta::id(id) FROM book_ratings WHERE book_id = $source_id
+                    AND user_id IN (SELECT VALUE user_id FROM book_ratings WHERE book_id = $target_id));
+                UPDATE type::thing('books', $target_key) MERGE {{
+                    isbn: $survivor.isbn,
+                    cover_images: $survivor.cover_images,
+                    cover_image_url: $survivor.cover_image_url,
+                    tags: $survivor.tags,
+                    catalog_tags: $survivor.catalog_tags,
+                    community_tags: $survivor.community_tags,
+                    updated_at: $survivor.updated_at,
+                    updated_by: $survivor.updated_by,
+                }};
+                IF $revision IS NOT NONE {{
+                    CREATE type::thing('book_revisions', $revision_key) CONTENT $revision;
+                }};
+                DELETE review_votes WHERE rating_id IN $dropped_ratings;
+                DELETE review_reports WHERE rating_id IN $dropped_ratings;
+                DELETE book_ratings WHERE meta::id(id) IN $dropped_ratings;
+                UPDATE book_ratings SET book_id = $target_id WHERE book_id = $source_id;
+                UPDATE recommendations SET book_id = $target_id WHERE book_id = $source_id;
+                UPDATE reading_sessions SET book_id = $target_id WHERE book_id = $source_id;
+                DELETE shelf_entries WHERE book_id = $source_id
+                    AND user_id IN (SELECT VALUE user_id FROM shelf_entries WHERE book_id = $target_id);
+                UPDATE shelf_entries SET book_id = $target_id WHERE book_id = $source_id;
+                UPDATE shelf_status_changes SET book_id = $target_id WHERE book_id = $source_id;
+                DELETE book_contributors WHERE book_id = $source_id
+                    AND author_id IN (SELECT VALUE author_id FROM book_contributors WHERE book_id = $target_id);
+                UPDATE book_contributors SET book_id = $target_id WHERE book_id = $source_id;
+                DELETE book_tags WHERE book_id = $source_id
+                    AND tag IN (SELECT VALUE tag FROM book_tags WHERE book_id = $target_id);
+                UPDATE book_tags SET book_id = $target_id WHERE book_id = $source_id;
+                UPDATE duplicate_candidates SET status = 'dismissed'
+                    WHERE status = 'pending' AND (book_id = $source_id OR duplicate_book_id = $source_id);
+                UPDATE book_redirects SET to_book_id = $target_id WHERE to_book_id = $source_id;
+                CREATE type::thing('book_redirects', $source_key) CONTENT $redirect;
+                DELETE type::thing('books', $source_key);
+                FOR $work_id IN $works {{
+                    {}
+                }};
+                COMMIT TRANSACTION;
+            ", RECOUNT_WORK_RATING_STATS))
+            .bind(("source_id", source_id))
+            .bind(("target_id", target_id))
+            .bind(("source_key", source_id.to_string()))
+            .bind(("target_key", target_id.to_string()))
+            .bind(("survivor", survivor))
+            .bind(("revision_key", revision.as_ref().map(|revision| revision.id.to_string())))
+            .bind(("revision", revision))
+            .bind(("redirect", redirect.clone()))
+            .await?;
+
+        let _: Option<surrealdb::sql::Value> = result.take(21)?;
+        Ok(redirect)
+    }
+
+    async fn find_redirect(&self, book_id: Uuid) -> Result<Option<BookRedirect>> {
+        let redirect: Option<BookRedirect> = self
+            .db
+            .select(("book_redirects", book_id.to_string()))
+            .await?;
+
+        Ok(redirect)
+    }
+}
@@ -0,0 +1,92 @@
+use async_trait::async_trait;
+use anyhow::Result;
+use uuid::Uuid;
+use surrealdb::{Surreal, engine::remote::ws::Client};
+
+use crate::domain::{
+    entities::genre::Genre,
+    repositories::genre_repository::GenreRepository,
+};
+
+pub struct GenreRepositoryImpl {
+    db: Surreal<Client>,
+}
+
+impl GenreRepositoryImpl {
+    pub fn new(db: Surreal<Client>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl GenreRepository for GenreRepositoryImpl {
+    async fn save(&self, genre: Genre) -> Result<Genre> {
+        let created: Option<Genre> = self
+            .db
+            .create(("genres", genre.id.to_string()))
+            .content(&genre)
+            .await?;
+        
+        Ok(created.unwrap())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Genre>> {
+        let genre: Option<Genre> = self
+            .db
+            .select(("genres", id.to_string()))
+            .await?;
+        
+        Ok(genre)
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<Option<Genre>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM genres WHERE slug = $slug")
+            .bind(("slug", slug))
+            .await?;
+        
+        let genres: Vec<Genre> = result.take(0)?;
+        Ok(genres.into_iter().next())
+    }
+
+    async fn find_all(&self) -> Result<Vec<Genre>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM genres ORDER BY name ASC")
+            .await?;
+        
+        let genres: Vec<Genre> = result.take(0)?;
+        Ok(genres)
+    }
+
+    async fn find_children(&self, parent_id: Uuid) -> Result<Vec<Genre>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM genres WHERE parent_id = $parent_id ORDER BY name ASC")
+            .bind(("parent_id", parent_id))
+            .await?;
+        
+        let genres: Vec<Genre> = result.take(0)?;
+        Ok(genres)
+    }
+
+    async fn update(&self, genre: Genre) -> Result<Genre> {
+        let updated: Option<Genre> = self
+            .db
+            .update(("genres", genre.id.to_string()))
+            .content(&genre)
+            .await?;
+        
+        Ok(updated.unwrap())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<()> {
+        let _: Option<Genre> = self
+            .db
+            .delete(("genres", id.to_string()))
+            .await?;
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+use anyhow::Result;
+use uuid::Uuid;
+use surrealdb::{Surreal, engine::remote::ws::Client};
+
+use crate::domain::{
+    entities::goal::ReadingGoal,
+    repositories::goal_repository::GoalRepository,
+};
+
+pub struct GoalRepositoryImpl {
+    db: Surreal<Client>,
+}
+
+impl GoalRepositoryImpl {
+    pub fn new(db: Surreal<Client>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl GoalRepository for GoalRepositoryImpl {
+    async fn save(&self, goal: ReadingGoal) -> Result<ReadingGoal> {
+        let saved: Option<ReadingGoal> = self
+            .db
+            .upsert(("reading_goals", goal.id.to_string()))
+            .content(&goal)
+            .await?;
+
+        Ok(saved.unwrap())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ReadingGoal>> {
+        let goal: Option<ReadingGoal> = self
+            .db
+            .select(("reading_goals", id.to_string()))
+            .await?;
+
+        Ok(goal)
+    }
+
+    async fn find_for_user(&self, user_id: Uuid, year: Option<i32>) -> Result<Vec<ReadingGoal>> {
+        let filter = if year.is_some() { "AND year = $year" } else { "" };
+
+        let mut result = self
+            .db
+            .query(format!(
+                "SELECT * FROM reading_goals WHERE user_id = $user_id {} ORDER BY year DESC, month DESC, metric ASC",
+                filter
+            ))
+            .bind(("user_id", user_id))
+            .bind(("year", year))
+            .await?;
+
+        let goals: Vec<ReadingGoal> = result.take(0)?;
+        Ok(goals)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<()> {
+        let _: Option<ReadingGoal> = self
+            .db
+            .delete(("reading_goals", id.to_string()))
+            .await?;
+
+        Ok(())
+    }
+}
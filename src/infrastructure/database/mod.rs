@@ -3,7 +3,29 @@
 pub mod user_repository_impl;
 pub mod book_repository_impl;
 pub mod recommendation_repository_impl;
+pub mod duplicate_repository_impl;
+pub mod work_repository_impl;
+pub mod author_repository_impl;
+pub mod genre_repository_impl;
+pub mod tag_repository_impl;
+pub mod review_repository_impl;
+pub mod notification_repository_impl;
+pub mod trending_repository_impl;
+pub mod shelf_repository_impl;
+pub mod goal_repository_impl;
+pub mod challenge_repository_impl;
 
 pub use user_repository_impl::*;
 pub use book_repository_impl::*;
-pub use recommendation_repository_impl::*;
\ No newline at end of file
+pub use recommendation_repository_impl::*;
+pub use duplicate_repository_impl::*;
+pub use work_repository_impl::*;
+pub use author_repository_impl::*;
+pub use genre_repository_impl::*;
+pub use tag_repository_impl::*;
+pub use review_repository_impl::*;
+pub use notification_repository_impl::*;
+pub use trending_repository_impl::*;
+pub use shelf_repository_impl::*;
+pub use goal_repository_impl::*;
+pub use challenge_repository_impl::*;
\ No newline at end of file
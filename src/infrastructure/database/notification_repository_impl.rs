@@ -0,0 +1,77 @@
+use async_trait::async_trait;
+use anyhow::Result;
+use uuid::Uuid;
+use surrealdb::{Surreal, engine::remote::ws::Client};
+
+use crate::domain::{
+    entities::notification::Notification,
+    repositories::notification_repository::NotificationRepository,
+};
+
+pub struct NotificationRepositoryImpl {
+    db: Surreal<Client>,
+}
+
+impl NotificationRepositoryImpl {
+    pub fn new(db: Surreal<Client>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl NotificationRepository for NotificationRepositoryImpl {
+    async fn save(&self, notification: Notification) -> Result<Notification> {
+        let created: Option<Notification> = self
+            .db
+            .create(("notifications", notification.id.to_string()))
+            .content(&notification)
+            .await?;
+
+        Ok(created.unwrap())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Notification>> {
+        let notification: Option<Notification> = self
+            .db
+            .select(("notifications", id.to_string()))
+            .await?;
+
+        Ok(notification)
+    }
+
+    async fn find_for_user(
+        &self,
+        user_id: Uuid,
+        unread_only: bool,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<Notification>> {
+        let limit = limit.unwrap_or(20);
+        let offset = offset.unwrap_or(0);
+        let filter = if unread_only { "AND read_at IS NONE" } else { "" };
+
+        let mut result = self
+            .db
+            .query(format!(
+                "SELECT * FROM notifications WHERE user_id = $user_id {} ORDER BY created_at DESC LIMIT $limit START $offset",
+                filter
+            ))
+            .bind(("user_id", user_id))
+            .bind(("limit", limit))
+            .bind(("offset", offset))
+            .await?;
+
+        let notifications: Vec<Notification> = result.take(0)?;
+        Ok(notifications)
+    }
+
+    async fn update(&self, notification: Notification) -> Result<Notification> {
+        let updated: Option<Notification> = self
+            .db
+            .update(("notifications", notification.id.to_string()))
+            .content(&notification)
+            .await?;
+
+        Ok(updated.unwrap())
+    }
+}
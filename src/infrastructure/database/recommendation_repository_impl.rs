@@ -1,6 +1,7 @@
 use async_trait::async_trait;
 use anyhow::Result;
 use uuid::Uuid;
+use chrono::{DateTime, Utc};
 use surrealdb::{Surreal, engine::remote::ws::Client};
 
 use crate::domain::{
@@ -45,12 +46,14 @@ impl RecommendationRepository for RecommendationRepositoryImpl {
     }
 
     async fn mark_recommendation_clicked(&self, recommendation_id: Uuid) -> Result<()> {
-        let _: Option<Recommendation> = self
+        // Only the first click counts towards trending
+        let mut result = self
             .db
-            .update(("recommendations", recommendation_id.to_string()))
-            .patch(surrealdb::opt::PatchOp::replace("/is_clicked", true))
+            .query("UPDATE type::thing('recommendations', $recommendation_key) SET is_clicked = true, clicked_at = clicked_at ?? time::now()")
+            .bind(("recommendation_key", recommendation_id.to_string()))
             .await?;
         
+        let _: Option<surrealdb::sql::Value> = result.take(0)?;
         Ok(())
     }
 
@@ -76,6 +79,17 @@ impl RecommendationRepository for RecommendationRepositoryImpl {
         Ok(())
     }
 
+    async fn get_clicked_since(&self, since: DateTime<Utc>) -> Result<Vec<Recommendation>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM recommendations WHERE clicked_at >= $since")
+            .bind(("since", since))
+            .await?;
+
+        let recommendations: Vec<Recommendation> = result.take(0)?;
+        Ok(recommendations)
+    }
+
     async fn save_user_preference(&self, preference: UserPreference) -> Result<UserPreference> {
         let created: Option<UserPreference> = self
             .db
@@ -129,16 +143,36 @@ impl RecommendationRepository for RecommendationRepositoryImpl {
         Ok(created.unwrap())
     }
 
-    async fn end_reading_session(&self, session_id: Uuid, pages_read: i32, duration_minutes: i32) -> Result<()> {
-        let _: Option<ReadingSession> = self
+    async fn find_reading_session(&self, session_id: Uuid) -> Result<Option<ReadingSession>> {
+        let session: Option<ReadingSession> = self
             .db
-            .update(("reading_sessions", session_id.to_string()))
-            .patch(surrealdb::opt::PatchOp::replace("/end_time", chrono::Utc::now()))
-            .patch(surrealdb::opt::PatchOp::replace("/pages_read", pages_read))
-            .patch(surrealdb::opt::PatchOp::replace("/session_duration_minutes", duration_minutes))
+            .select(("reading_sessions", session_id.to_string()))
             .await?;
         
-        Ok(())
+        Ok(session)
+    }
+
+    async fn end_reading_session(&self, session: ReadingSession) -> Result<Option<ReadingSession>> {
+        // Guarded so a session ended twice concurrently is only written once
+        let mut result = self
+            .db
+            .query("
+                UPDATE type::thing('reading_sessions', $session_key) SET
+                    end_time = $end_time,
+                    end_page = $end_page,
+                    pages_read = $pages_read,
+                    session_duration_minutes = $duration_minutes
+                WHERE end_time IS NONE
+            ")
+            .bind(("session_key", session.id.to_string()))
+            .bind(("end_time", session.end_time))
+            .bind(("end_page", session.end_page))
+            .bind(("pages_read", session.pages_read))
+            .bind(("duration_minutes", session.session_duration_minutes))
+            .await?;
+        
+        let ended: Vec<ReadingSession> = result.take(0)?;
+        Ok(ended.into_iter().next())
     }
 
     async fn get_user_reading_sessions(&self, user_id: Uuid, limit: Option<usize>) -> Result<Vec<ReadingSession>> {
@@ -155,6 +189,47 @@ impl RecommendationRepository for RecommendationRepositoryImpl {
         Ok(sessions)
     }
 
+    async fn get_book_reading_sessions(&self, user_id: Uuid, book_id: Uuid) -> Result<Vec<ReadingSession>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM reading_sessions WHERE user_id = $user_id AND book_id = $book_id ORDER BY start_time ASC")
+            .bind(("user_id", user_id))
+            .bind(("book_id", book_id))
+            .await?;
+        
+        let sessions: Vec<ReadingSession> = result.take(0)?;
+        Ok(sessions)
+    }
+
+    async fn get_ended_reading_sessions(&self, user_id: Uuid) -> Result<Vec<ReadingSession>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM reading_sessions WHERE user_id = $user_id AND end_time IS NOT NONE ORDER BY end_time ASC")
+            .bind(("user_id", user_id))
+            .await?;
+        
+        let sessions: Vec<ReadingSession> = result.take(0)?;
+        Ok(sessions)
+    }
+
+    async fn get_reading_sessions_between(
+        &self,
+        user_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<ReadingSession>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM reading_sessions WHERE user_id = $user_id AND end_time >= $from AND end_time < $to ORDER BY end_time ASC")
+            .bind(("user_id", user_id))
+            .bind(("from", from))
+            .bind(("to", to))
+            .await?;
+        
+        let sessions: Vec<ReadingSession> = result.take(0)?;
+        Ok(sessions)
+    }
+
     async fn get_recommendation_click_rate(&self, user_id: Uuid) -> Result<f64> {
         let mut result = self
             .db
@@ -0,0 +1,192 @@
+use async_trait::async_trait;
+use anyhow::Result;
+use uuid::Uuid;
+use surrealdb::{Surreal, engine::remote::ws::Client};
+
+use crate::domain::{
+    entities::{
+        book::BookRating,
+        review::{ReviewVote, ReviewReport, ReportStatus},
+    },
+    repositories::review_repository::ReviewRepository,
+    errors::domain_error::DomainError,
+};
+
+/// Recounts the votes on the review `$rating_id` and rescores its helpfulness,
+/// the lower bound of the 95% Wilson score interval (z = 1.96) for the helpful
+/// share, so one helpful vote doesn't outrank 90 of 100. The only place the
+/// score is computed; it is written with the counts so a concurrent vote can't
+/// leave it scored from stale counts.
+pub const RECOUNT_REVIEW_VOTES: &str = "
+    LET $helpful = count((SELECT id FROM review_votes WHERE rating_id = $rating_id AND helpful = true));
+    LET $not_helpful = count((SELECT id FROM review_votes WHERE rating_id = $rating_id AND helpful = false));
+    LET $total = <float> ($helpful + $not_helpful);
+    UPDATE type::thing('book_ratings', <string> $rating_id) SET
+        helpful_count = $helpful,
+        not_helpful_count = $not_helpful,
+        helpfulness = IF $total > 0 THEN
+            math::max([0.0, (
+                $helpful / $total + 3.8416 / (2 * $total)
+                    - 1.96 * math::sqrt(($helpful / $total * (1 - $helpful / $total) + 3.8416 / (4 * $total)) / $total)
+            ) / (1 + 3.8416 / $total)])
+        ELSE 0.0 END;
+";
+
+pub struct ReviewRepositoryImpl {
+    db: Surreal<Client>,
+}
+
+impl ReviewRepositoryImpl {
+    pub fn new(db: Surreal<Client>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl ReviewRepository for ReviewRepositoryImpl {
+    async fn find_vote(&self, rating_id: Uuid, user_id: Uuid) -> Result<Option<ReviewVote>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM review_votes WHERE rating_id = $rating_id AND user_id = $user_id LIMIT 1")
+            .bind(("rating_id", rating_id))
+            .bind(("user_id", user_id))
+            .await?;
+
+        let votes: Vec<ReviewVote> = result.take(0)?;
+        Ok(votes.into_iter().next())
+    }
+
+    async fn save_vote(&self, vote: ReviewVote) -> Result<ReviewVote> {
+        // Upsert by id so a changed vote replaces the previous one
+        let saved: Option<ReviewVote> = self
+            .db
+            .upsert(("review_votes", vote.id.to_string()))
+            .content(&vote)
+            .await?;
+
+        Ok(saved.unwrap())
+    }
+
+    async fn delete_vote(&self, id: Uuid) -> Result<()> {
+        let _: Option<ReviewVote> = self
+            .db
+            .delete(("review_votes", id.to_string()))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn update_review_vote_counts(&self, rating_id: Uuid) -> Result<BookRating> {
+        let mut result = self
+            .db
+            .query(format!("
+                BEGIN TRANSACTION;
+                {}
+                COMMIT TRANSACTION;
+            ", RECOUNT_REVIEW_VOTES))
+            .bind(("rating_id", rating_id))
+            .await?;
+
+        let ratings: Vec<BookRating> = result.take(3)?;
+        ratings.into_iter()
+            .next()
+            .ok_or_else(|| DomainError::ReviewNotFound.into())
+    }
+
+    async fn find_report(&self, rating_id: Uuid, reported_by: Uuid) -> Result<Option<ReviewReport>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM review_reports WHERE rating_id = $rating_id AND reported_by = $reported_by LIMIT 1")
+            .bind(("rating_id", rating_id))
+            .bind(("reported_by", reported_by))
+            .await?;
+
+        let reports: Vec<ReviewReport> = result.take(0)?;
+        Ok(reports.into_iter().next())
+    }
+
+    async fn find_open_reports(&self, rating_id: Uuid) -> Result<Vec<ReviewReport>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM review_reports WHERE rating_id = $rating_id AND status = 'open' ORDER BY created_at ASC")
+            .bind(("rating_id", rating_id))
+            .await?;
+
+        let reports: Vec<ReviewReport> = result.take(0)?;
+        Ok(reports)
+    }
+
+    async fn save_report(&self, report: ReviewReport) -> Result<ReviewReport> {
+        let created: Option<ReviewReport> = self
+            .db
+            .create(("review_reports", report.id.to_string()))
+            .content(&report)
+            .await?;
+
+        Ok(created.unwrap())
+    }
+
+    async fn resolve_reports(&self, rating_id: Uuid, status: ReportStatus, resolved_by: Uuid) -> Result<()> {
+        self.db
+            .query("
+                UPDATE review_reports SET
+                    status = $status,
+                    resolved_by = $resolved_by,
+                    resolved_at = time::now()
+                WHERE rating_id = $rating_id AND status = 'open'
+            ")
+            .bind(("rating_id", rating_id))
+            .bind(("status", status))
+            .bind(("resolved_by", resolved_by))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn update_review_report_count(&self, rating_id: Uuid) -> Result<BookRating> {
+        let mut result = self
+            .db
+            .query("
+                UPDATE type::thing('book_ratings', $rating_key) SET
+                    report_count = count((SELECT id FROM review_reports WHERE rating_id = $rating_id AND status = 'open'))
+            ")
+            .bind(("rating_key", rating_id.to_string()))
+            .bind(("rating_id", rating_id))
+            .await?;
+
+        let ratings: Vec<BookRating> = result.take(0)?;
+        ratings.into_iter()
+            .next()
+            .ok_or_else(|| DomainError::ReviewNotFound.into())
+    }
+
+    async fn find_pending_reviews(&self, limit: Option<usize>, offset: Option<usize>) -> Result<Vec<BookRating>> {
+        let limit = limit.unwrap_or(20);
+        let offset = offset.unwrap_or(0);
+
+        let mut result = self
+            .db
+            .query("SELECT * FROM book_ratings WHERE status = 'pending' ORDER BY created_at ASC LIMIT $limit START $offset")
+            .bind(("limit", limit))
+            .bind(("offset", offset))
+            .await?;
+
+        let reviews: Vec<BookRating> = result.take(0)?;
+        Ok(reviews)
+    }
+
+    async fn find_reported_reviews(&self, limit: Option<usize>, offset: Option<usize>) -> Result<Vec<BookRating>> {
+        let limit = limit.unwrap_or(20);
+        let offset = offset.unwrap_or(0);
+
+        let mut result = self
+            .db
+            .query("SELECT * FROM book_ratings WHERE report_count > 0 ORDER BY report_count DESC, created_at ASC LIMIT $limit START $offset")
+            .bind(("limit", limit))
+            .bind(("offset", offset))
+            .await?;
+
+        let reviews: Vec<BookRating> = result.take(0)?;
+        Ok(reviews)
+    }
+}
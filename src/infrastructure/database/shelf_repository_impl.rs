@@ -0,0 +1,201 @@
+use async_trait::async_trait;
+use anyhow::Result;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use surrealdb::{Surreal, engine::remote::ws::Client};
+
+use crate::domain::{
+    entities::shelf::{Shelf, ShelfEntry, ShelfStatus, ShelfStatusChange},
+    repositories::shelf_repository::ShelfRepository,
+};
+
+pub struct ShelfRepositoryImpl {
+    db: Surreal<Client>,
+}
+
+impl ShelfRepositoryImpl {
+    pub fn new(db: Surreal<Client>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl ShelfRepository for ShelfRepositoryImpl {
+    async fn create_shelf(&self, shelf: Shelf) -> Result<Shelf> {
+        let created: Option<Shelf> = self
+            .db
+            .create(("shelves", shelf.id.to_string()))
+            .content(&shelf)
+            .await?;
+
+        Ok(created.unwrap())
+    }
+
+    async fn find_shelf(&self, id: Uuid) -> Result<Option<Shelf>> {
+        let shelf: Option<Shelf> = self
+            .db
+            .select(("shelves", id.to_string()))
+            .await?;
+
+        Ok(shelf)
+    }
+
+    async fn find_shelves_for_user(&self, user_id: Uuid) -> Result<Vec<Shelf>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM shelves WHERE user_id = $user_id ORDER BY name ASC")
+            .bind(("user_id", user_id))
+            .await?;
+
+        let shelves: Vec<Shelf> = result.take(0)?;
+        Ok(shelves)
+    }
+
+    async fn delete_shelf(&self, id: Uuid) -> Result<()> {
+        let mut result = self
+            .db
+            .query("
+                BEGIN TRANSACTION;
+                UPDATE shelf_entries SET shelf_ids -= $shelf_id WHERE shelf_ids CONTAINS $shelf_id;
+                DELETE type::thing('shelves', $shelf_key);
+                COMMIT TRANSACTION;
+            ")
+            .bind(("shelf_id", id))
+            .bind(("shelf_key", id.to_string()))
+            .await?;
+
+        let _: Option<surrealdb::sql::Value> = result.take(1)?;
+        Ok(())
+    }
+
+    async fn find_entry(&self, user_id: Uuid, book_id: Uuid) -> Result<Option<ShelfEntry>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM shelf_entries WHERE user_id = $user_id AND book_id = $book_id LIMIT 1")
+            .bind(("user_id", user_id))
+            .bind(("book_id", book_id))
+            .await?;
+
+        let entries: Vec<ShelfEntry> = result.take(0)?;
+        Ok(entries.into_iter().next())
+    }
+
+    async fn save_entry(&self, entry: ShelfEntry, change: Option<ShelfStatusChange>) -> Result<ShelfEntry> {
+        let mut result = self
+            .db
+            .query("
+                BEGIN TRANSACTION;
+                LET $saved = (UPSERT type::thing('shelf_entries', $entry_key) CONTENT $entry);
+                IF $change != NONE {
+                    CREATE type::thing('shelf_status_changes', <string> $change.id) CONTENT $change;
+                };
+                RETURN $saved;
+                COMMIT TRANSACTION;
+            ")
+            .bind(("entry_key", entry.id.to_string()))
+            .bind(("entry", entry))
+            .bind(("change", change))
+            .await?;
+
+        let saved: Vec<ShelfEntry> = result.take(2)?;
+        Ok(saved.into_iter().next().unwrap())
+    }
+
+    async fn delete_entry(&self, entry: ShelfEntry, change: ShelfStatusChange) -> Result<()> {
+        let mut result = self
+            .db
+            .query("
+                BEGIN TRANSACTION;
+                DELETE type::thing('shelf_entries', $entry_key);
+                CREATE type::thing('shelf_status_changes', $change_key) CONTENT $change;
+                COMMIT TRANSACTION;
+            ")
+            .bind(("entry_key", entry.id.to_string()))
+            .bind(("change_key", change.id.to_string()))
+            .bind(("change", change))
+            .await?;
+
+        let _: Option<surrealdb::sql::Value> = result.take(1)?;
+        Ok(())
+    }
+
+    async fn find_entries(
+        &self,
+        user_id: Uuid,
+        status: Option<ShelfStatus>,
+        shelf_id: Option<Uuid>,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<ShelfEntry>> {
+        let limit = limit.unwrap_or(20);
+        let offset = offset.unwrap_or(0);
+        let mut filters = String::new();
+        if status.is_some() {
+            filters.push_str(" AND status = $status");
+        }
+        if shelf_id.is_some() {
+            filters.push_str(" AND shelf_ids CONTAINS $shelf_id");
+        }
+
+        let mut result = self
+            .db
+            .query(format!(
+                "SELECT * FROM shelf_entries WHERE user_id = $user_id{} ORDER BY updated_at DESC LIMIT $limit START $offset",
+                filters
+            ))
+            .bind(("user_id", user_id))
+            .bind(("status", status))
+            .bind(("shelf_id", shelf_id))
+            .bind(("limit", limit))
+            .bind(("offset", offset))
+            .await?;
+
+        let entries: Vec<ShelfEntry> = result.take(0)?;
+        Ok(entries)
+    }
+
+    async fn find_all_entries(&self, user_id: Uuid) -> Result<Vec<ShelfEntry>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM shelf_entries WHERE user_id = $user_id ORDER BY added_at ASC")
+            .bind(("user_id", user_id))
+            .await?;
+
+        let entries: Vec<ShelfEntry> = result.take(0)?;
+        Ok(entries)
+    }
+
+    async fn find_finished_between(
+        &self,
+        user_ids: &[Uuid],
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<ShelfEntry>> {
+        let mut result = self
+            .db
+            .query("
+                SELECT * FROM shelf_entries
+                WHERE user_id INSIDE $user_ids AND status = 'read' AND finished_at >= $from AND finished_at < $to
+                ORDER BY finished_at ASC
+            ")
+            .bind(("user_ids", user_ids.to_vec()))
+            .bind(("from", from))
+            .bind(("to", to))
+            .await?;
+
+        let entries: Vec<ShelfEntry> = result.take(0)?;
+        Ok(entries)
+    }
+
+    async fn find_status_changes(&self, user_id: Uuid, book_id: Uuid) -> Result<Vec<ShelfStatusChange>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM shelf_status_changes WHERE user_id = $user_id AND book_id = $book_id ORDER BY changed_at ASC")
+            .bind(("user_id", user_id))
+            .bind(("book_id", book_id))
+            .await?;
+
+        let changes: Vec<ShelfStatusChange> = result.take(0)?;
+        Ok(changes)
+    }
+}
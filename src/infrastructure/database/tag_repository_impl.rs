@@ -0,0 +1,199 @@
+use async_trait::async_trait;
+use anyhow::Result;
+use uuid::Uuid;
+use surrealdb::{Surreal, engine::remote::ws::Client};
+
+use crate::domain::{
+    entities::tag::{BookTag, TagVote, BlockedTag, TagStatus},
+    repositories::tag_repository::TagRepository,
+};
+
+pub struct TagRepositoryImpl {
+    db: Surreal<Client>,
+}
+
+impl TagRepositoryImpl {
+    pub fn new(db: Surreal<Client>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl TagRepository for TagRepositoryImpl {
+    async fn save_tag(&self, tag: BookTag) -> Result<BookTag> {
+        let created: Option<BookTag> = self
+            .db
+            .create(("book_tags", tag.id.to_string()))
+            .content(&tag)
+            .await?;
+
+        Ok(created.unwrap())
+    }
+
+    async fn find_tag(&self, id: Uuid) -> Result<Option<BookTag>> {
+        let tag: Option<BookTag> = self
+            .db
+            .select(("book_tags", id.to_string()))
+            .await?;
+
+        Ok(tag)
+    }
+
+    async fn find_tag_for_book(&self, book_id: Uuid, tag: &str) -> Result<Option<BookTag>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM book_tags WHERE book_id = $book_id AND tag = $tag LIMIT 1")
+            .bind(("book_id", book_id))
+            .bind(("tag", tag))
+            .await?;
+
+        let tags: Vec<BookTag> = result.take(0)?;
+        Ok(tags.into_iter().next())
+    }
+
+    async fn find_tags_for_book(&self, book_id: Uuid, status: Option<TagStatus>) -> Result<Vec<BookTag>> {
+        let mut result = match status {
+            Some(status) => self
+                .db
+                .query("SELECT * FROM book_tags WHERE book_id = $book_id AND status = $status")
+                .bind(("book_id", book_id))
+                .bind(("status", status))
+                .await?,
+            None => self
+                .db
+                .query("SELECT * FROM book_tags WHERE book_id = $book_id")
+                .bind(("book_id", book_id))
+                .await?,
+        };
+
+        let tags: Vec<BookTag> = result.take(0)?;
+        Ok(tags)
+    }
+
+    async fn find_tags_by_name(&self, tag: &str) -> Result<Vec<BookTag>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM book_tags WHERE tag = $tag")
+            .bind(("tag", tag))
+            .await?;
+
+        let tags: Vec<BookTag> = result.take(0)?;
+        Ok(tags)
+    }
+
+    async fn find_pending_tags(&self, limit: Option<usize>, offset: Option<usize>) -> Result<Vec<BookTag>> {
+        let limit = limit.unwrap_or(20);
+        let offset = offset.unwrap_or(0);
+
+        let mut result = self
+            .db
+            .query("SELECT * FROM book_tags WHERE status = 'pending' ORDER BY created_at ASC LIMIT $limit START $offset")
+            .bind(("limit", limit))
+            .bind(("offset", offset))
+            .await?;
+
+        let tags: Vec<BookTag> = result.take(0)?;
+        Ok(tags)
+    }
+
+    async fn update_tag(&self, tag: BookTag) -> Result<BookTag> {
+        let updated: Option<BookTag> = self
+            .db
+            .update(("book_tags", tag.id.to_string()))
+            .content(&tag)
+            .await?;
+
+        Ok(updated.unwrap())
+    }
+
+    async fn find_vote(&self, book_tag_id: Uuid, user_id: Uuid) -> Result<Option<TagVote>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM tag_votes WHERE book_tag_id = $book_tag_id AND user_id = $user_id LIMIT 1")
+            .bind(("book_tag_id", book_tag_id))
+            .bind(("user_id", user_id))
+            .await?;
+
+        let votes: Vec<TagVote> = result.take(0)?;
+        Ok(votes.into_iter().next())
+    }
+
+    async fn save_vote(&self, vote: TagVote) -> Result<TagVote> {
+        // Upsert by id so a changed vote replaces the previous one
+        let saved: Option<TagVote> = self
+            .db
+            .upsert(("tag_votes", vote.id.to_string()))
+            .content(&vote)
+            .await?;
+
+        Ok(saved.unwrap())
+    }
+
+    async fn delete_vote(&self, id: Uuid) -> Result<()> {
+        let _: Option<TagVote> = self
+            .db
+            .delete(("tag_votes", id.to_string()))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn update_tag_vote_counts(&self, book_tag_id: Uuid) -> Result<BookTag> {
+        let mut result = self
+            .db
+            .query("
+                UPDATE type::thing('book_tags', $tag_key) SET
+                    upvotes = count((SELECT id FROM tag_votes WHERE book_tag_id = $book_tag_id AND value > 0)),
+                    downvotes = count((SELECT id FROM tag_votes WHERE book_tag_id = $book_tag_id AND value < 0)),
+                    updated_at = time::now()
+            ")
+            .bind(("tag_key", book_tag_id.to_string()))
+            .bind(("book_tag_id", book_tag_id))
+            .await?;
+
+        let tags: Vec<BookTag> = result.take(0)?;
+        tags.into_iter()
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("Book tag {} not found", book_tag_id))
+    }
+
+    async fn find_blocked_tag(&self, tag: &str) -> Result<Option<BlockedTag>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM blocked_tags WHERE tag = $tag LIMIT 1")
+            .bind(("tag", tag))
+            .await?;
+
+        let blocked: Vec<BlockedTag> = result.take(0)?;
+        Ok(blocked.into_iter().next())
+    }
+
+    async fn find_blocked_tags(&self) -> Result<Vec<BlockedTag>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM blocked_tags ORDER BY tag ASC")
+            .await?;
+
+        let blocked: Vec<BlockedTag> = result.take(0)?;
+        Ok(blocked)
+    }
+
+    async fn save_blocked_tag(&self, blocked: BlockedTag) -> Result<BlockedTag> {
+        let created: Option<BlockedTag> = self
+            .db
+            .create(("blocked_tags", blocked.id.to_string()))
+            .content(&blocked)
+            .await?;
+
+        Ok(created.unwrap())
+    }
+
+    async fn delete_blocked_tag(&self, tag: &str) -> Result<()> {
+        self.db
+            .query("DELETE blocked_tags WHERE tag = $tag")
+            .bind(("tag", tag))
+            .await?;
+
+        Ok(())
+    }
+}
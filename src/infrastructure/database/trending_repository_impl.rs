@@ -0,0 +1,52 @@
+use async_trait::async_trait;
+use anyhow::Result;
+use surrealdb::{Surreal, engine::remote::ws::Client};
+
+use crate::domain::{
+    entities::trending::{TrendingEntry, TrendingList},
+    repositories::trending_repository::TrendingRepository,
+};
+
+pub struct TrendingRepositoryImpl {
+    db: Surreal<Client>,
+}
+
+impl TrendingRepositoryImpl {
+    pub fn new(db: Surreal<Client>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl TrendingRepository for TrendingRepositoryImpl {
+    async fn replace_list(&self, list: TrendingList, entries: Vec<TrendingEntry>) -> Result<()> {
+        let mut result = self
+            .db
+            .query("
+                BEGIN TRANSACTION;
+                DELETE trending_books WHERE list = $list;
+                FOR $entry IN $entries {
+                    CREATE type::thing('trending_books', <string> $entry.id) CONTENT $entry;
+                };
+                COMMIT TRANSACTION;
+            ")
+            .bind(("list", list.key()))
+            .bind(("entries", entries))
+            .await?;
+
+        let _: Option<surrealdb::sql::Value> = result.take(0)?;
+        Ok(())
+    }
+
+    async fn find_list(&self, list: TrendingList, limit: usize) -> Result<Vec<TrendingEntry>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM trending_books WHERE list = $list ORDER BY rank ASC LIMIT $limit")
+            .bind(("list", list.key()))
+            .bind(("limit", limit))
+            .await?;
+
+        let entries: Vec<TrendingEntry> = result.take(0)?;
+        Ok(entries)
+    }
+}
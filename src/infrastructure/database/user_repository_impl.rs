@@ -1,9 +1,14 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use surrealdb::{Surreal, engine::remote::ws::Client};
 use uuid::Uuid;
 
 use crate::domain::{entities::user::User, repositories::user_repository::UserRepository};
+use crate::infrastructure::database::{
+    review_repository_impl::RECOUNT_REVIEW_VOTES,
+    work_repository_impl::RECOUNT_WORK_RATING_STATS,
+};
 
 pub struct UserRepositoryImpl {
     db: Surreal<Client>,
@@ -30,13 +35,13 @@ impl UserRepository for UserRepositoryImpl {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<User>> {
         let user: Option<User> = self.db.select(("users", id.to_string())).await?;
 
-        Ok(user)
+        Ok(user.filter(|user| user.deleted_at.is_none()))
     }
 
     async fn find_by_email(&self, email: &str) -> Result<Option<User>> {
         let mut result = self
             .db
-            .query("SELECT * FROM users WHERE email = $email")
+            .query("SELECT * FROM users WHERE email = $email AND deleted_at IS NONE")
             .bind(("email", email))
             .await?;
 
@@ -47,7 +52,7 @@ impl UserRepository for UserRepositoryImpl {
     async fn find_by_username(&self, username: &str) -> Result<Option<User>> {
         let mut result = self
             .db
-            .query("SELECT * FROM users WHERE username = $username")
+            .query("SELECT * FROM users WHERE username = $username AND deleted_at IS NONE")
             .bind(("username", username))
             .await?;
 
@@ -66,13 +71,101 @@ impl UserRepository for UserRepositoryImpl {
     }
 
     async fn delete(&self, id: Uuid) -> Result<()> {
-        let _: Option<User> = self.db.delete(("users", id.to_string())).await?;
+        self.db
+            .query("UPDATE type::thing('users', $user_key) SET deleted_at = time::now(), is_active = false WHERE deleted_at IS NONE")
+            .bind(("user_key", id.to_string()))
+            .await?;
 
         Ok(())
     }
 
+    async fn find_deleted(&self, limit: Option<usize>, offset: Option<usize>) -> Result<Vec<User>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM users WHERE deleted_at IS NOT NONE ORDER BY deleted_at DESC LIMIT $limit START $offset")
+            .bind(("limit", limit.unwrap_or(20)))
+            .bind(("offset", offset.unwrap_or(0)))
+            .await?;
+
+        let users: Vec<User> = result.take(0)?;
+        Ok(users)
+    }
+
+    async fn restore(&self, id: Uuid) -> Result<Option<User>> {
+        let mut result = self
+            .db
+            .query("UPDATE type::thing('users', $user_key) SET deleted_at = NONE, is_active = true WHERE deleted_at IS NOT NONE")
+            .bind(("user_key", id.to_string()))
+            .await?;
+
+        let users: Vec<User> = result.take(0)?;
+        Ok(users.into_iter().next())
+    }
+
+    async fn purge_deleted(&self, deleted_before: DateTime<Utc>) -> Result<usize> {
+        // Ratings, review votes and reports, and tag votes feed aggregates on
+        // other records, which are recomputed once the purged user's rows are gone
+        let mut result = self
+            .db
+            .query(format!("
+                BEGIN TRANSACTION;
+                LET $users = (SELECT VALUE id FROM users WHERE deleted_at IS NOT NONE AND deleted_at < $deleted_before);
+                LET $user_ids = $users.map(|$user| meta::id($user));
+                LET $rated_books = array::distinct((SELECT VALUE book_id FROM book_ratings WHERE user_id IN $user_ids));
+                LET $voted_tags = array::distinct((SELECT VALUE book_tag_id FROM tag_votes WHERE user_id IN $user_ids));
+                LET $rated_works = array::distinct((SELECT VALUE work_id FROM books WHERE meta::id(id) IN $rated_books AND work_id IS NOT NONE));
+                LET $rating_ids = (SELECT VALUE meta::id(id) FROM book_ratings WHERE user_id IN $user_ids);
+                LET $voted_reviews = array::distinct((SELECT VALUE rating_id FROM review_votes WHERE user_id IN $user_ids AND rating_id NOT IN $rating_ids));
+                LET $reported_reviews = array::distinct((SELECT VALUE rating_id FROM review_reports WHERE reported_by IN $user_ids AND rating_id NOT IN $rating_ids));
+                DELETE review_votes WHERE user_id IN $user_ids OR rating_id IN $rating_ids;
+                DELETE review_reports WHERE reported_by IN $user_ids OR rating_id IN $rating_ids;
+                DELETE book_ratings WHERE user_id IN $user_ids;
+                DELETE recommendations WHERE user_id IN $user_ids;
+                DELETE user_preferences WHERE user_id IN $user_ids;
+                DELETE reading_sessions WHERE user_id IN $user_ids;
+                DELETE tag_votes WHERE user_id IN $user_ids;
+                DELETE shelf_entries WHERE user_id IN $user_ids;
+                DELETE shelf_status_changes WHERE user_id IN $user_ids;
+                DELETE shelves WHERE user_id IN $user_ids;
+                DELETE reading_goals WHERE user_id IN $user_ids;
+                DELETE challenge_participants WHERE user_id IN $user_ids;
+                DELETE notifications WHERE user_id IN $user_ids;
+                FOR $book_id IN $rated_books {{
+                    LET $ratings = (SELECT VALUE rating FROM book_ratings WHERE book_id = $book_id);
+                    UPDATE type::thing('books', <string> $book_id) SET
+                        ratings_count = array::len($ratings),
+                        rating_sum = math::sum($ratings),
+                        rating_histogram = [1, 2, 3, 4, 5].map(|$star| $ratings.filter(|$value| math::round($value) = $star).len()),
+                        average_rating = IF array::len($ratings) > 0 THEN math::mean($ratings) ELSE 0.0 END;
+                }};
+                FOR $work_id IN $rated_works {{
+                    {}
+                }};
+                FOR $rating_id IN $voted_reviews {{
+                    {}
+                }};
+                FOR $rating_id IN $reported_reviews {{
+                    UPDATE type::thing('book_ratings', <string> $rating_id) SET
+                        report_count = count((SELECT id FROM review_reports WHERE rating_id = $rating_id AND status = 'open'));
+                }};
+                FOR $book_tag_id IN $voted_tags {{
+                    UPDATE type::thing('book_tags', $book_tag_id) SET
+                        upvotes = count((SELECT id FROM tag_votes WHERE book_tag_id = $book_tag_id AND value > 0)),
+                        downvotes = count((SELECT id FROM tag_votes WHERE book_tag_id = $book_tag_id AND value < 0));
+                }};
+                DELETE $users;
+                RETURN array::len($users);
+                COMMIT TRANSACTION;
+            ", RECOUNT_WORK_RATING_STATS, RECOUNT_REVIEW_VOTES))
+            .bind(("deleted_before", deleted_before))
+            .await?;
+
+        let purged: Option<usize> = result.take(27)?;
+        Ok(purged.unwrap_or(0))
+    }
+
     async fn find_all(&self, limit: Option<usize>, offset: Option<usize>) -> Result<Vec<User>> {
-        let mut query = "SELECT * FROM users".to_string();
+        let mut query = "SELECT * FROM users WHERE deleted_at IS NONE".to_string();
 
         if let Some(limit_val) = limit {
             query.push_str(&format!(" LIMIT {}", limit_val));
@@ -0,0 +1,122 @@
+use async_trait::async_trait;
+use anyhow::Result;
+use uuid::Uuid;
+use surrealdb::{Surreal, engine::remote::ws::Client};
+
+use crate::domain::{
+    entities::{
+        book::Book,
+        work::{Work, Series},
+    },
+    repositories::work_repository::WorkRepository,
+};
+
+/// Recomputes the rating stats of the work `$work_id` from the ratings on its
+/// live editions
+pub const RECOUNT_WORK_RATING_STATS: &str = "
+    LET $editions = (SELECT VALUE meta::id(id) FROM books WHERE work_id = $work_id AND deleted_at IS NONE);
+    LET $ratings = (SELECT VALUE rating FROM book_ratings WHERE book_id IN $editions);
+    UPDATE type::thing('works', <string> $work_id) SET
+        ratings_count = array::len($ratings),
+        rating_sum = math::sum($ratings),
+        average_rating = IF array::len($ratings) > 0 THEN math::mean($ratings) ELSE 0.0 END;
+";
+
+pub struct WorkRepositoryImpl {
+    db: Surreal<Client>,
+}
+
+impl WorkRepositoryImpl {
+    pub fn new(db: Surreal<Client>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl WorkRepository for WorkRepositoryImpl {
+    async fn save(&self, work: Work) -> Result<Work> {
+        let created: Option<Work> = self
+            .db
+            .create(("works", work.id.to_string()))
+            .content(&work)
+            .await?;
+        
+        Ok(created.unwrap())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Work>> {
+        let work: Option<Work> = self
+            .db
+            .select(("works", id.to_string()))
+            .await?;
+        
+        Ok(work)
+    }
+
+    async fn update(&self, work: Work) -> Result<Work> {
+        let updated: Option<Work> = self
+            .db
+            .update(("works", work.id.to_string()))
+            .content(&work)
+            .await?;
+        
+        Ok(updated.unwrap())
+    }
+
+    async fn find_editions(&self, work_id: Uuid) -> Result<Vec<Book>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM books WHERE work_id = $work_id AND deleted_at IS NONE ORDER BY publication_year ASC")
+            .bind(("work_id", work_id))
+            .await?;
+        
+        let books: Vec<Book> = result.take(0)?;
+        Ok(books)
+    }
+
+    async fn update_work_rating_stats(&self, work_id: Uuid) -> Result<()> {
+        // Aggregate ratings across every live edition of the work
+        let mut result = self
+            .db
+            .query(format!("
+                BEGIN TRANSACTION;
+                {}
+                COMMIT TRANSACTION;
+            ", RECOUNT_WORK_RATING_STATS))
+            .bind(("work_id", work_id))
+            .await?;
+        
+        let _: Option<surrealdb::sql::Value> = result.take(2)?;
+        Ok(())
+    }
+
+    async fn save_series(&self, series: Series) -> Result<Series> {
+        let created: Option<Series> = self
+            .db
+            .create(("series", series.id.to_string()))
+            .content(&series)
+            .await?;
+        
+        Ok(created.unwrap())
+    }
+
+    async fn find_series_by_id(&self, id: Uuid) -> Result<Option<Series>> {
+        let series: Option<Series> = self
+            .db
+            .select(("series", id.to_string()))
+            .await?;
+        
+        Ok(series)
+    }
+
+    async fn find_works_in_series(&self, series_id: Uuid) -> Result<Vec<Work>> {
+        let mut result = self
+            .db
+            .query("SELECT * FROM works WHERE series_id = $series_id ORDER BY series_position ASC")
+            .bind(("series_id", series_id))
+            .await?;
+        
+        let works: Vec<Work> = result.take(0)?;
+        Ok(works)
+    }
+}
@@ -1,8 +1,12 @@
 use anyhow::Result;
+use async_trait::async_trait;
 use reqwest::multipart;
 use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
 use base64;
 
+use crate::application::ports::image_storage_port::{ImageStorage, StoredImage};
+
 #[derive(Debug, Serialize)]
 struct CloudinaryUploadParams {
     upload_preset: String,
@@ -68,4 +72,49 @@ impl CloudinaryService {
         let cloudinary_response: CloudinaryResponse = response.json().await?;
         Ok(cloudinary_response)
     }
-}
\ No newline at end of file
+
+    /// Delete an asset with a signed `destroy` call
+    pub async fn destroy_image(&self, public_id: &str) -> Result<()> {
+        let timestamp = chrono::Utc::now().timestamp().to_string();
+        let signature = hex::encode(Sha1::digest(
+            format!("public_id={}&timestamp={}{}", public_id, timestamp, self.api_secret).as_bytes(),
+        ));
+
+        let client = reqwest::Client::new();
+        let url = format!(
+            "https://api.cloudinary.com/v1_1/{}/image/destroy",
+            self.cloud_name
+        );
+
+        client
+            .post(&url)
+            .form(&[
+                ("public_id", public_id),
+                ("timestamp", timestamp.as_str()),
+                ("api_key", self.api_key.as_str()),
+                ("signature", signature.as_str()),
+            ])
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ImageStorage for CloudinaryService {
+    async fn store(&self, key: &str, data: Vec<u8>, _content_type: &str) -> Result<StoredImage> {
+        let (folder, filename) = key.rsplit_once('/').unwrap_or(("", key));
+        let response = self.upload_image(data, filename, folder).await?;
+
+        Ok(StoredImage {
+            key: response.public_id,
+            url: response.secure_url,
+        })
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        self.destroy_image(key).await
+    }
+}
@@ -0,0 +1,56 @@
+use anyhow::{bail, Result};
+use std::time::Duration;
+
+use crate::infrastructure::external::image_processor::ImageUploadLimits;
+
+/// How long to wait for a cover host to accept the connection
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+/// How long a cover host may go quiet mid-response
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+/// Upper bound on a whole download, so a slow drip can't hold a backfill
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Downloads stored or publisher-supplied cover images by URL
+pub struct ImageFetcher {
+    client: reqwest::Client,
+    max_bytes: usize,
+}
+
+impl ImageFetcher {
+    pub fn new(max_bytes: usize) -> Self {
+        let client = reqwest::Client::builder()
+            .connect_timeout(CONNECT_TIMEOUT)
+            .read_timeout(READ_TIMEOUT)
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .expect("Failed to build the image fetcher HTTP client");
+
+        Self { client, max_bytes }
+    }
+
+    /// Download an image, giving up as soon as it exceeds `max_bytes` whether
+    /// or not the server announced its length
+    pub async fn fetch(&self, url: &str) -> Result<Vec<u8>> {
+        let mut response = self.client.get(url).send().await?.error_for_status()?;
+
+        if response.content_length().is_some_and(|length| length as usize > self.max_bytes) {
+            bail!("{} is larger than {} bytes", url, self.max_bytes);
+        }
+
+        let mut data = Vec::new();
+        while let Some(chunk) = response.chunk().await? {
+            if data.len() + chunk.len() > self.max_bytes {
+                bail!("{} is larger than {} bytes", url, self.max_bytes);
+            }
+            data.extend_from_slice(&chunk);
+        }
+
+        Ok(data)
+    }
+}
+
+impl Default for ImageFetcher {
+    fn default() -> Self {
+        Self::new(ImageUploadLimits::default().max_bytes)
+    }
+}
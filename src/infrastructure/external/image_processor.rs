@@ -0,0 +1,411 @@
+use anyhow::Result;
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::webp::WebPEncoder;
+use image::imageops::FilterType;
+use image::{DynamicImage, ImageDecoder, ImageFormat, ImageReader, Limits};
+use std::io::Cursor;
+
+use crate::domain::entities::book::{CoverFormat, CoverPlaceholder, CoverSize};
+use crate::domain::errors::domain_error::DomainError;
+
+pub const DEFAULT_JPEG_QUALITY: u8 = 82;
+
+/// BlurHash components across and down; covers are portrait
+const BLURHASH_COMPONENTS: (u32, u32) = (3, 4);
+/// Width placeholders are computed at, they only carry a few colors
+const PLACEHOLDER_SAMPLE_WIDTH: u32 = 32;
+
+/// What an uploaded cover must satisfy before it is processed
+#[derive(Debug, Clone)]
+pub struct ImageUploadLimits {
+    pub max_bytes: usize,
+    pub min_width: u32,
+    pub min_height: u32,
+    pub max_width: u32,
+    pub max_height: u32,
+    /// Cap on width × height, well below `max_width` × `max_height`, so a
+    /// small upload can't decode into hundreds of megabytes
+    pub max_pixels: u64,
+    pub allowed_formats: Vec<ImageFormat>,
+}
+
+/// Decoded bytes per pixel at most: 16-bit RGBA
+const MAX_BYTES_PER_PIXEL: u64 = 8;
+
+impl Default for ImageUploadLimits {
+    fn default() -> Self {
+        Self {
+            max_bytes: 10 * 1024 * 1024,
+            min_width: 100,
+            min_height: 150,
+            max_width: 8000,
+            max_height: 8000,
+            max_pixels: 16_000_000,
+            allowed_formats: vec![ImageFormat::Jpeg, ImageFormat::Png, ImageFormat::WebP],
+        }
+    }
+}
+
+/// One encoded rendition of an uploaded cover, ready to be stored
+#[derive(Debug, Clone)]
+pub struct ProcessedImage {
+    pub size: CoverSize,
+    pub format: CoverFormat,
+    pub width: u32,
+    pub height: u32,
+    pub data: Vec<u8>,
+}
+
+/// Every rendition of an uploaded cover plus its placeholder and perceptual hash
+#[derive(Debug, Clone)]
+pub struct ProcessedCover {
+    pub variants: Vec<ProcessedImage>,
+    pub placeholder: CoverPlaceholder,
+    pub hash: String,
+}
+
+/// Turns an uploaded cover into WebP and JPEG renditions at every `CoverSize`.
+/// Images are decoded and re-encoded from pixels, so EXIF, XMP and ICC
+/// metadata never reach storage; the EXIF orientation is applied first.
+/// The WebP encoder is lossless, which beats JPEG on flat artwork but not on
+/// photographic covers, so a WebP rendition is only kept when it is smaller.
+#[derive(Debug, Clone)]
+pub struct ImageProcessor {
+    jpeg_quality: u8,
+    limits: ImageUploadLimits,
+}
+
+impl ImageProcessor {
+    pub fn new(jpeg_quality: u8, limits: ImageUploadLimits) -> Self {
+        Self {
+            jpeg_quality: jpeg_quality.clamp(1, 100),
+            limits,
+        }
+    }
+
+    pub fn limits(&self) -> &ImageUploadLimits {
+        &self.limits
+    }
+
+    /// CPU bound, call from a blocking task. Rejected uploads fail with
+    /// `DomainError::PayloadTooLarge`, `UnsupportedMediaType` or `InvalidInput`.
+    pub fn process(&self, data: &[u8]) -> Result<ProcessedCover> {
+        let image = self.decode(data, true)?;
+        let mut variants = Vec::new();
+
+        for size in CoverSize::ALL {
+            let resized = if image.width() > size.max_width() {
+                image.resize(size.max_width(), u32::MAX, FilterType::Lanczos3)
+            } else {
+                image.clone()
+            };
+
+            let jpeg = self.encode_jpeg(&resized)?;
+            let webp = Self::encode_webp(&resized)?;
+            if webp.len() < jpeg.len() {
+                variants.push(ProcessedImage {
+                    size,
+                    format: CoverFormat::Webp,
+                    width: resized.width(),
+                    height: resized.height(),
+                    data: webp,
+                });
+            }
+            variants.push(ProcessedImage {
+                size,
+                format: CoverFormat::Jpeg,
+                width: resized.width(),
+                height: resized.height(),
+                data: jpeg,
+            });
+        }
+
+        Ok(ProcessedCover {
+            variants,
+            placeholder: Self::placeholder_for(&image)?,
+            hash: Self::cover_hash_for(&image),
+        })
+    }
+
+    /// Placeholder and perceptual hash for an already stored cover. Decoded
+    /// under the same limits as uploads, except the minimum dimensions, as
+    /// stored thumbnails are smaller than an upload may be.
+    pub fn analyze(&self, data: &[u8]) -> Result<(CoverPlaceholder, String)> {
+        let image = self.decode(data, false)?;
+        Ok((Self::placeholder_for(&image)?, Self::cover_hash_for(&image)))
+    }
+
+    /// 64-bit difference hash: shrink to 9x8 grayscale and record whether each
+    /// pixel is brighter than its left neighbour. Survives re-encoding,
+    /// resizing and small color shifts, so near-identical covers differ in
+    /// only a few bits.
+    fn cover_hash_for(image: &DynamicImage) -> String {
+        let gray = image.resize_exact(9, 8, FilterType::Triangle).to_luma8();
+
+        let mut hash = 0u64;
+        for y in 0..8 {
+            for x in 0..8 {
+                hash <<= 1;
+                if gray.get_pixel(x + 1, y)[0] > gray.get_pixel(x, y)[0] {
+                    hash |= 1;
+                }
+            }
+        }
+
+        format!("{:016x}", hash)
+    }
+
+    fn placeholder_for(image: &DynamicImage) -> Result<CoverPlaceholder> {
+        let sample = image
+            .resize(PLACEHOLDER_SAMPLE_WIDTH, u32::MAX, FilterType::Triangle)
+            .to_rgba8();
+
+        let blurhash = blurhash::encode(
+            BLURHASH_COMPONENTS.0,
+            BLURHASH_COMPONENTS.1,
+            sample.width(),
+            sample.height(),
+            sample.as_raw(),
+        )
+        .map_err(|e| anyhow::anyhow!("BlurHash encoding failed: {:?}", e))?;
+
+        let pixel_count = (sample.width() * sample.height()).max(1) as u64;
+        let [r, g, b] = sample.pixels().fold([0u64; 3], |mut sum, pixel| {
+            for channel in 0..3 {
+                sum[channel] += pixel[channel] as u64;
+            }
+            sum
+        });
+
+        Ok(CoverPlaceholder {
+            blurhash,
+            average_color: format!(
+                "#{:02x}{:02x}{:02x}",
+                r / pixel_count,
+                g / pixel_count,
+                b / pixel_count
+            ),
+        })
+    }
+
+    /// Validate and decode an upload. The format comes from the magic bytes,
+    /// never from the filename, and dimensions are checked from the header
+    /// before any pixels are allocated.
+    fn decode(&self, data: &[u8], enforce_minimum: bool) -> Result<DynamicImage> {
+        if data.is_empty() {
+            return Err(DomainError::InvalidInput("Empty image data".to_string()).into());
+        }
+        if data.len() > self.limits.max_bytes {
+            return Err(DomainError::PayloadTooLarge(format!(
+                "Cover images are limited to {} bytes",
+                self.limits.max_bytes
+            )).into());
+        }
+
+        let format = image::guess_format(data)
+            .map_err(|_| DomainError::UnsupportedMediaType("Unrecognized image format".to_string()))?;
+        if !self.limits.allowed_formats.contains(&format) {
+            return Err(DomainError::UnsupportedMediaType(format!(
+                "{} images are not accepted, use {}",
+                format_name(format),
+                self.limits.allowed_formats.iter().map(|f| format_name(*f)).collect::<Vec<_>>().join(", ")
+            )).into());
+        }
+
+        let unreadable = |e: image::ImageError| DomainError::InvalidInput(format!("Unreadable image: {}", e));
+
+        let mut decoder_limits = Limits::default();
+        decoder_limits.max_image_width = Some(self.limits.max_width);
+        decoder_limits.max_image_height = Some(self.limits.max_height);
+        decoder_limits.max_alloc = Some(self.limits.max_pixels * MAX_BYTES_PER_PIXEL);
+
+        let mut reader = ImageReader::with_format(Cursor::new(data), format);
+        reader.limits(decoder_limits);
+        let mut decoder = reader.into_decoder().map_err(unreadable)?;
+
+        let (width, height) = decoder.dimensions();
+        if enforce_minimum && (width < self.limits.min_width || height < self.limits.min_height) {
+            return Err(DomainError::InvalidInput(format!(
+                "Image is {}x{}, covers must be at least {}x{}",
+                width, height, self.limits.min_width, self.limits.min_height
+            )).into());
+        }
+        if width > self.limits.max_width || height > self.limits.max_height {
+            return Err(DomainError::InvalidInput(format!(
+                "Image is {}x{}, covers must be at most {}x{}",
+                width, height, self.limits.max_width, self.limits.max_height
+            )).into());
+        }
+        if u64::from(width) * u64::from(height) > self.limits.max_pixels {
+            return Err(DomainError::InvalidInput(format!(
+                "Image is {}x{}, covers must be at most {} megapixels",
+                width, height, self.limits.max_pixels / 1_000_000
+            )).into());
+        }
+
+        let orientation = decoder.orientation().map_err(unreadable)?;
+        let mut image = DynamicImage::from_decoder(decoder).map_err(unreadable)?;
+        image.apply_orientation(orientation);
+        Ok(image)
+    }
+
+    fn encode_webp(image: &DynamicImage) -> Result<Vec<u8>> {
+        let mut buffer = Vec::new();
+        image.to_rgba8().write_with_encoder(WebPEncoder::new_lossless(&mut buffer))?;
+        Ok(buffer)
+    }
+
+    fn encode_jpeg(&self, image: &DynamicImage) -> Result<Vec<u8>> {
+        let mut buffer = Vec::new();
+        image.to_rgb8().write_with_encoder(JpegEncoder::new_with_quality(&mut buffer, self.jpeg_quality))?;
+        Ok(buffer)
+    }
+}
+
+fn format_name(format: ImageFormat) -> &'static str {
+    format.extensions_str().first().copied().unwrap_or("unknown")
+}
+
+impl Default for ImageProcessor {
+    fn default() -> Self {
+        Self::new(DEFAULT_JPEG_QUALITY, ImageUploadLimits::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::RgbImage;
+
+    fn encode(width: u32, height: u32, format: ImageFormat) -> Vec<u8> {
+        let mut buffer = Cursor::new(Vec::new());
+        DynamicImage::ImageRgb8(RgbImage::new(width, height))
+            .write_to(&mut buffer, format)
+            .unwrap();
+        buffer.into_inner()
+    }
+
+    fn rejection(processor: &ImageProcessor, data: &[u8]) -> DomainError {
+        processor.process(data).unwrap_err().downcast::<DomainError>().unwrap()
+    }
+
+    #[test]
+    fn test_produces_every_size_and_format_without_upscaling() {
+        let variants = ImageProcessor::default().process(&encode(800, 1200, ImageFormat::Png)).unwrap().variants;
+        assert_eq!(variants.len(), 6);
+
+        let widths: Vec<(CoverSize, CoverFormat, u32, u32)> = variants
+            .iter()
+            .map(|v| (v.size, v.format, v.width, v.height))
+            .collect();
+        assert!(widths.contains(&(CoverSize::Thumbnail, CoverFormat::Webp, 160, 240)));
+        assert!(widths.contains(&(CoverSize::Card, CoverFormat::Jpeg, 400, 600)));
+        assert!(widths.contains(&(CoverSize::Full, CoverFormat::Jpeg, 800, 1200)));
+
+        let jpeg = variants.iter().find(|v| v.format == CoverFormat::Jpeg).unwrap();
+        assert_eq!(image::guess_format(&jpeg.data).unwrap(), ImageFormat::Jpeg);
+        let webp = variants.iter().find(|v| v.format == CoverFormat::Webp).unwrap();
+        assert_eq!(image::guess_format(&webp.data).unwrap(), ImageFormat::WebP);
+    }
+
+    #[test]
+    fn test_keeps_webp_only_when_smaller_than_jpeg() {
+        // Noise stands in for a photographic cover, where lossless WebP loses
+        let mut state = 0x2545_f491_u32;
+        let noise = RgbImage::from_fn(400, 600, |_, _| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            let [r, g, b, _] = state.to_le_bytes();
+            image::Rgb([r, g, b])
+        });
+        let mut data = Cursor::new(Vec::new());
+        DynamicImage::ImageRgb8(noise).write_to(&mut data, ImageFormat::Png).unwrap();
+
+        let variants = ImageProcessor::default().process(data.get_ref()).unwrap().variants;
+        for size in CoverSize::ALL {
+            let rendition = |format| variants.iter().find(|v| v.size == size && v.format == format);
+            let jpeg = rendition(CoverFormat::Jpeg).unwrap();
+            assert!(rendition(CoverFormat::Webp).is_none_or(|webp| webp.data.len() < jpeg.data.len()));
+        }
+        assert!(!variants.iter().any(|v| v.format == CoverFormat::Webp));
+    }
+
+    #[test]
+    fn test_rejects_unknown_and_disallowed_formats() {
+        let processor = ImageProcessor::default();
+        assert!(matches!(rejection(&processor, b"definitely not an image"), DomainError::UnsupportedMediaType(_)));
+        assert!(matches!(
+            rejection(&processor, &encode(200, 300, ImageFormat::Bmp)),
+            DomainError::UnsupportedMediaType(_)
+        ));
+    }
+
+    #[test]
+    fn test_rejects_oversized_payloads_and_out_of_range_dimensions() {
+        let processor = ImageProcessor::new(DEFAULT_JPEG_QUALITY, ImageUploadLimits {
+            max_bytes: 64,
+            ..ImageUploadLimits::default()
+        });
+        assert!(matches!(
+            rejection(&processor, &encode(200, 300, ImageFormat::Png)),
+            DomainError::PayloadTooLarge(_)
+        ));
+
+        let processor = ImageProcessor::default();
+        assert!(matches!(rejection(&processor, &encode(50, 50, ImageFormat::Png)), DomainError::InvalidInput(_)));
+        assert!(matches!(rejection(&processor, &encode(9000, 150, ImageFormat::Png)), DomainError::InvalidInput(_)));
+
+        let processor = ImageProcessor::new(DEFAULT_JPEG_QUALITY, ImageUploadLimits {
+            max_pixels: 200 * 299,
+            ..ImageUploadLimits::default()
+        });
+        assert!(matches!(rejection(&processor, &encode(200, 300, ImageFormat::Png)), DomainError::InvalidInput(_)));
+
+        // Stored covers skip the minimum size but not the maximum
+        let processor = ImageProcessor::default();
+        assert!(processor.analyze(&encode(50, 50, ImageFormat::Png)).is_ok());
+        assert!(processor.analyze(&encode(9000, 150, ImageFormat::Png)).is_err());
+    }
+
+    #[test]
+    fn test_rejects_truncated_image() {
+        let mut data = encode(200, 300, ImageFormat::Png);
+        data.truncate(data.len() / 2);
+        assert!(matches!(rejection(&ImageProcessor::default(), &data), DomainError::InvalidInput(_)));
+    }
+
+    #[test]
+    fn test_placeholder_matches_cover_color() {
+        let mut data = Cursor::new(Vec::new());
+        DynamicImage::ImageRgb8(RgbImage::from_pixel(200, 300, image::Rgb([200, 40, 40])))
+            .write_to(&mut data, ImageFormat::Png)
+            .unwrap();
+
+        let processed = ImageProcessor::default().process(data.get_ref()).unwrap();
+        assert_eq!(processed.placeholder.average_color, "#c82828");
+        // 1 size flag + 1 max AC + 4 DC + 2 per AC component
+        assert_eq!(processed.placeholder.blurhash.len(), 6 + 2 * (3 * 4 - 1));
+        assert_eq!(
+            ImageProcessor::default().analyze(data.get_ref()).unwrap(),
+            (processed.placeholder, processed.hash)
+        );
+    }
+
+    #[test]
+    fn test_cover_hash_survives_resizing() {
+        let waves = RgbImage::from_fn(300, 450, |x, y| {
+            let value = 128.0 + 100.0 * ((x as f32 / 40.0).sin() * (y as f32 / 60.0).cos());
+            image::Rgb([value as u8, value as u8, 255 - value as u8])
+        });
+        let original = DynamicImage::ImageRgb8(waves);
+        let resized = original.resize(150, 225, FilterType::Lanczos3);
+
+        let hash = ImageProcessor::cover_hash_for(&original);
+        assert_eq!(hash.len(), 16);
+        let distance = (u64::from_str_radix(&hash, 16).unwrap()
+            ^ u64::from_str_radix(&ImageProcessor::cover_hash_for(&resized), 16).unwrap())
+            .count_ones();
+        assert!(distance <= 6);
+    }
+}
@@ -1,7 +1,13 @@
 //! External services module
 
 pub mod cloudinary;
+pub mod image_fetcher;
+pub mod image_processor;
 pub mod ml_model;
+pub mod onix;
 
 pub use cloudinary::*;
-pub use ml_model::*;
\ No newline at end of file
+pub use image_fetcher::*;
+pub use image_processor::*;
+pub use ml_model::*;
+pub use onix::*;
\ No newline at end of file
@@ -0,0 +1,451 @@
+use anyhow::Result;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+use crate::domain::entities::author::ContributorRole;
+
+/// ONIX 3.0 notification types we act on (code list 1)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OnixNotificationType {
+    EarlyNotice,        // 01
+    AdvanceNotice,      // 02
+    Confirmed,          // 03
+    Update,             // 04 - block update, only the blocks present are replaced
+    Delete,             // 05
+    Other,
+}
+
+impl OnixNotificationType {
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "01" => Self::EarlyNotice,
+            "02" => Self::AdvanceNotice,
+            "03" => Self::Confirmed,
+            "04" => Self::Update,
+            "05" => Self::Delete,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// A person or organization credited on an ONIX product, in credit order
+#[derive(Debug, Clone, PartialEq)]
+pub struct OnixContributor {
+    pub name: String,
+    pub role: ContributorRole,
+}
+
+/// Map an ONIX contributor role (code list 17) onto the roles we record.
+/// Roles we don't record, such as "Foreword by", return `None`.
+fn contributor_role(code: &str) -> Option<ContributorRole> {
+    match code {
+        "A01" => Some(ContributorRole::Author),  // By (author)
+        "A12" => Some(ContributorRole::Illustrator),
+        "B01" => Some(ContributorRole::Editor),
+        "B06" => Some(ContributorRole::Translator),
+        "E07" => Some(ContributorRole::Narrator), // Read by
+        _ => None,
+    }
+}
+
+/// A single ONIX `<Product>` record, reduced to the fields we map onto `Book`.
+/// Every field is optional so that block updates only touch what was sent.
+#[derive(Debug, Clone)]
+pub struct OnixProduct {
+    pub record_reference: String,
+    pub notification_type: OnixNotificationType,
+    pub isbn: Option<String>,
+    pub title: Option<String>,
+    pub subtitle: Option<String>,
+    pub contributors: Vec<OnixContributor>,
+    pub description: Option<String>,
+    pub genres: Vec<String>,
+    pub keywords: Vec<String>,
+    pub publisher: Option<String>,
+    pub publication_year: Option<i32>,
+    pub language: Option<String>,
+    pub page_count: Option<i32>,
+    pub cover_image_url: Option<String>,
+}
+
+/// Minimal in-memory XML element used while walking an ONIX message
+#[derive(Debug, Default)]
+struct XmlNode {
+    name: String,
+    text: String,
+    children: Vec<XmlNode>,
+}
+
+impl XmlNode {
+    fn child(&self, name: &str) -> Option<&XmlNode> {
+        self.children.iter().find(|c| c.name == name)
+    }
+
+    fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a XmlNode> {
+        self.children.iter().filter(move |c| c.name == name)
+    }
+
+    fn child_text(&self, name: &str) -> Option<String> {
+        self.child(name)
+            .map(|c| c.text.trim().to_string())
+            .filter(|t| !t.is_empty())
+    }
+}
+
+/// Parser for ONIX 3.0 reference-tag product feeds
+pub struct OnixParser;
+
+impl OnixParser {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn parse(&self, xml: &str) -> Result<Vec<OnixProduct>> {
+        let root = Self::parse_tree(xml)?;
+
+        if root.name != "ONIXMessage" {
+            return Err(anyhow::anyhow!("Not an ONIX 3.0 message (root element is <{}>)", root.name));
+        }
+
+        Ok(root.children_named("Product").map(Self::map_product).collect())
+    }
+
+    fn parse_tree(xml: &str) -> Result<XmlNode> {
+        let mut reader = Reader::from_str(xml);
+        reader.config_mut().trim_text(true);
+
+        let mut stack: Vec<XmlNode> = vec![XmlNode::default()];
+
+        loop {
+            match reader.read_event()? {
+                Event::Start(e) => stack.push(XmlNode {
+                    name: String::from_utf8_lossy(e.local_name().as_ref()).to_string(),
+                    ..Default::default()
+                }),
+                Event::Empty(e) => {
+                    let node = XmlNode {
+                        name: String::from_utf8_lossy(e.local_name().as_ref()).to_string(),
+                        ..Default::default()
+                    };
+                    stack.last_mut().unwrap().children.push(node);
+                }
+                Event::Text(t) => {
+                    stack.last_mut().unwrap().text.push_str(&t.unescape()?);
+                }
+                Event::CData(c) => {
+                    stack.last_mut().unwrap().text.push_str(&String::from_utf8_lossy(&c));
+                }
+                Event::End(_) => {
+                    let node = stack.pop().unwrap();
+                    match stack.last_mut() {
+                        Some(parent) => parent.children.push(node),
+                        None => return Err(anyhow::anyhow!("Unbalanced ONIX document")),
+                    }
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        stack
+            .pop()
+            .and_then(|document| document.children.into_iter().next())
+            .ok_or_else(|| anyhow::anyhow!("Empty ONIX document"))
+    }
+
+    fn map_product(product: &XmlNode) -> OnixProduct {
+        let notification_type = product
+            .child_text("NotificationType")
+            .map(|code| OnixNotificationType::from_code(&code))
+            .unwrap_or(OnixNotificationType::Other);
+
+        // ProductIDType 15 = ISBN-13, 02 = ISBN-10
+        let isbn = product
+            .children_named("ProductIdentifier")
+            .find(|id| id.child_text("ProductIDType").as_deref() == Some("15"))
+            .or_else(|| {
+                product
+                    .children_named("ProductIdentifier")
+                    .find(|id| id.child_text("ProductIDType").as_deref() == Some("02"))
+            })
+            .and_then(|id| id.child_text("IDValue"))
+            .map(|isbn| isbn.replace('-', ""));
+
+        let mut parsed = OnixProduct {
+            record_reference: product.child_text("RecordReference").unwrap_or_default(),
+            notification_type,
+            isbn,
+            title: None,
+            subtitle: None,
+            contributors: Vec::new(),
+            description: None,
+            genres: Vec::new(),
+            keywords: Vec::new(),
+            publisher: None,
+            publication_year: None,
+            language: None,
+            page_count: None,
+            cover_image_url: None,
+        };
+
+        if let Some(descriptive) = product.child("DescriptiveDetail") {
+            Self::map_descriptive_detail(descriptive, &mut parsed);
+        }
+        if let Some(collateral) = product.child("CollateralDetail") {
+            Self::map_collateral_detail(collateral, &mut parsed);
+        }
+        if let Some(publishing) = product.child("PublishingDetail") {
+            Self::map_publishing_detail(publishing, &mut parsed);
+        }
+
+        parsed
+    }
+
+    fn map_descriptive_detail(detail: &XmlNode, parsed: &mut OnixProduct) {
+        // TitleType 01 = distinctive title, TitleElementLevel 01 = product level
+        if let Some(element) = detail
+            .children_named("TitleDetail")
+            .find(|t| t.child_text("TitleType").as_deref() == Some("01"))
+            .and_then(|t| {
+                t.children_named("TitleElement")
+                    .find(|e| e.child_text("TitleElementLevel").as_deref() == Some("01"))
+            })
+        {
+            parsed.title = element.child_text("TitleText").or_else(|| {
+                element.child_text("TitleWithoutPrefix").map(|without| {
+                    match element.child_text("TitlePrefix") {
+                        Some(prefix) => format!("{} {}", prefix, without),
+                        None => without,
+                    }
+                })
+            });
+            parsed.subtitle = element.child_text("Subtitle");
+        }
+
+        let mut contributors: Vec<(i32, OnixContributor)> = detail
+            .children_named("Contributor")
+            .filter_map(|c| {
+                let role = c.child_text("ContributorRole").as_deref().and_then(contributor_role)?;
+                let name = c.child_text("PersonName").or_else(|| {
+                    match (c.child_text("NamesBeforeKey"), c.child_text("KeyNames")) {
+                        (Some(before), Some(key)) => Some(format!("{} {}", before, key)),
+                        (None, Some(key)) => Some(key),
+                        _ => c.child_text("CorporateName"),
+                    }
+                })?;
+                let sequence = c
+                    .child_text("SequenceNumber")
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(i32::MAX);
+                Some((sequence, OnixContributor { name, role }))
+            })
+            .collect();
+        contributors.sort_by_key(|(sequence, _)| *sequence);
+        parsed.contributors = contributors.into_iter().map(|(_, contributor)| contributor).collect();
+
+        // SubjectSchemeIdentifier 10 = BISAC subject heading, 20 = keywords
+        for subject in detail.children_named("Subject") {
+            match subject.child_text("SubjectSchemeIdentifier").as_deref() {
+                Some("10") => {
+                    let genre = subject
+                        .child_text("SubjectHeadingText")
+                        .and_then(|heading| heading.split(" / ").next().map(str::to_string))
+                        .or_else(|| subject.child_text("SubjectCode").and_then(|code| bisac_genre(&code)));
+
+                    if let Some(genre) = genre {
+                        if !parsed.genres.contains(&genre) {
+                            parsed.genres.push(genre);
+                        }
+                    }
+                }
+                Some("20") => {
+                    if let Some(text) = subject.child_text("SubjectHeadingText") {
+                        parsed.keywords.extend(
+                            text.split(';')
+                                .map(|k| k.trim().to_lowercase())
+                                .filter(|k| !k.is_empty()),
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // LanguageRole 01 = language of text
+        parsed.language = detail
+            .children_named("Language")
+            .find(|l| l.child_text("LanguageRole").as_deref() == Some("01"))
+            .and_then(|l| l.child_text("LanguageCode"));
+
+        // ExtentType 00 = main content page count, 11 = content page count; ExtentUnit 03 = pages
+        parsed.page_count = detail
+            .children_named("Extent")
+            .filter(|e| e.child_text("ExtentUnit").as_deref() == Some("03"))
+            .find(|e| matches!(e.child_text("ExtentType").as_deref(), Some("00") | Some("11")))
+            .and_then(|e| e.child_text("ExtentValue"))
+            .and_then(|v| v.parse().ok());
+    }
+
+    fn map_collateral_detail(detail: &XmlNode, parsed: &mut OnixProduct) {
+        // TextType 03 = description, 02 = short description
+        parsed.description = ["03", "02"].iter().find_map(|text_type| {
+            detail
+                .children_named("TextContent")
+                .find(|t| t.child_text("TextType").as_deref() == Some(*text_type))
+                .and_then(|t| t.child_text("Text"))
+        });
+
+        // ResourceContentType 01 = front cover, ResourceMode 03 = image
+        parsed.cover_image_url = detail
+            .children_named("SupportingResource")
+            .filter(|r| r.child_text("ResourceContentType").as_deref() == Some("01"))
+            .filter(|r| r.child_text("ResourceMode").as_deref() != Some("01"))
+            .flat_map(|r| r.children_named("ResourceVersion"))
+            .find_map(|v| v.child_text("ResourceLink"));
+    }
+
+    fn map_publishing_detail(detail: &XmlNode, parsed: &mut OnixProduct) {
+        // PublishingRole 01 = publisher
+        parsed.publisher = detail
+            .children_named("Publisher")
+            .find(|p| p.child_text("PublishingRole").as_deref() == Some("01"))
+            .and_then(|p| p.child_text("PublisherName"));
+
+        // PublishingDateRole 01 = publication date (YYYYMMDD or YYYY)
+        parsed.publication_year = detail
+            .children_named("PublishingDate")
+            .find(|d| d.child_text("PublishingDateRole").as_deref() == Some("01"))
+            .and_then(|d| d.child_text("Date"))
+            .and_then(|date| date.get(0..4).and_then(|year| year.parse().ok()));
+    }
+}
+
+/// Fallback mapping from a BISAC code prefix to a top-level genre, used when a
+/// feed sends only `<SubjectCode>` without a heading text
+fn bisac_genre(code: &str) -> Option<String> {
+    let genre = match code.get(0..3)? {
+        "ART" => "Art",
+        "BIO" => "Biography & Autobiography",
+        "BUS" => "Business & Economics",
+        "CKB" => "Cooking",
+        "CGN" => "Comics & Graphic Novels",
+        "COM" => "Computers",
+        "DRA" => "Drama",
+        "EDU" => "Education",
+        "FIC" => "Fiction",
+        "HEA" => "Health & Fitness",
+        "HIS" => "History",
+        "JUV" => "Juvenile Fiction",
+        "JNF" => "Juvenile Nonfiction",
+        "LIT" => "Literary Criticism",
+        "MAT" => "Mathematics",
+        "MUS" => "Music",
+        "PHI" => "Philosophy",
+        "POE" => "Poetry",
+        "POL" => "Political Science",
+        "PSY" => "Psychology",
+        "REL" => "Religion",
+        "SCI" => "Science",
+        "SEL" => "Self-Help",
+        "SOC" => "Social Science",
+        "TEC" => "Technology & Engineering",
+        "TRV" => "Travel",
+        "TRU" => "True Crime",
+        "YAF" => "Young Adult Fiction",
+        "YAN" => "Young Adult Nonfiction",
+        _ => return None,
+    };
+
+    Some(genre.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ONIXMessage release="3.0" xmlns="http://ns.editeur.org/onix/3.0/reference">
+  <Header><Sender><SenderName>Example Press</SenderName></Sender></Header>
+  <Product>
+    <RecordReference>com.example.0001</RecordReference>
+    <NotificationType>03</NotificationType>
+    <ProductIdentifier><ProductIDType>15</ProductIDType><IDValue>978-0-00-000000-2</IDValue></ProductIdentifier>
+    <DescriptiveDetail>
+      <TitleDetail>
+        <TitleType>01</TitleType>
+        <TitleElement>
+          <TitleElementLevel>01</TitleElementLevel>
+          <TitlePrefix>The</TitlePrefix>
+          <TitleWithoutPrefix>Long Voyage</TitleWithoutPrefix>
+          <Subtitle>A Novel</Subtitle>
+        </TitleElement>
+      </TitleDetail>
+      <Contributor><SequenceNumber>2</SequenceNumber><ContributorRole>A01</ContributorRole><PersonName>Second Author</PersonName></Contributor>
+      <Contributor><SequenceNumber>1</SequenceNumber><ContributorRole>A01</ContributorRole><NamesBeforeKey>Jane</NamesBeforeKey><KeyNames>Doe</KeyNames></Contributor>
+      <Contributor><ContributorRole>B06</ContributorRole><PersonName>A Translator</PersonName></Contributor>
+      <Extent><ExtentType>00</ExtentType><ExtentValue>320</ExtentValue><ExtentUnit>03</ExtentUnit></Extent>
+      <Language><LanguageRole>01</LanguageRole><LanguageCode>eng</LanguageCode></Language>
+      <Subject><SubjectSchemeIdentifier>10</SubjectSchemeIdentifier><SubjectCode>FIC009000</SubjectCode></Subject>
+      <Subject><SubjectSchemeIdentifier>20</SubjectSchemeIdentifier><SubjectHeadingText>Sea; Adventure</SubjectHeadingText></Subject>
+    </DescriptiveDetail>
+    <CollateralDetail>
+      <TextContent><TextType>03</TextType><Text><![CDATA[A <b>long</b> voyage.]]></Text></TextContent>
+      <SupportingResource>
+        <ResourceContentType>01</ResourceContentType>
+        <ResourceMode>03</ResourceMode>
+        <ResourceVersion><ResourceForm>02</ResourceForm><ResourceLink>https://example.com/cover.jpg</ResourceLink></ResourceVersion>
+      </SupportingResource>
+    </CollateralDetail>
+    <PublishingDetail>
+      <Publisher><PublishingRole>01</PublishingRole><PublisherName>Example Press</PublisherName></Publisher>
+      <PublishingDate><PublishingDateRole>01</PublishingDateRole><Date>20190412</Date></PublishingDate>
+    </PublishingDetail>
+  </Product>
+  <Product>
+    <RecordReference>com.example.0002</RecordReference>
+    <NotificationType>05</NotificationType>
+    <ProductIdentifier><ProductIDType>15</ProductIDType><IDValue>9780000000019</IDValue></ProductIdentifier>
+  </Product>
+</ONIXMessage>"#;
+
+    #[test]
+    fn test_parse_product_fields() {
+        let products = OnixParser::new().parse(SAMPLE).unwrap();
+        assert_eq!(products.len(), 2);
+
+        let product = &products[0];
+        assert_eq!(product.notification_type, OnixNotificationType::Confirmed);
+        assert_eq!(product.isbn.as_deref(), Some("9780000000002"));
+        assert_eq!(product.title.as_deref(), Some("The Long Voyage"));
+        assert_eq!(product.subtitle.as_deref(), Some("A Novel"));
+        let credits: Vec<(&str, ContributorRole)> = product.contributors
+            .iter()
+            .map(|c| (c.name.as_str(), c.role))
+            .collect();
+        assert_eq!(credits, vec![
+            ("Jane Doe", ContributorRole::Author),
+            ("Second Author", ContributorRole::Author),
+            ("A Translator", ContributorRole::Translator),
+        ]);
+        assert_eq!(product.genres, vec!["Fiction"]);
+        assert_eq!(product.keywords, vec!["sea", "adventure"]);
+        assert_eq!(product.page_count, Some(320));
+        assert_eq!(product.language.as_deref(), Some("eng"));
+        assert_eq!(product.description.as_deref(), Some("A <b>long</b> voyage."));
+        assert_eq!(product.cover_image_url.as_deref(), Some("https://example.com/cover.jpg"));
+        assert_eq!(product.publisher.as_deref(), Some("Example Press"));
+        assert_eq!(product.publication_year, Some(2019));
+    }
+
+    #[test]
+    fn test_parse_delete_notification() {
+        let products = OnixParser::new().parse(SAMPLE).unwrap();
+        assert_eq!(products[1].notification_type, OnixNotificationType::Delete);
+        assert!(products[1].title.is_none());
+    }
+
+    #[test]
+    fn test_rejects_non_onix_document() {
+        assert!(OnixParser::new().parse("<Catalog></Catalog>").is_err());
+    }
+}
@@ -0,0 +1,25 @@
+use crate::{
+    application::use_cases::books::backfill_cover_placeholders::BackfillCoverPlaceholdersUseCase,
+    domain::repositories::{book_repository::BookRepository, user_repository::UserRepository},
+};
+
+/// Backfill missing cover placeholders once, in the background, at startup
+pub fn spawn_cover_placeholder_backfill_job<UR, BR>(
+    use_case: BackfillCoverPlaceholdersUseCase<UR, BR>,
+) -> tokio::task::JoinHandle<()>
+where
+    UR: UserRepository + 'static,
+    BR: BookRepository + 'static,
+{
+    tokio::spawn(async move {
+        match use_case.execute().await {
+            Ok(result) => tracing::info!(
+                "Cover placeholder backfill scanned {} books, added {}, failed {}",
+                result.books_scanned,
+                result.placeholders_added,
+                result.failures
+            ),
+            Err(e) => tracing::error!("Cover placeholder backfill failed: {:?}", e),
+        }
+    })
+}
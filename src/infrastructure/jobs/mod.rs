@@ -0,0 +1,9 @@
+//! Background jobs module
+
+pub mod cover_placeholder_backfill_job;
+pub mod purge_deleted_records_job;
+pub mod trending_refresh_job;
+
+pub use cover_placeholder_backfill_job::*;
+pub use purge_deleted_records_job::*;
+pub use trending_refresh_job::*;
@@ -0,0 +1,33 @@
+use std::time::Duration;
+
+use crate::{
+    application::use_cases::admin::purge_deleted_records::PurgeDeletedRecordsUseCase,
+    domain::repositories::{book_repository::BookRepository, user_repository::UserRepository},
+};
+
+/// Run the purge every `interval` for the lifetime of the process
+pub fn spawn_purge_deleted_records_job<UR, BR>(
+    use_case: PurgeDeletedRecordsUseCase<UR, BR>,
+    interval: Duration,
+) -> tokio::task::JoinHandle<()>
+where
+    UR: UserRepository + 'static,
+    BR: BookRepository + 'static,
+{
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+
+            match use_case.execute().await {
+                Ok(result) => tracing::info!(
+                    "Purged {} books and {} users deleted before {}",
+                    result.books_purged,
+                    result.users_purged,
+                    result.deleted_before
+                ),
+                Err(e) => tracing::error!("Purge of deleted records failed: {:?}", e),
+            }
+        }
+    })
+}
@@ -0,0 +1,38 @@
+use std::time::Duration;
+
+use crate::{
+    application::use_cases::books::trending_books::RefreshTrendingUseCase,
+    domain::repositories::{
+        book_repository::BookRepository,
+        recommendation_repository::RecommendationRepository,
+        trending_repository::TrendingRepository,
+    },
+};
+
+/// Refresh the trending and new-and-notable lists every `interval` for the
+/// lifetime of the process
+pub fn spawn_trending_refresh_job<BR, RR, TR>(
+    use_case: RefreshTrendingUseCase<BR, RR, TR>,
+    interval: Duration,
+) -> tokio::task::JoinHandle<()>
+where
+    BR: BookRepository + 'static,
+    RR: RecommendationRepository + 'static,
+    TR: TrendingRepository + 'static,
+{
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+
+            match use_case.execute().await {
+                Ok(result) => tracing::info!(
+                    "Refreshed {} trending lists with {} books",
+                    result.lists_refreshed,
+                    result.books_ranked
+                ),
+                Err(e) => tracing::error!("Trending refresh failed: {:?}", e),
+            }
+        }
+    })
+}
@@ -6,7 +6,13 @@
 pub mod database;
 pub mod external;
 pub mod middleware;
+pub mod jobs;
+pub mod storage;
+pub mod moderation;
 
 pub use database::*;
 pub use external::*;
-pub use middleware::*;
\ No newline at end of file
+pub use middleware::*;
+pub use jobs::*;
+pub use storage::*;
+pub use moderation::*;
\ No newline at end of file
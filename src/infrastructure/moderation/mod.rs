@@ -0,0 +1,89 @@
+//! Review moderation filters built from configuration
+
+use anyhow::{anyhow, Context, Result};
+
+use crate::config::settings::ModerationSettings;
+use crate::domain::services::review_moderation_service::{
+    ReviewModerationService, ReviewFilter, ProfanityFilter, LinkSpamFilter, RepeatedTextFilter, ModerationVerdict,
+};
+
+/// Words from an optional list file, one per line; blank lines and `#` comments are skipped
+fn read_word_list(path: Option<&str>) -> Result<Vec<String>> {
+    let Some(path) = path else {
+        return Ok(Vec::new());
+    };
+
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read word list {}", path))?;
+    Ok(contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(String::from)
+        .collect())
+}
+
+/// Build the review moderation chain described by `[moderation]`
+pub fn review_moderation_from_settings(settings: &ModerationSettings) -> Result<ReviewModerationService> {
+    let mut filters: Vec<Box<dyn ReviewFilter>> = Vec::new();
+
+    for name in &settings.filters {
+        match name.as_str() {
+            "profanity" => {
+                let profanity = &settings.profanity;
+                let mut hold_words = profanity.hold_words.clone();
+                hold_words.extend(read_word_list(profanity.hold_words_file.as_deref())?);
+                let mut reject_words = profanity.reject_words.clone();
+                reject_words.extend(read_word_list(profanity.reject_words_file.as_deref())?);
+
+                filters.push(Box::new(ProfanityFilter::new(hold_words, reject_words)));
+            }
+            "links" => {
+                let links = &settings.links;
+                filters.push(Box::new(LinkSpamFilter {
+                    links_to_hold: links.links_to_hold,
+                    links_to_reject: links.links_to_reject,
+                    blocked_domains: links.blocked_domains.clone(),
+                    max_uppercase_ratio: links.max_uppercase_ratio,
+                }));
+            }
+            "repeated_text" => {
+                let repeated = &settings.repeated_text;
+                filters.push(Box::new(RepeatedTextFilter {
+                    max_char_run: repeated.max_char_run,
+                    min_unique_word_ratio: repeated.min_unique_word_ratio,
+                    duplicate_verdict: if repeated.reject_duplicates {
+                        ModerationVerdict::Reject
+                    } else {
+                        ModerationVerdict::Hold
+                    },
+                }));
+            }
+            other => return Err(anyhow!("Unknown review moderation filter \"{}\"", other)),
+        }
+    }
+
+    Ok(ReviewModerationService::new(filters))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filters_follow_configured_order() {
+        let mut settings = ModerationSettings::default();
+        settings.filters = vec!["repeated_text".to_string(), "profanity".to_string()];
+
+        let service = review_moderation_from_settings(&settings).unwrap();
+        assert_eq!(service.filter_names(), vec!["repeated_text", "profanity"]);
+    }
+
+    #[test]
+    fn test_unknown_filter_is_an_error() {
+        let mut settings = ModerationSettings::default();
+        settings.filters = vec!["sentiment".to_string()];
+
+        assert!(review_moderation_from_settings(&settings).is_err());
+    }
+}
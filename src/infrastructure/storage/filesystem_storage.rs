@@ -0,0 +1,81 @@
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use std::path::{Component, Path, PathBuf};
+
+use crate::application::ports::image_storage_port::{ImageStorage, StoredImage};
+
+/// Stores images under a local directory that the app serves itself
+pub struct FilesystemImageStorage {
+    root_dir: PathBuf,
+    public_url: String,
+}
+
+impl FilesystemImageStorage {
+    pub fn new(root_dir: impl Into<PathBuf>, public_url: &str) -> Self {
+        Self {
+            root_dir: root_dir.into(),
+            public_url: public_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Resolve a key inside `root_dir`, refusing anything that could escape it
+    fn path_for(&self, key: &str) -> Result<PathBuf> {
+        let relative = Path::new(key);
+        if key.is_empty() || !relative.components().all(|c| matches!(c, Component::Normal(_))) {
+            bail!("Invalid storage key \"{}\"", key);
+        }
+        Ok(self.root_dir.join(relative))
+    }
+}
+
+#[async_trait]
+impl ImageStorage for FilesystemImageStorage {
+    async fn store(&self, key: &str, data: Vec<u8>, _content_type: &str) -> Result<StoredImage> {
+        let path = self.path_for(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::write(&path, data).await?;
+
+        Ok(StoredImage {
+            key: key.to_string(),
+            url: format!("{}/{}", self.public_url, key),
+        })
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        match tokio::fs::remove_file(self.path_for(key)?).await {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_store_and_delete() {
+        let root = std::env::temp_dir().join(format!("covers-{}", uuid::Uuid::new_v4()));
+        let storage = FilesystemImageStorage::new(&root, "http://localhost:8080/uploads/");
+
+        let stored = storage.store("book-covers/1/card.webp", vec![1, 2, 3], "image/webp").await.unwrap();
+        assert_eq!(stored.url, "http://localhost:8080/uploads/book-covers/1/card.webp");
+        assert_eq!(std::fs::read(root.join("book-covers/1/card.webp")).unwrap(), vec![1, 2, 3]);
+
+        storage.delete(&stored.key).await.unwrap();
+        assert!(!root.join("book-covers/1/card.webp").exists());
+        // Already gone
+        storage.delete(&stored.key).await.unwrap();
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rejects_keys_outside_root() {
+        let storage = FilesystemImageStorage::new(std::env::temp_dir(), "http://localhost/uploads");
+        assert!(storage.store("../escape.jpg", vec![], "image/jpeg").await.is_err());
+        assert!(storage.store("/etc/passwd", vec![], "image/jpeg").await.is_err());
+    }
+}
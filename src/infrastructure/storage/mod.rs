@@ -0,0 +1,39 @@
+//! Image storage adapters
+
+pub mod filesystem_storage;
+pub mod s3_storage;
+
+pub use filesystem_storage::*;
+pub use s3_storage::*;
+
+use anyhow::{anyhow, Result};
+use std::sync::Arc;
+
+use crate::application::ports::image_storage_port::ImageStorage;
+use crate::config::settings::{Settings, StorageBackend};
+use crate::infrastructure::external::cloudinary::CloudinaryService;
+
+/// Build the image storage backend selected in `Settings`
+pub fn image_storage_from_settings(settings: &Settings) -> Result<Arc<dyn ImageStorage>> {
+    match settings.storage.backend {
+        StorageBackend::Filesystem => {
+            let filesystem = &settings.storage.filesystem;
+            Ok(Arc::new(FilesystemImageStorage::new(&filesystem.root_dir, &filesystem.public_url)))
+        }
+        StorageBackend::S3 => {
+            let s3 = settings.storage.s3.clone()
+                .ok_or_else(|| anyhow!("storage.backend is \"s3\" but [storage.s3] is missing"))?;
+            Ok(Arc::new(S3ImageStorage::new(s3)))
+        }
+        StorageBackend::Cloudinary => {
+            let cloudinary = settings.cloudinary.clone()
+                .ok_or_else(|| anyhow!("storage.backend is \"cloudinary\" but [cloudinary] is missing"))?;
+            Ok(Arc::new(CloudinaryService::new(
+                cloudinary.cloud_name,
+                cloudinary.api_key,
+                cloudinary.api_secret,
+                cloudinary.upload_preset,
+            )))
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use actix_web::{middleware::Logger, web, App, HttpServer};
+use clap::{Parser, Subcommand};
 use dotenv::dotenv;
 use std::env;
 use tracing_subscriber;
@@ -13,7 +14,7 @@ mod presentation;
 mod di;
 
 use config::database::initialize_database;
-use presentation::routes::{auth_routes, book_routes, recommendation_routes};
+use presentation::routes::{auth_routes, book_routes, recommendation_routes, duplicate_routes, work_routes, author_routes, genre_routes, tag_routes, admin_routes, me_routes, review_routes, reading_session_routes, challenge_routes};
 
 #[derive(OpenApi)]
 #[openapi(
@@ -22,7 +23,87 @@ use presentation::routes::{auth_routes, book_routes, recommendation_routes};
         presentation::controllers::auth_controller::login,
         presentation::controllers::book_controller::create_book,
         presentation::controllers::book_controller::get_books,
+        presentation::controllers::book_controller::ingest_onix_feed,
+        presentation::controllers::book_controller::get_book,
+        presentation::controllers::book_controller::get_similar_books,
+        presentation::controllers::book_controller::get_trending_books,
+        presentation::controllers::book_controller::get_book_revisions,
+        presentation::controllers::book_controller::revert_book,
+        presentation::controllers::book_controller::upsert_book_translation,
+        presentation::controllers::book_controller::delete_book_translation,
+        presentation::controllers::book_controller::backfill_cover_placeholders,
+        presentation::controllers::book_controller::find_cover_duplicates,
         presentation::controllers::recommendation_controller::get_recommendations,
+        presentation::controllers::duplicate_controller::scan_duplicates,
+        presentation::controllers::duplicate_controller::list_duplicates,
+        presentation::controllers::duplicate_controller::merge_duplicate,
+        presentation::controllers::duplicate_controller::dismiss_duplicate,
+        presentation::controllers::work_controller::create_work,
+        presentation::controllers::work_controller::get_work,
+        presentation::controllers::work_controller::get_next_in_series,
+        presentation::controllers::work_controller::add_edition,
+        presentation::controllers::work_controller::create_series,
+        presentation::controllers::work_controller::get_series,
+        presentation::controllers::author_controller::get_author,
+        presentation::controllers::author_controller::update_author,
+        presentation::controllers::author_controller::add_book_contributor,
+        presentation::controllers::author_controller::backfill_authors,
+        presentation::controllers::genre_controller::get_genre_tree,
+        presentation::controllers::genre_controller::create_genre,
+        presentation::controllers::genre_controller::update_genre,
+        presentation::controllers::genre_controller::delete_genre,
+        presentation::controllers::tag_controller::get_book_tags,
+        presentation::controllers::tag_controller::suggest_tag,
+        presentation::controllers::tag_controller::vote_tag,
+        presentation::controllers::tag_controller::list_pending_tags,
+        presentation::controllers::tag_controller::approve_tag,
+        presentation::controllers::tag_controller::reject_tag,
+        presentation::controllers::tag_controller::list_blocked_tags,
+        presentation::controllers::tag_controller::block_tag,
+        presentation::controllers::tag_controller::unblock_tag,
+        presentation::controllers::admin_controller::delete_book,
+        presentation::controllers::admin_controller::list_deleted_books,
+        presentation::controllers::admin_controller::restore_book,
+        presentation::controllers::admin_controller::delete_user,
+        presentation::controllers::admin_controller::list_deleted_users,
+        presentation::controllers::admin_controller::restore_user,
+        presentation::controllers::admin_controller::purge_deleted_records,
+        presentation::controllers::review_controller::update_rating,
+        presentation::controllers::review_controller::delete_rating,
+        presentation::controllers::review_controller::get_book_reviews,
+        presentation::controllers::review_controller::get_my_ratings,
+        presentation::controllers::review_controller::vote_review,
+        presentation::controllers::review_controller::report_review,
+        presentation::controllers::review_controller::list_reported_reviews,
+        presentation::controllers::review_controller::dismiss_review_reports,
+        presentation::controllers::review_controller::remove_review,
+        presentation::controllers::review_controller::list_pending_reviews,
+        presentation::controllers::review_controller::approve_review,
+        presentation::controllers::review_controller::reject_review,
+        presentation::controllers::notification_controller::get_my_notifications,
+        presentation::controllers::notification_controller::mark_notification_read,
+        presentation::controllers::reading_session_controller::start_reading_session,
+        presentation::controllers::reading_session_controller::end_reading_session,
+        presentation::controllers::reading_session_controller::get_reading_sessions,
+        presentation::controllers::shelf_controller::get_my_shelves,
+        presentation::controllers::shelf_controller::create_shelf,
+        presentation::controllers::shelf_controller::delete_shelf,
+        presentation::controllers::shelf_controller::get_shelved_books,
+        presentation::controllers::shelf_controller::shelve_book,
+        presentation::controllers::shelf_controller::unshelve_book,
+        presentation::controllers::shelf_controller::get_shelf_history,
+        presentation::controllers::shelf_controller::export_shelves,
+        presentation::controllers::goal_controller::get_my_goals,
+        presentation::controllers::goal_controller::upsert_goal,
+        presentation::controllers::goal_controller::delete_goal,
+        presentation::controllers::challenge_controller::get_challenges,
+        presentation::controllers::challenge_controller::get_challenge,
+        presentation::controllers::challenge_controller::join_challenge,
+        presentation::controllers::challenge_controller::leave_challenge,
+        presentation::controllers::challenge_controller::get_challenge_leaderboard,
+        presentation::controllers::challenge_controller::create_challenge,
+        presentation::controllers::challenge_controller::delete_challenge,
+        presentation::controllers::stats_controller::get_my_stats,
     ),
     components(
         schemas(
@@ -30,22 +111,148 @@ use presentation::routes::{auth_routes, book_routes, recommendation_routes};
             application::dtos::auth_dtos::LoginRequest,
             application::dtos::auth_dtos::AuthResponse,
             application::dtos::book_dtos::CreateBookRequest,
+            application::dtos::book_dtos::OnixIngestResponse,
+            application::dtos::book_dtos::OnixIngestError,
+            application::dtos::book_dtos::DuplicateScanResponse,
+            application::dtos::book_dtos::DuplicateCandidateResponse,
+            application::dtos::book_dtos::DuplicateBookSummary,
+            application::dtos::book_dtos::MergeBooksRequest,
+            application::dtos::book_dtos::MergeBooksResponse,
+            application::dtos::book_dtos::BookRevisionResponse,
+            application::dtos::book_dtos::BookTranslationRequest,
+            application::dtos::book_dtos::CoverImageSet,
+            application::dtos::book_dtos::CoverPlaceholderBackfillResponse,
+            application::dtos::book_dtos::CoverUploadResponse,
+            application::dtos::book_dtos::SimilarCoverResponse,
+            application::dtos::book_dtos::CoverDuplicateResponse,
+            application::dtos::book_dtos::SimilarBookResponse,
+            application::dtos::trending_dtos::TrendingResponse,
+            application::dtos::trending_dtos::TrendingBookResponse,
+            domain::entities::trending::TrendingWindow,
+            domain::entities::revision::FieldChange,
             domain::entities::user::User,
             domain::entities::book::Book,
+            domain::entities::book::BookTranslation,
+            domain::entities::book::CoverImage,
+            domain::entities::book::CoverSize,
+            domain::entities::book::CoverFormat,
+            domain::entities::book::CoverPlaceholder,
+            domain::entities::work::EditionFormat,
+            application::dtos::work_dtos::CreateWorkRequest,
+            application::dtos::work_dtos::AddEditionRequest,
+            application::dtos::work_dtos::CreateSeriesRequest,
+            application::dtos::work_dtos::WorkSummary,
+            application::dtos::work_dtos::WorkResponse,
+            application::dtos::work_dtos::SeriesResponse,
+            domain::entities::author::ContributorRole,
+            application::dtos::author_dtos::AuthorSummary,
+            application::dtos::author_dtos::AuthorResponse,
+            application::dtos::author_dtos::BibliographyEntry,
+            application::dtos::author_dtos::UpdateAuthorRequest,
+            application::dtos::author_dtos::AddContributorRequest,
+            application::dtos::author_dtos::ContributorResponse,
+            application::dtos::author_dtos::AuthorBackfillResponse,
+            application::dtos::genre_dtos::CreateGenreRequest,
+            application::dtos::genre_dtos::UpdateGenreRequest,
+            application::dtos::genre_dtos::GenreResponse,
+            application::dtos::genre_dtos::GenreNode,
+            domain::entities::tag::TagStatus,
+            application::dtos::tag_dtos::SuggestTagRequest,
+            application::dtos::tag_dtos::VoteTagRequest,
+            application::dtos::tag_dtos::RejectTagRequest,
+            application::dtos::tag_dtos::BlockTagRequest,
+            application::dtos::tag_dtos::BookTagResponse,
+            application::dtos::tag_dtos::BlockedTagResponse,
+            application::dtos::admin_dtos::DeletedBookResponse,
+            application::dtos::admin_dtos::DeletedUserResponse,
+            application::dtos::admin_dtos::PurgeResponse,
+            application::dtos::book_dtos::BookRatingRequest,
+            application::dtos::book_dtos::BookRatingResponse,
+            domain::entities::book::ReviewSort,
+            domain::entities::review::ReviewStatus,
+            domain::entities::review::ReportReason,
+            domain::entities::review::ReportStatus,
+            application::dtos::review_dtos::VoteReviewRequest,
+            application::dtos::review_dtos::ReportReviewRequest,
+            application::dtos::review_dtos::ReviewReportResponse,
+            application::dtos::review_dtos::ReportedReviewResponse,
+            application::dtos::review_dtos::RejectReviewRequest,
+            domain::entities::notification::NotificationKind,
+            application::dtos::notification_dtos::NotificationResponse,
+            application::dtos::reading_dtos::StartReadingSessionRequest,
+            application::dtos::reading_dtos::EndReadingSessionRequest,
+            application::dtos::reading_dtos::ReadingSessionResponse,
+            domain::entities::recommendation::ReadingProgress,
+            domain::entities::shelf::ShelfStatus,
+            domain::entities::shelf::ShelfStatusChange,
+            application::dtos::shelf_dtos::CreateShelfRequest,
+            application::dtos::shelf_dtos::ShelveBookRequest,
+            application::dtos::shelf_dtos::ShelfExportFormat,
+            application::dtos::shelf_dtos::ShelfResponse,
+            application::dtos::shelf_dtos::ShelfEntryResponse,
+            application::dtos::shelf_dtos::ShelfExportRow,
+            domain::entities::goal::GoalMetric,
+            domain::entities::goal::GoalPeriod,
+            domain::entities::goal::GoalProgress,
+            application::dtos::goal_dtos::UpsertGoalRequest,
+            application::dtos::goal_dtos::GoalResponse,
+            domain::entities::challenge::ChallengeRule,
+            domain::entities::challenge::ChallengeRuleProgress,
+            domain::entities::challenge::ChallengeProgress,
+            application::dtos::challenge_dtos::CreateChallengeRequest,
+            application::dtos::challenge_dtos::ChallengeResponse,
+            application::dtos::challenge_dtos::LeaderboardEntry,
+            application::dtos::challenge_dtos::LeaderboardResponse,
+            domain::entities::stats::ReadingStats,
+            domain::entities::stats::ReadingTotals,
+            domain::entities::stats::PeriodStats,
+            domain::entities::stats::ReadingStreaks,
+            domain::entities::stats::BreakdownEntry,
+            domain::entities::stats::BookHighlight,
+            domain::entities::stats::YearInReview,
         )
     ),
     tags(
         (name = "Authentication", description = "User authentication endpoints"),
         (name = "Books", description = "Book management endpoints"),
-        (name = "Recommendations", description = "Book recommendation endpoints")
+        (name = "Recommendations", description = "Book recommendation endpoints"),
+        (name = "Duplicates", description = "Duplicate book review and merge (admin)"),
+        (name = "Works", description = "Works, editions and series"),
+        (name = "Authors", description = "Authors, publishers and book contributors"),
+        (name = "Genres", description = "Genre taxonomy"),
+        (name = "Tags", description = "User-contributed tags, voting and moderation"),
+        (name = "Admin", description = "Deleted records: restore and purge"),
+        (name = "Reviews", description = "Ratings, reviews, helpfulness votes, abuse reports and moderation"),
+        (name = "Notifications", description = "In-app notifications for the caller"),
+        (name = "Reading", description = "Reading sessions, progress and statistics"),
+        (name = "Shelves", description = "The caller's reading shelves and their export"),
+        (name = "Goals", description = "The caller's yearly and monthly reading goals"),
+        (name = "Challenges", description = "Community reading challenges and leaderboards")
     )
 )]
 struct ApiDoc;
 
+#[derive(Parser)]
+#[command(about = "Book recommendation API server and maintenance commands")]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+/// Maintenance commands run against the database and exit without serving
+#[derive(Subcommand)]
+enum Command {
+    /// Serve the API (the default)
+    Serve,
+    /// Recompute every book's and work's rating stats from the stored ratings
+    RecomputeRatingStats,
+}
+
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
     dotenv().ok();
     tracing_subscriber::init();
+    let cli = Cli::parse();
 
     // Initialize database
     let db = initialize_database(
@@ -56,6 +263,30 @@ async fn main() -> std::io::Result<()> {
     .await
     .expect("Failed to initialize database");
 
+    match cli.command.unwrap_or(Command::Serve) {
+        Command::Serve => {}
+        Command::RecomputeRatingStats => {
+            let result = application::use_cases::books::recompute_rating_stats::RecomputeRatingStatsUseCase::new(
+                infrastructure::database::book_repository_impl::BookRepositoryImpl::new(db.clone()),
+                infrastructure::database::work_repository_impl::WorkRepositoryImpl::new(db.clone()),
+            )
+            .execute()
+            .await
+            .map_err(|e| {
+                tracing::error!("Rating stats recompute failed: {:?}", e);
+                std::io::Error::other(e.to_string())
+            })?;
+
+            tracing::info!(
+                "Recomputed rating stats: scanned {} books, repaired {}, recomputed {} works",
+                result.books_scanned,
+                result.books_repaired,
+                result.works_recomputed
+            );
+            return Ok(());
+        }
+    }
+
     // Create dependency injection container
     let container = di::container::AppModule::builder()
         .with_component_override::<dyn UserRepository>(Box::new(
@@ -66,6 +297,69 @@ async fn main() -> std::io::Result<()> {
         ))
         .build();
 
+    // Hard delete soft-deleted books and users once their retention window has passed
+    let retention_days = env::var("DELETED_RETENTION_DAYS")
+        .ok()
+        .and_then(|days| days.parse().ok())
+        .unwrap_or(application::use_cases::admin::purge_deleted_records::DEFAULT_RETENTION_DAYS);
+    let purge_interval_hours: u64 = env::var("PURGE_INTERVAL_HOURS")
+        .ok()
+        .and_then(|hours| hours.parse().ok())
+        .unwrap_or(24);
+    infrastructure::jobs::purge_deleted_records_job::spawn_purge_deleted_records_job(
+        application::use_cases::admin::purge_deleted_records::PurgeDeletedRecordsUseCase::new(
+            infrastructure::database::user_repository_impl::UserRepositoryImpl::new(db.clone()),
+            infrastructure::database::book_repository_impl::BookRepositoryImpl::new(db.clone()),
+            retention_days,
+        ),
+        std::time::Duration::from_secs(purge_interval_hours * 3600),
+    );
+
+    // Compute placeholders for covers uploaded before they existed
+    infrastructure::jobs::cover_placeholder_backfill_job::spawn_cover_placeholder_backfill_job(
+        application::use_cases::books::backfill_cover_placeholders::BackfillCoverPlaceholdersUseCase::new(
+            infrastructure::database::user_repository_impl::UserRepositoryImpl::new(db.clone()),
+            infrastructure::database::book_repository_impl::BookRepositoryImpl::new(db.clone()),
+            infrastructure::external::image_processor::ImageProcessor::default(),
+            infrastructure::external::image_fetcher::ImageFetcher::default(),
+        ),
+    );
+
+    // Prior every book's weighted rating is pulled towards
+    let rating_settings = config::settings::Settings::new()
+        .map(|settings| settings.ratings)
+        .unwrap_or_default();
+    let rating_prior = domain::services::rating_service::RatingPrior::new(
+        rating_settings.prior_mean,
+        rating_settings.prior_weight,
+    );
+
+    // Keep the trending and new-and-notable lists fresh
+    let trending_settings = config::settings::Settings::new()
+        .map(|settings| settings.trending)
+        .unwrap_or_default();
+    infrastructure::jobs::trending_refresh_job::spawn_trending_refresh_job(
+        application::use_cases::books::trending_books::RefreshTrendingUseCase::new(
+            infrastructure::database::book_repository_impl::BookRepositoryImpl::new(db.clone()),
+            infrastructure::database::recommendation_repository_impl::RecommendationRepositoryImpl::new(db.clone()),
+            infrastructure::database::trending_repository_impl::TrendingRepositoryImpl::new(db.clone()),
+            domain::services::trending_service::TrendingService {
+                list_size: trending_settings.list_size,
+                click_weight: trending_settings.click_weight,
+                new_release_days: trending_settings.new_release_days,
+                new_and_notable_min_ratings: trending_settings.new_and_notable_min_ratings,
+                new_and_notable_min_average: trending_settings.new_and_notable_min_average,
+                ..domain::services::trending_service::TrendingService::new(rating_prior)
+            },
+        ),
+        std::time::Duration::from_secs(trending_settings.refresh_interval_minutes.max(1) * 60),
+    );
+
+    // Serve locally stored cover images when the filesystem backend is selected
+    let storage_settings = config::settings::Settings::new()
+        .map(|settings| settings.storage)
+        .unwrap_or_default();
+
     let openapi = ApiDoc::openapi();
 
     HttpServer::new(move || {
@@ -79,6 +373,12 @@ async fn main() -> std::io::Result<()> {
                     .allow_any_header()
                     .max_age(3600),
             )
+            .configure(|cfg| {
+                if storage_settings.backend == config::settings::StorageBackend::Filesystem {
+                    let filesystem = &storage_settings.filesystem;
+                    cfg.service(actix_files::Files::new(&filesystem.mount_path, &filesystem.root_dir));
+                }
+            })
             .service(
                 SwaggerUi::new("/swagger-ui/{_:.*}")
                     .url("/api-docs/openapi.json", openapi.clone()),
@@ -88,6 +388,16 @@ async fn main() -> std::io::Result<()> {
                     .configure(auth_routes::configure)
                     .configure(book_routes::configure)
                     .configure(recommendation_routes::configure)
+                    .configure(duplicate_routes::configure)
+                    .configure(work_routes::configure)
+                    .configure(author_routes::configure)
+                    .configure(genre_routes::configure)
+                    .configure(tag_routes::configure)
+                    .configure(admin_routes::configure)
+                    .configure(me_routes::configure)
+                    .configure(review_routes::configure)
+                    .configure(reading_session_routes::configure)
+                    .configure(challenge_routes::configure)
             )
     })
     .bind("127.0.0.1:8080")?
@@ -0,0 +1,253 @@
+use actix_web::{web, HttpResponse, Result as ActixResult, HttpRequest};
+use uuid::Uuid;
+
+use crate::{
+    application::{
+        dtos::book_dtos::PaginationQuery,
+        use_cases::admin::{
+            deleted_records::ManageDeletedRecordsUseCase,
+            purge_deleted_records::PurgeDeletedRecordsUseCase,
+        },
+    },
+    domain::{
+        repositories::{book_repository::BookRepository, user_repository::UserRepository},
+        services::auth_service::Claims,
+        errors::domain_error::DomainError,
+    },
+};
+
+fn error_response(e: anyhow::Error, context: &str) -> HttpResponse {
+    match e.downcast_ref::<DomainError>() {
+        Some(DomainError::Unauthorized) => HttpResponse::Forbidden().json("Insufficient permissions"),
+        Some(DomainError::BookNotFound) | Some(DomainError::UserNotFound) => {
+            HttpResponse::NotFound().json(e.to_string())
+        }
+        Some(DomainError::InvalidInput(message)) => HttpResponse::BadRequest().json(message),
+        _ => {
+            tracing::error!("{}: {:?}", context, e);
+            HttpResponse::InternalServerError().json(context)
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/books/{book_id}",
+    params(
+        ("book_id" = Uuid, Path, description = "Book ID")
+    ),
+    responses(
+        (status = 204, description = "Book deleted, restorable until purged"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Curator access required"),
+        (status = 404, description = "Book not found")
+    ),
+    tag = "Admin",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_book(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    deleted_records_use_case: web::Data<ManageDeletedRecordsUseCase<UserRepository, BookRepository>>,
+) -> ActixResult<HttpResponse> {
+    let book_id = path.into_inner();
+
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match deleted_records_use_case.delete_book(user_id, book_id).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Ok(error_response(e, "Book deletion failed")),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/books/deleted",
+    params(
+        ("limit" = Option<usize>, Query, description = "Limit results"),
+        ("offset" = Option<usize>, Query, description = "Offset for pagination")
+    ),
+    responses(
+        (status = 200, description = "Deleted books, most recently deleted first", body = Vec<DeletedBookResponse>),
+        (status = 403, description = "Admin access required")
+    ),
+    tag = "Admin",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_deleted_books(
+    req: HttpRequest,
+    query: web::Query<PaginationQuery>,
+    deleted_records_use_case: web::Data<ManageDeletedRecordsUseCase<UserRepository, BookRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match deleted_records_use_case.list_deleted_books(user_id, query.limit, query.offset).await {
+        Ok(books) => Ok(HttpResponse::Ok().json(books)),
+        Err(e) => Ok(error_response(e, "Failed to retrieve deleted books")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/books/{book_id}/restore",
+    params(
+        ("book_id" = Uuid, Path, description = "Book ID")
+    ),
+    responses(
+        (status = 200, description = "Book restored", body = BookResponse),
+        (status = 403, description = "Admin access required"),
+        (status = 404, description = "No deleted book with this ID")
+    ),
+    tag = "Admin",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn restore_book(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    deleted_records_use_case: web::Data<ManageDeletedRecordsUseCase<UserRepository, BookRepository>>,
+) -> ActixResult<HttpResponse> {
+    let book_id = path.into_inner();
+
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match deleted_records_use_case.restore_book(user_id, book_id).await {
+        Ok(book) => Ok(HttpResponse::Ok().json(book)),
+        Err(e) => Ok(error_response(e, "Book restore failed")),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/admin/users/{user_id}",
+    params(
+        ("user_id" = Uuid, Path, description = "User ID")
+    ),
+    responses(
+        (status = 204, description = "User deactivated and deleted, restorable until purged"),
+        (status = 400, description = "Admins cannot delete their own account"),
+        (status = 403, description = "Admin access required"),
+        (status = 404, description = "User not found")
+    ),
+    tag = "Admin",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_user(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    deleted_records_use_case: web::Data<ManageDeletedRecordsUseCase<UserRepository, BookRepository>>,
+) -> ActixResult<HttpResponse> {
+    let target_user_id = path.into_inner();
+
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match deleted_records_use_case.delete_user(user_id, target_user_id).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Ok(error_response(e, "User deletion failed")),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/users/deleted",
+    params(
+        ("limit" = Option<usize>, Query, description = "Limit results"),
+        ("offset" = Option<usize>, Query, description = "Offset for pagination")
+    ),
+    responses(
+        (status = 200, description = "Deleted users, most recently deleted first", body = Vec<DeletedUserResponse>),
+        (status = 403, description = "Admin access required")
+    ),
+    tag = "Admin",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_deleted_users(
+    req: HttpRequest,
+    query: web::Query<PaginationQuery>,
+    deleted_records_use_case: web::Data<ManageDeletedRecordsUseCase<UserRepository, BookRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match deleted_records_use_case.list_deleted_users(user_id, query.limit, query.offset).await {
+        Ok(users) => Ok(HttpResponse::Ok().json(users)),
+        Err(e) => Ok(error_response(e, "Failed to retrieve deleted users")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/users/{user_id}/restore",
+    params(
+        ("user_id" = Uuid, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "User restored and reactivated", body = DeletedUserResponse),
+        (status = 403, description = "Admin access required"),
+        (status = 404, description = "No deleted user with this ID")
+    ),
+    tag = "Admin",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn restore_user(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    deleted_records_use_case: web::Data<ManageDeletedRecordsUseCase<UserRepository, BookRepository>>,
+) -> ActixResult<HttpResponse> {
+    let target_user_id = path.into_inner();
+
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match deleted_records_use_case.restore_user(user_id, target_user_id).await {
+        Ok(user) => Ok(HttpResponse::Ok().json(user)),
+        Err(e) => Ok(error_response(e, "User restore failed")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/purge",
+    responses(
+        (status = 200, description = "Records past the retention window hard-deleted", body = PurgeResponse),
+        (status = 403, description = "Admin access required")
+    ),
+    tag = "Admin",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn purge_deleted_records(
+    req: HttpRequest,
+    purge_use_case: web::Data<PurgeDeletedRecordsUseCase<UserRepository, BookRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match purge_use_case.execute_as_admin(user_id).await {
+        Ok(result) => Ok(HttpResponse::Ok().json(result)),
+        Err(e) => Ok(error_response(e, "Purge failed")),
+    }
+}
@@ -0,0 +1,163 @@
+use actix_web::{web, HttpResponse, Result as ActixResult, HttpRequest};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    application::{
+        dtos::author_dtos::{UpdateAuthorRequest, AddContributorRequest},
+        use_cases::authors::{
+            get_author::GetAuthorUseCase,
+            update_author::UpdateAuthorUseCase,
+            add_book_contributor::AddBookContributorUseCase,
+            backfill_authors::BackfillAuthorsUseCase,
+        },
+    },
+    domain::{
+        repositories::{
+            author_repository::AuthorRepository,
+            book_repository::BookRepository,
+            user_repository::UserRepository,
+        },
+        services::auth_service::Claims,
+        errors::domain_error::DomainError,
+    },
+};
+
+fn error_response(e: anyhow::Error, context: &str) -> HttpResponse {
+    match e.downcast_ref::<DomainError>() {
+        Some(DomainError::Unauthorized) => HttpResponse::Forbidden().json("Insufficient permissions"),
+        Some(DomainError::AuthorNotFound) | Some(DomainError::BookNotFound) => {
+            HttpResponse::NotFound().json(e.to_string())
+        }
+        Some(DomainError::InvalidInput(message)) => HttpResponse::BadRequest().json(message),
+        _ => {
+            tracing::error!("{}: {:?}", context, e);
+            HttpResponse::InternalServerError().json(context)
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/authors/{author_id}",
+    params(
+        ("author_id" = Uuid, Path, description = "Author ID")
+    ),
+    responses(
+        (status = 200, description = "Author page with bibliography", body = AuthorResponse),
+        (status = 404, description = "Author not found")
+    ),
+    tag = "Authors"
+)]
+pub async fn get_author(
+    path: web::Path<Uuid>,
+    get_author_use_case: web::Data<GetAuthorUseCase<AuthorRepository, BookRepository>>,
+) -> ActixResult<HttpResponse> {
+    match get_author_use_case.execute(path.into_inner()).await {
+        Ok(author) => Ok(HttpResponse::Ok().json(author)),
+        Err(e) => Ok(error_response(e, "Failed to retrieve author")),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/authors/{author_id}",
+    params(
+        ("author_id" = Uuid, Path, description = "Author ID")
+    ),
+    request_body = UpdateAuthorRequest,
+    responses(
+        (status = 200, description = "Author updated", body = AuthorSummary),
+        (status = 400, description = "Invalid input"),
+        (status = 403, description = "Curator access required"),
+        (status = 404, description = "Author not found")
+    ),
+    tag = "Authors",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_author(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    author_req: web::Json<UpdateAuthorRequest>,
+    update_author_use_case: web::Data<UpdateAuthorUseCase<UserRepository, AuthorRepository>>,
+) -> ActixResult<HttpResponse> {
+    let author_id = path.into_inner();
+
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    // Validate input
+    if let Err(validation_errors) = author_req.validate() {
+        return Ok(HttpResponse::BadRequest().json(validation_errors));
+    }
+
+    match update_author_use_case.execute(author_id, user_id, author_req.into_inner()).await {
+        Ok(author) => Ok(HttpResponse::Ok().json(author)),
+        Err(e) => Ok(error_response(e, "Author update failed")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/books/{book_id}/contributors",
+    params(
+        ("book_id" = Uuid, Path, description = "Book ID")
+    ),
+    request_body = AddContributorRequest,
+    responses(
+        (status = 201, description = "Contributor added, returns all contributors", body = Vec<ContributorResponse>),
+        (status = 400, description = "Invalid input"),
+        (status = 403, description = "Curator access required"),
+        (status = 404, description = "Book or author not found")
+    ),
+    tag = "Authors",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn add_book_contributor(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    contributor_req: web::Json<AddContributorRequest>,
+    add_contributor_use_case: web::Data<AddBookContributorUseCase<UserRepository, BookRepository, AuthorRepository>>,
+) -> ActixResult<HttpResponse> {
+    let book_id = path.into_inner();
+
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match add_contributor_use_case.execute(book_id, user_id, contributor_req.into_inner()).await {
+        Ok(contributors) => Ok(HttpResponse::Created().json(contributors)),
+        Err(e) => Ok(error_response(e, "Failed to add contributor")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/authors/backfill",
+    responses(
+        (status = 200, description = "Authors and publishers backfilled from book strings", body = AuthorBackfillResponse),
+        (status = 403, description = "Admin access required")
+    ),
+    tag = "Authors",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn backfill_authors(
+    req: HttpRequest,
+    backfill_use_case: web::Data<BackfillAuthorsUseCase<UserRepository, BookRepository, AuthorRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match backfill_use_case.execute(user_id).await {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => Ok(error_response(e, "Author backfill failed")),
+    }
+}
@@ -6,20 +6,53 @@ use uuid::Uuid;
 
 use crate::{
     application::{
-        dtos::book_dtos::{CreateBookRequest, BookSearchQuery, BookRatingRequest},
+        dtos::book_dtos::{
+            CreateBookRequest, BookSearchQuery, BookRatingRequest, PaginationQuery, BookTranslationRequest,
+            CoverUploadResponse, CoverPlaceholderBackfillResponse, CoverDuplicatesQuery, CoverDuplicateResponse,
+            SimilarBooksQuery, SimilarBookResponse,
+        },
+        dtos::trending_dtos::{TrendingQuery, TrendingResponse},
         use_cases::books::{
             create_book::CreateBookUseCase,
             get_books::GetBooksUseCase,
             upload_book_image::UploadBookImageUseCase,
             rate_book::RateBookUseCase,
+            ingest_onix_feed::IngestOnixFeedUseCase,
+            get_book::{GetBookUseCase, BookLookup},
+            book_revisions::{GetBookRevisionsUseCase, RevertBookUseCase},
+            manage_translations::ManageBookTranslationsUseCase,
+            backfill_cover_placeholders::BackfillCoverPlaceholdersUseCase,
+            find_cover_duplicates::FindCoverDuplicatesUseCase,
+            similar_books::GetSimilarBooksUseCase,
+            trending_books::GetTrendingBooksUseCase,
         },
     },
     domain::{
-        repositories::book_repository::BookRepository,
-        services::auth_service::Claims,
+        entities::trending::TrendingWindow,
+        repositories::{
+            book_repository::BookRepository,
+            user_repository::UserRepository,
+            duplicate_repository::DuplicateRepository,
+            author_repository::AuthorRepository,
+            genre_repository::GenreRepository,
+            notification_repository::NotificationRepository,
+            trending_repository::TrendingRepository,
+            recommendation_repository::RecommendationRepository,
+        },
+        services::{auth_service::Claims, localization_service::LocalizationService},
+        errors::domain_error::DomainError,
     },
 };
 
+/// Locales from the `Accept-Language` header, most preferred first
+pub fn preferred_locales(req: &HttpRequest) -> Vec<String> {
+    req.headers()
+        .get(actix_web::http::header::ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+        .map(LocalizationService::parse_accept_language)
+        .unwrap_or_default()
+}
+
 #[utoipa::path(
     post,
     path = "/api/books",
@@ -37,7 +70,7 @@ use crate::{
 pub async fn create_book(
     req: HttpRequest,
     book_req: web::Json<CreateBookRequest>,
-    create_book_use_case: web::Data<CreateBookUseCase<BookRepository>>,
+    create_book_use_case: web::Data<CreateBookUseCase<BookRepository, AuthorRepository, GenreRepository>>,
 ) -> ActixResult<HttpResponse> {
     // Extract user from JWT claims
     let claims = req.extensions().get::<Claims>().unwrap();
@@ -65,7 +98,8 @@ pub async fn create_book(
         ("author" = Option<String>, Query, description = "Filter by author"),
         ("genre" = Option<String>, Query, description = "Filter by genre"),
         ("limit" = Option<usize>, Query, description = "Limit results"),
-        ("offset" = Option<usize>, Query, description = "Offset for pagination")
+        ("offset" = Option<usize>, Query, description = "Offset for pagination"),
+        ("Accept-Language" = Option<String>, Header, description = "Preferred locales for titles and descriptions")
     ),
     responses(
         (status = 200, description = "Books retrieved successfully", body = Vec<BookResponse>)
@@ -73,11 +107,12 @@ pub async fn create_book(
     tag = "Books"
 )]
 pub async fn get_books(
+    req: HttpRequest,
     query: web::Query<BookSearchQuery>,
     get_books_use_case: web::Data<GetBooksUseCase<BookRepository>>,
 ) -> ActixResult<HttpResponse> {
-    match get_books_use_case.execute(query.into_inner()).await {
-        Ok(books) => Ok(HttpResponse::Ok().json(books)),
+    match get_books_use_case.execute(query.into_inner(), &preferred_locales(&req)).await {
+        Ok(books) => Ok(HttpResponse::Ok().insert_header(("Vary", "Accept-Language")).json(books)),
         Err(e) => {
             tracing::error!("Failed to get books: {:?}", e);
             Ok(HttpResponse::InternalServerError().json("Failed to retrieve books"))
@@ -85,6 +120,110 @@ pub async fn get_books(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/books/{book_id}",
+    params(
+        ("book_id" = Uuid, Path, description = "Book ID"),
+        ("Accept-Language" = Option<String>, Header, description = "Preferred locales for title and description")
+    ),
+    responses(
+        (status = 200, description = "Book retrieved successfully, with the caller's reading progress when signed in", body = BookResponse),
+        (status = 301, description = "Book was merged into another book, see Location"),
+        (status = 404, description = "Book not found")
+    ),
+    tag = "Books"
+)]
+pub async fn get_book(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    get_book_use_case: web::Data<GetBookUseCase<BookRepository, DuplicateRepository, RecommendationRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Signed-in callers also get their reading progress
+    let user_id = req.extensions().get::<Claims>().and_then(|claims| Uuid::parse_str(&claims.sub).ok());
+
+    match get_book_use_case.execute(path.into_inner(), user_id, &preferred_locales(&req)).await {
+        Ok(BookLookup::Found(book)) => {
+            let mut response = HttpResponse::Ok();
+            response.insert_header(("Vary", "Accept-Language"));
+            if let Some(locale) = &book.locale {
+                response.insert_header(("Content-Language", locale.as_str()));
+            }
+            Ok(response.json(book))
+        }
+        Ok(BookLookup::MovedTo(book_id)) => Ok(HttpResponse::MovedPermanently()
+            .insert_header(("Location", format!("/api/books/{}", book_id)))
+            .finish()),
+        Err(e) => match e.downcast_ref::<DomainError>() {
+            Some(DomainError::BookNotFound) => Ok(HttpResponse::NotFound().json("Book not found")),
+            _ => {
+                tracing::error!("Failed to get book: {:?}", e);
+                Ok(HttpResponse::InternalServerError().json("Failed to retrieve book"))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/books/{book_id}/similar",
+    params(
+        ("book_id" = Uuid, Path, description = "Book ID"),
+        ("limit" = Option<usize>, Query, description = "Limit results, at most 50 (default 10)"),
+        ("Accept-Language" = Option<String>, Header, description = "Preferred locales for title and description")
+    ),
+    responses(
+        (status = 200, description = "Most similar books first, with their scores and why they match", body = Vec<SimilarBookResponse>),
+        (status = 404, description = "Book not found")
+    ),
+    tag = "Books"
+)]
+pub async fn get_similar_books(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    query: web::Query<SimilarBooksQuery>,
+    get_similar_books_use_case: web::Data<GetSimilarBooksUseCase<BookRepository>>,
+) -> ActixResult<HttpResponse> {
+    match get_similar_books_use_case.execute(path.into_inner(), query.limit, &preferred_locales(&req)).await {
+        Ok(similar) => Ok(HttpResponse::Ok().insert_header(("Vary", "Accept-Language")).json(similar)),
+        Err(e) => match e.downcast_ref::<DomainError>() {
+            Some(DomainError::BookNotFound) => Ok(HttpResponse::NotFound().json("Book not found")),
+            _ => {
+                tracing::error!("Failed to find similar books: {:?}", e);
+                Ok(HttpResponse::InternalServerError().json("Failed to retrieve similar books"))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/books/trending",
+    params(
+        ("window" = Option<TrendingWindow>, Query, description = "Trending window: day, week or month (default week)"),
+        ("limit" = Option<usize>, Query, description = "Books per list, at most 50 (default 20)"),
+        ("Accept-Language" = Option<String>, Header, description = "Preferred locales for title and description")
+    ),
+    responses(
+        (status = 200, description = "Trending and new and notable books as of the last refresh", body = TrendingResponse)
+    ),
+    tag = "Books"
+)]
+pub async fn get_trending_books(
+    req: HttpRequest,
+    query: web::Query<TrendingQuery>,
+    get_trending_books_use_case: web::Data<GetTrendingBooksUseCase<BookRepository, TrendingRepository>>,
+) -> ActixResult<HttpResponse> {
+    let query = query.into_inner();
+    match get_trending_books_use_case.execute(query.window, query.limit, &preferred_locales(&req)).await {
+        Ok(trending) => Ok(HttpResponse::Ok().insert_header(("Vary", "Accept-Language")).json(trending)),
+        Err(e) => {
+            tracing::error!("Failed to get trending books: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json("Failed to retrieve trending books"))
+        }
+    }
+}
+
 #[utoipa::path(
     post,
     path = "/api/books/{book_id}/upload-image",
@@ -93,9 +232,13 @@ pub async fn get_books(
     ),
     request_body(content = String, description = "Image file", content_type = "multipart/form-data"),
     responses(
-        (status = 200, description = "Image uploaded successfully"),
+        (status = 200, description = "Cover processed and stored in every size, with books whose cover looks the same", body = CoverUploadResponse),
+        (status = 400, description = "Empty or unreadable image, or dimensions out of range"),
         (status = 401, description = "Unauthorized"),
-        (status = 404, description = "Book not found")
+        (status = 403, description = "Only the book's creator can upload a cover"),
+        (status = 404, description = "Book not found"),
+        (status = 413, description = "Image exceeds the upload size limit"),
+        (status = 415, description = "Not a JPEG, PNG or WebP image")
     ),
     tag = "Books",
     security(
@@ -114,28 +257,37 @@ pub async fn upload_book_image(
     let claims = req.extensions().get::<Claims>().unwrap();
     let user_id = Uuid::parse_str(&claims.sub).unwrap();
 
-    // Extract file from multipart
-    while let Some(mut field) = payload.next().await {
-        let field = field?;
+    // Extract file from multipart, giving up as soon as it exceeds the size limit
+    let max_bytes = upload_use_case.max_upload_bytes();
+    while let Some(field) = payload.next().await {
+        let mut field = field?;
         let content_disposition = field.content_disposition();
         
-        if let Some(filename) = content_disposition.get_filename() {
+        if content_disposition.get_filename().is_some() {
             let mut file_data = Vec::new();
             while let Some(chunk) = field.next().await {
                 let data = chunk?;
+                if file_data.len() + data.len() > max_bytes {
+                    return Ok(HttpResponse::PayloadTooLarge()
+                        .json(format!("Cover images are limited to {} bytes", max_bytes)));
+                }
                 file_data.extend_from_slice(&data);
             }
 
-            match upload_use_case.execute(book_id, user_id, file_data, filename).await {
-                Ok(image_url) => return Ok(HttpResponse::Ok().json(serde_json::json!({
-                    "message": "Image uploaded successfully",
-                    "image_url": image_url
-                }))),
-                Err(e) => {
-                    tracing::error!("Image upload failed: {:?}", e);
-                    return Ok(HttpResponse::InternalServerError().json("Image upload failed"));
-                }
-            }
+            return match upload_use_case.execute(book_id, user_id, file_data).await {
+                Ok(upload) => Ok(HttpResponse::Ok().json(upload)),
+                Err(e) => match e.downcast_ref::<DomainError>() {
+                    Some(DomainError::BookNotFound) => Ok(HttpResponse::NotFound().json("Book not found")),
+                    Some(DomainError::Unauthorized) => Ok(HttpResponse::Forbidden().json("Only the book's creator can upload a cover")),
+                    Some(DomainError::InvalidInput(msg)) => Ok(HttpResponse::BadRequest().json(msg)),
+                    Some(DomainError::PayloadTooLarge(msg)) => Ok(HttpResponse::PayloadTooLarge().json(msg)),
+                    Some(DomainError::UnsupportedMediaType(msg)) => Ok(HttpResponse::UnsupportedMediaType().json(msg)),
+                    _ => {
+                        tracing::error!("Image upload failed: {:?}", e);
+                        Ok(HttpResponse::InternalServerError().json("Image upload failed"))
+                    }
+                },
+            };
         }
     }
 
@@ -153,7 +305,8 @@ pub async fn upload_book_image(
         (status = 201, description = "Rating added successfully", body = BookRatingResponse),
         (status = 400, description = "Invalid input"),
         (status = 401, description = "Unauthorized"),
-        (status = 404, description = "Book not found")
+        (status = 404, description = "Book not found"),
+        (status = 409, description = "Already rated, use PUT to change the rating")
     ),
     tag = "Books",
     security(
@@ -164,7 +317,7 @@ pub async fn rate_book(
     req: HttpRequest,
     path: web::Path<Uuid>,
     rating_req: web::Json<BookRatingRequest>,
-    rate_book_use_case: web::Data<RateBookUseCase<BookRepository>>,
+    rate_book_use_case: web::Data<RateBookUseCase<BookRepository, NotificationRepository>>,
 ) -> ActixResult<HttpResponse> {
     let book_id = path.into_inner();
     
@@ -179,9 +332,296 @@ pub async fn rate_book(
 
     match rate_book_use_case.execute(book_id, user_id, rating_req.into_inner()).await {
         Ok(response) => Ok(HttpResponse::Created().json(response)),
-        Err(e) => {
-            tracing::error!("Rating failed: {:?}", e);
-            Ok(HttpResponse::InternalServerError().json("Rating failed"))
+        Err(e) => match e.downcast_ref::<DomainError>() {
+            Some(DomainError::BookNotFound) => Ok(HttpResponse::NotFound().json("Book not found")),
+            Some(DomainError::InvalidInput(message)) => Ok(HttpResponse::Conflict().json(message)),
+            _ => {
+                tracing::error!("Rating failed: {:?}", e);
+                Ok(HttpResponse::InternalServerError().json("Rating failed"))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/books/onix",
+    request_body(content = String, description = "ONIX 3.0 reference-tag message", content_type = "application/xml"),
+    responses(
+        (status = 200, description = "Feed ingested", body = OnixIngestResponse),
+        (status = 400, description = "Invalid ONIX feed"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Curator access required")
+    ),
+    tag = "Books",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn ingest_onix_feed(
+    req: HttpRequest,
+    body: web::Bytes,
+    ingest_use_case: web::Data<IngestOnixFeedUseCase<BookRepository, UserRepository, AuthorRepository, GenreRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    let xml = match std::str::from_utf8(&body) {
+        Ok(xml) => xml,
+        Err(_) => return Ok(HttpResponse::BadRequest().json("ONIX feed must be UTF-8 encoded")),
+    };
+
+    match ingest_use_case.execute(xml, user_id).await {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => match e.downcast_ref::<DomainError>() {
+            Some(DomainError::Unauthorized) => Ok(HttpResponse::Forbidden().json("Insufficient permissions")),
+            Some(DomainError::InvalidInput(message)) => Ok(HttpResponse::BadRequest().json(message)),
+            _ => {
+                tracing::error!("ONIX ingestion failed: {:?}", e);
+                Ok(HttpResponse::InternalServerError().json("ONIX ingestion failed"))
+            }
+        },
+    }
+}
+#[utoipa::path(
+    get,
+    path = "/api/books/{book_id}/revisions",
+    params(
+        ("book_id" = Uuid, Path, description = "Book ID"),
+        ("limit" = Option<usize>, Query, description = "Limit results"),
+        ("offset" = Option<usize>, Query, description = "Offset for pagination")
+    ),
+    responses(
+        (status = 200, description = "Edit history, newest revision first", body = Vec<BookRevisionResponse>),
+        (status = 404, description = "Book not found")
+    ),
+    tag = "Books"
+)]
+pub async fn get_book_revisions(
+    path: web::Path<Uuid>,
+    query: web::Query<PaginationQuery>,
+    get_revisions_use_case: web::Data<GetBookRevisionsUseCase<BookRepository>>,
+) -> ActixResult<HttpResponse> {
+    match get_revisions_use_case.execute(path.into_inner(), query.limit, query.offset).await {
+        Ok(revisions) => Ok(HttpResponse::Ok().json(revisions)),
+        Err(e) => match e.downcast_ref::<DomainError>() {
+            Some(DomainError::BookNotFound) => Ok(HttpResponse::NotFound().json("Book not found")),
+            _ => {
+                tracing::error!("Failed to get book revisions: {:?}", e);
+                Ok(HttpResponse::InternalServerError().json("Failed to retrieve revisions"))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/books/{book_id}/revisions/{revision_number}/revert",
+    params(
+        ("book_id" = Uuid, Path, description = "Book ID"),
+        ("revision_number" = i32, Path, description = "Revision to restore")
+    ),
+    responses(
+        (status = 200, description = "Book restored to the revision, recorded as a new revision", body = BookResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Curator access required"),
+        (status = 404, description = "Book or revision not found")
+    ),
+    tag = "Books",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn revert_book(
+    req: HttpRequest,
+    path: web::Path<(Uuid, i32)>,
+    revert_use_case: web::Data<RevertBookUseCase<UserRepository, BookRepository>>,
+) -> ActixResult<HttpResponse> {
+    let (book_id, revision_number) = path.into_inner();
+
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match revert_use_case.execute(book_id, revision_number, user_id).await {
+        Ok(book) => Ok(HttpResponse::Ok().json(book)),
+        Err(e) => match e.downcast_ref::<DomainError>() {
+            Some(DomainError::Unauthorized) => Ok(HttpResponse::Forbidden().json("Curator access required")),
+            Some(DomainError::BookNotFound) | Some(DomainError::RevisionNotFound) => {
+                Ok(HttpResponse::NotFound().json(e.to_string()))
+            }
+            _ => {
+                tracing::error!("Failed to revert book: {:?}", e);
+                Ok(HttpResponse::InternalServerError().json("Book revert failed"))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/books/{book_id}/translations/{locale}",
+    params(
+        ("book_id" = Uuid, Path, description = "Book ID"),
+        ("locale" = String, Path, description = "BCP 47 locale, e.g. pt-BR")
+    ),
+    request_body = BookTranslationRequest,
+    responses(
+        (status = 200, description = "Translation saved", body = BookResponse),
+        (status = 400, description = "Invalid input or locale"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only the book's creator or a curator can edit translations"),
+        (status = 404, description = "Book not found")
+    ),
+    tag = "Books",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn upsert_book_translation(
+    req: HttpRequest,
+    path: web::Path<(Uuid, String)>,
+    request: web::Json<BookTranslationRequest>,
+    translations_use_case: web::Data<ManageBookTranslationsUseCase<UserRepository, BookRepository>>,
+) -> ActixResult<HttpResponse> {
+    let (book_id, locale) = path.into_inner();
+
+    // Validate request
+    if let Err(validation_errors) = request.validate() {
+        return Ok(HttpResponse::BadRequest().json(validation_errors));
+    }
+
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match translations_use_case.upsert(book_id, &locale, user_id, request.into_inner()).await {
+        Ok(book) => Ok(HttpResponse::Ok().json(book)),
+        Err(e) => translation_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/books/{book_id}/translations/{locale}",
+    params(
+        ("book_id" = Uuid, Path, description = "Book ID"),
+        ("locale" = String, Path, description = "BCP 47 locale, e.g. pt-BR")
+    ),
+    responses(
+        (status = 200, description = "Translation removed", body = BookResponse),
+        (status = 400, description = "Invalid locale or no translation for it"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only the book's creator or a curator can edit translations"),
+        (status = 404, description = "Book not found")
+    ),
+    tag = "Books",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_book_translation(
+    req: HttpRequest,
+    path: web::Path<(Uuid, String)>,
+    translations_use_case: web::Data<ManageBookTranslationsUseCase<UserRepository, BookRepository>>,
+) -> ActixResult<HttpResponse> {
+    let (book_id, locale) = path.into_inner();
+
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match translations_use_case.remove(book_id, &locale, user_id).await {
+        Ok(book) => Ok(HttpResponse::Ok().json(book)),
+        Err(e) => translation_error_response(e),
+    }
+}
+
+fn translation_error_response(e: anyhow::Error) -> ActixResult<HttpResponse> {
+    match e.downcast_ref::<DomainError>() {
+        Some(DomainError::Unauthorized) => {
+            Ok(HttpResponse::Forbidden().json("Only the book's creator or a curator can edit translations"))
+        }
+        Some(DomainError::BookNotFound) => Ok(HttpResponse::NotFound().json("Book not found")),
+        Some(DomainError::InvalidInput(msg)) => Ok(HttpResponse::BadRequest().json(msg)),
+        _ => {
+            tracing::error!("Failed to update book translations: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json("Translation update failed"))
         }
     }
-}
\ No newline at end of file
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/covers/backfill-placeholders",
+    responses(
+        (status = 200, description = "Placeholders computed for stored covers that lacked one", body = CoverPlaceholderBackfillResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Admin access required")
+    ),
+    tag = "Books",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn backfill_cover_placeholders(
+    req: HttpRequest,
+    backfill_use_case: web::Data<BackfillCoverPlaceholdersUseCase<UserRepository, BookRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match backfill_use_case.execute_as_admin(user_id).await {
+        Ok(result) => Ok(HttpResponse::Ok().json(result)),
+        Err(e) => match e.downcast_ref::<DomainError>() {
+            Some(DomainError::Unauthorized) => Ok(HttpResponse::Forbidden().json("Admin access required")),
+            _ => {
+                tracing::error!("Cover placeholder backfill failed: {:?}", e);
+                Ok(HttpResponse::InternalServerError().json("Cover placeholder backfill failed"))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/covers/duplicates",
+    params(
+        ("max_distance" = Option<u32>, Query, description = "Differing hash bits still counted as the same cover (default 6, at most 16)"),
+        ("limit" = Option<usize>, Query, description = "Limit results"),
+        ("offset" = Option<usize>, Query, description = "Offset for pagination")
+    ),
+    responses(
+        (status = 200, description = "Pairs of books with near-identical covers, closest first", body = Vec<CoverDuplicateResponse>),
+        (status = 400, description = "max_distance out of range"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Curator access required")
+    ),
+    tag = "Books",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_cover_duplicates(
+    req: HttpRequest,
+    query: web::Query<CoverDuplicatesQuery>,
+    cover_duplicates_use_case: web::Data<FindCoverDuplicatesUseCase<UserRepository, BookRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match cover_duplicates_use_case.execute(user_id, query.max_distance, query.limit, query.offset).await {
+        Ok(pairs) => Ok(HttpResponse::Ok().json(pairs)),
+        Err(e) => match e.downcast_ref::<DomainError>() {
+            Some(DomainError::Unauthorized) => Ok(HttpResponse::Forbidden().json("Curator access required")),
+            Some(DomainError::InvalidInput(msg)) => Ok(HttpResponse::BadRequest().json(msg)),
+            _ => {
+                tracing::error!("Failed to find duplicate covers: {:?}", e);
+                Ok(HttpResponse::InternalServerError().json("Failed to find duplicate covers"))
+            }
+        },
+    }
+}
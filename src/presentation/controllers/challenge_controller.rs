@@ -0,0 +1,253 @@
+use actix_web::{web, HttpResponse, Result as ActixResult, HttpRequest};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    application::{
+        dtos::challenge_dtos::{
+            CreateChallengeRequest, ChallengeQuery, LeaderboardQuery, ChallengeResponse, LeaderboardResponse,
+        },
+        use_cases::challenges::manage_challenges::ManageChallengesUseCase,
+    },
+    domain::{
+        repositories::{
+            user_repository::UserRepository,
+            book_repository::BookRepository,
+            shelf_repository::ShelfRepository,
+            challenge_repository::ChallengeRepository,
+        },
+        services::auth_service::Claims,
+        errors::domain_error::DomainError,
+    },
+};
+
+fn error_response(e: anyhow::Error, context: &str) -> HttpResponse {
+    match e.downcast_ref::<DomainError>() {
+        Some(DomainError::Unauthorized) => HttpResponse::Forbidden().json("Insufficient permissions"),
+        Some(DomainError::ChallengeNotFound) => HttpResponse::NotFound().json(e.to_string()),
+        Some(DomainError::ChallengeAlreadyJoined) => HttpResponse::Conflict().json(e.to_string()),
+        Some(DomainError::InvalidInput(message)) => HttpResponse::BadRequest().json(message),
+        _ => {
+            tracing::error!("{}: {:?}", context, e);
+            HttpResponse::InternalServerError().json(context)
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/challenges",
+    params(
+        ("active" = Option<bool>, Query, description = "Only challenges running now")
+    ),
+    responses(
+        (status = 200, description = "Challenges, newest first, with the caller's progress in those they joined", body = Vec<ChallengeResponse>),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "Challenges",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_challenges(
+    req: HttpRequest,
+    query: web::Query<ChallengeQuery>,
+    challenges_use_case: web::Data<ManageChallengesUseCase<UserRepository, BookRepository, ShelfRepository, ChallengeRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match challenges_use_case.list(user_id, query.active).await {
+        Ok(challenges) => Ok(HttpResponse::Ok().json(challenges)),
+        Err(e) => Ok(error_response(e, "Failed to retrieve challenges")),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/challenges/{challenge_id}",
+    params(
+        ("challenge_id" = Uuid, Path, description = "Challenge ID")
+    ),
+    responses(
+        (status = 200, description = "Challenge with the caller's progress, if they joined", body = ChallengeResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Challenge not found")
+    ),
+    tag = "Challenges",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_challenge(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    challenges_use_case: web::Data<ManageChallengesUseCase<UserRepository, BookRepository, ShelfRepository, ChallengeRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match challenges_use_case.get(user_id, path.into_inner()).await {
+        Ok(challenge) => Ok(HttpResponse::Ok().json(challenge)),
+        Err(e) => Ok(error_response(e, "Failed to retrieve challenge")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/challenges/{challenge_id}/join",
+    params(
+        ("challenge_id" = Uuid, Path, description = "Challenge ID")
+    ),
+    responses(
+        (status = 200, description = "Joined; books finished since the challenge started count", body = ChallengeResponse),
+        (status = 400, description = "Challenge has ended"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Challenge not found"),
+        (status = 409, description = "Already joined")
+    ),
+    tag = "Challenges",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn join_challenge(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    challenges_use_case: web::Data<ManageChallengesUseCase<UserRepository, BookRepository, ShelfRepository, ChallengeRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match challenges_use_case.join(user_id, path.into_inner()).await {
+        Ok(challenge) => Ok(HttpResponse::Ok().json(challenge)),
+        Err(e) => Ok(error_response(e, "Failed to join challenge")),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/challenges/{challenge_id}/join",
+    params(
+        ("challenge_id" = Uuid, Path, description = "Challenge ID")
+    ),
+    responses(
+        (status = 204, description = "Left the challenge"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Challenge not found")
+    ),
+    tag = "Challenges",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn leave_challenge(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    challenges_use_case: web::Data<ManageChallengesUseCase<UserRepository, BookRepository, ShelfRepository, ChallengeRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match challenges_use_case.leave(user_id, path.into_inner()).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Ok(error_response(e, "Failed to leave challenge")),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/challenges/{challenge_id}/leaderboard",
+    params(
+        ("challenge_id" = Uuid, Path, description = "Challenge ID"),
+        ("limit" = Option<usize>, Query, description = "Number of entries (default 50, max 100)")
+    ),
+    responses(
+        (status = 200, description = "Participants ranked by progress, then books finished; ties share a rank", body = LeaderboardResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Challenge not found")
+    ),
+    tag = "Challenges",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_challenge_leaderboard(
+    path: web::Path<Uuid>,
+    query: web::Query<LeaderboardQuery>,
+    challenges_use_case: web::Data<ManageChallengesUseCase<UserRepository, BookRepository, ShelfRepository, ChallengeRepository>>,
+) -> ActixResult<HttpResponse> {
+    match challenges_use_case.leaderboard(path.into_inner(), query.limit).await {
+        Ok(leaderboard) => Ok(HttpResponse::Ok().json(leaderboard)),
+        Err(e) => Ok(error_response(e, "Failed to retrieve leaderboard")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/challenges",
+    request_body = CreateChallengeRequest,
+    responses(
+        (status = 201, description = "Challenge created", body = ChallengeResponse),
+        (status = 400, description = "Invalid name, rules or dates"),
+        (status = 403, description = "Admin access required")
+    ),
+    tag = "Challenges",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_challenge(
+    req: HttpRequest,
+    challenge_req: web::Json<CreateChallengeRequest>,
+    challenges_use_case: web::Data<ManageChallengesUseCase<UserRepository, BookRepository, ShelfRepository, ChallengeRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    // Validate input
+    if let Err(validation_errors) = challenge_req.validate() {
+        return Ok(HttpResponse::BadRequest().json(validation_errors));
+    }
+
+    match challenges_use_case.create(user_id, challenge_req.into_inner()).await {
+        Ok(challenge) => Ok(HttpResponse::Created().json(challenge)),
+        Err(e) => Ok(error_response(e, "Challenge creation failed")),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/admin/challenges/{challenge_id}",
+    params(
+        ("challenge_id" = Uuid, Path, description = "Challenge ID")
+    ),
+    responses(
+        (status = 204, description = "Challenge and its participants deleted"),
+        (status = 403, description = "Admin access required"),
+        (status = 404, description = "Challenge not found")
+    ),
+    tag = "Challenges",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_challenge(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    challenges_use_case: web::Data<ManageChallengesUseCase<UserRepository, BookRepository, ShelfRepository, ChallengeRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match challenges_use_case.delete(user_id, path.into_inner()).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Ok(error_response(e, "Challenge deletion failed")),
+    }
+}
@@ -0,0 +1,165 @@
+use actix_web::{web, HttpResponse, Result as ActixResult, HttpRequest};
+use uuid::Uuid;
+
+use crate::{
+    application::{
+        dtos::book_dtos::{MergeBooksRequest, PaginationQuery},
+        use_cases::books::{
+            detect_duplicate_books::DetectDuplicateBooksUseCase,
+            review_duplicates::ReviewDuplicatesUseCase,
+        },
+    },
+    domain::{
+        repositories::{
+            book_repository::BookRepository,
+            duplicate_repository::DuplicateRepository,
+            user_repository::UserRepository,
+        },
+        services::auth_service::Claims,
+        errors::domain_error::DomainError,
+    },
+};
+
+fn error_response(e: anyhow::Error, context: &str) -> HttpResponse {
+    match e.downcast_ref::<DomainError>() {
+        Some(DomainError::Unauthorized) => HttpResponse::Forbidden().json("Admin access required"),
+        Some(DomainError::BookNotFound) | Some(DomainError::DuplicateCandidateNotFound) => {
+            HttpResponse::NotFound().json(e.to_string())
+        }
+        Some(DomainError::InvalidInput(message)) => HttpResponse::BadRequest().json(message),
+        _ => {
+            tracing::error!("{}: {:?}", context, e);
+            HttpResponse::InternalServerError().json(context)
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/duplicates/scan",
+    responses(
+        (status = 200, description = "Catalog scanned for duplicates", body = DuplicateScanResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Admin access required")
+    ),
+    tag = "Duplicates",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn scan_duplicates(
+    req: HttpRequest,
+    detect_use_case: web::Data<DetectDuplicateBooksUseCase<UserRepository, BookRepository, DuplicateRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match detect_use_case.execute(user_id).await {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => Ok(error_response(e, "Duplicate scan failed")),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/duplicates",
+    params(
+        ("limit" = Option<usize>, Query, description = "Limit results"),
+        ("offset" = Option<usize>, Query, description = "Offset for pagination")
+    ),
+    responses(
+        (status = 200, description = "Pending duplicate candidates", body = Vec<DuplicateCandidateResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Admin access required")
+    ),
+    tag = "Duplicates",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_duplicates(
+    req: HttpRequest,
+    query: web::Query<PaginationQuery>,
+    review_use_case: web::Data<ReviewDuplicatesUseCase<UserRepository, BookRepository, DuplicateRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match review_use_case.list(user_id, query.limit, query.offset).await {
+        Ok(candidates) => Ok(HttpResponse::Ok().json(candidates)),
+        Err(e) => Ok(error_response(e, "Failed to list duplicate candidates")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/duplicates/{candidate_id}/merge",
+    params(
+        ("candidate_id" = Uuid, Path, description = "Duplicate candidate ID")
+    ),
+    request_body = MergeBooksRequest,
+    responses(
+        (status = 200, description = "Books merged", body = MergeBooksResponse),
+        (status = 400, description = "Invalid input"),
+        (status = 403, description = "Admin access required"),
+        (status = 404, description = "Candidate or book not found")
+    ),
+    tag = "Duplicates",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn merge_duplicate(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    merge_req: web::Json<MergeBooksRequest>,
+    review_use_case: web::Data<ReviewDuplicatesUseCase<UserRepository, BookRepository, DuplicateRepository>>,
+) -> ActixResult<HttpResponse> {
+    let candidate_id = path.into_inner();
+
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match review_use_case.merge(user_id, candidate_id, merge_req.surviving_book_id).await {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => Ok(error_response(e, "Merge failed")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/duplicates/{candidate_id}/dismiss",
+    params(
+        ("candidate_id" = Uuid, Path, description = "Duplicate candidate ID")
+    ),
+    responses(
+        (status = 200, description = "Candidate dismissed"),
+        (status = 403, description = "Admin access required"),
+        (status = 404, description = "Candidate not found")
+    ),
+    tag = "Duplicates",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn dismiss_duplicate(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    review_use_case: web::Data<ReviewDuplicatesUseCase<UserRepository, BookRepository, DuplicateRepository>>,
+) -> ActixResult<HttpResponse> {
+    let candidate_id = path.into_inner();
+
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match review_use_case.dismiss(user_id, candidate_id).await {
+        Ok(_) => Ok(HttpResponse::Ok().json(serde_json::json!({
+            "message": "Candidate dismissed"
+        }))),
+        Err(e) => Ok(error_response(e, "Dismiss failed")),
+    }
+}
@@ -0,0 +1,156 @@
+use actix_web::{web, HttpResponse, Result as ActixResult, HttpRequest};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    application::{
+        dtos::genre_dtos::{CreateGenreRequest, UpdateGenreRequest},
+        use_cases::genres::{
+            manage_genres::ManageGenresUseCase,
+            get_genre_tree::GetGenreTreeUseCase,
+        },
+    },
+    domain::{
+        repositories::{genre_repository::GenreRepository, user_repository::UserRepository},
+        services::auth_service::Claims,
+        errors::domain_error::DomainError,
+    },
+};
+
+fn error_response(e: anyhow::Error, context: &str) -> HttpResponse {
+    match e.downcast_ref::<DomainError>() {
+        Some(DomainError::Unauthorized) => HttpResponse::Forbidden().json("Insufficient permissions"),
+        Some(DomainError::GenreNotFound) => HttpResponse::NotFound().json(e.to_string()),
+        Some(DomainError::InvalidInput(message)) => HttpResponse::BadRequest().json(message),
+        _ => {
+            tracing::error!("{}: {:?}", context, e);
+            HttpResponse::InternalServerError().json(context)
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/genres",
+    responses(
+        (status = 200, description = "Genre tree, top-level genres first", body = Vec<GenreNode>)
+    ),
+    tag = "Genres"
+)]
+pub async fn get_genre_tree(
+    get_genre_tree_use_case: web::Data<GetGenreTreeUseCase<GenreRepository>>,
+) -> ActixResult<HttpResponse> {
+    match get_genre_tree_use_case.execute().await {
+        Ok(tree) => Ok(HttpResponse::Ok().json(tree)),
+        Err(e) => Ok(error_response(e, "Failed to retrieve genres")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/genres",
+    request_body = CreateGenreRequest,
+    responses(
+        (status = 201, description = "Genre created", body = GenreResponse),
+        (status = 400, description = "Invalid input, slug or synonym clash, or cyclic parent"),
+        (status = 403, description = "Admin access required")
+    ),
+    tag = "Genres",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_genre(
+    req: HttpRequest,
+    genre_req: web::Json<CreateGenreRequest>,
+    manage_genres_use_case: web::Data<ManageGenresUseCase<UserRepository, GenreRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    // Validate input
+    if let Err(validation_errors) = genre_req.validate() {
+        return Ok(HttpResponse::BadRequest().json(validation_errors));
+    }
+
+    match manage_genres_use_case.create(user_id, genre_req.into_inner()).await {
+        Ok(genre) => Ok(HttpResponse::Created().json(genre)),
+        Err(e) => Ok(error_response(e, "Genre creation failed")),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/admin/genres/{genre_id}",
+    params(
+        ("genre_id" = Uuid, Path, description = "Genre ID")
+    ),
+    request_body = UpdateGenreRequest,
+    responses(
+        (status = 200, description = "Genre updated", body = GenreResponse),
+        (status = 400, description = "Invalid input, slug or synonym clash, or cyclic parent"),
+        (status = 403, description = "Admin access required"),
+        (status = 404, description = "Genre not found")
+    ),
+    tag = "Genres",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_genre(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    genre_req: web::Json<UpdateGenreRequest>,
+    manage_genres_use_case: web::Data<ManageGenresUseCase<UserRepository, GenreRepository>>,
+) -> ActixResult<HttpResponse> {
+    let genre_id = path.into_inner();
+
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    // Validate input
+    if let Err(validation_errors) = genre_req.validate() {
+        return Ok(HttpResponse::BadRequest().json(validation_errors));
+    }
+
+    match manage_genres_use_case.update(user_id, genre_id, genre_req.into_inner()).await {
+        Ok(genre) => Ok(HttpResponse::Ok().json(genre)),
+        Err(e) => Ok(error_response(e, "Genre update failed")),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/admin/genres/{genre_id}",
+    params(
+        ("genre_id" = Uuid, Path, description = "Genre ID")
+    ),
+    responses(
+        (status = 204, description = "Genre deleted"),
+        (status = 400, description = "Genre still has child genres"),
+        (status = 403, description = "Admin access required"),
+        (status = 404, description = "Genre not found")
+    ),
+    tag = "Genres",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_genre(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    manage_genres_use_case: web::Data<ManageGenresUseCase<UserRepository, GenreRepository>>,
+) -> ActixResult<HttpResponse> {
+    let genre_id = path.into_inner();
+
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match manage_genres_use_case.delete(user_id, genre_id).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Ok(error_response(e, "Genre deletion failed")),
+    }
+}
@@ -0,0 +1,138 @@
+use actix_web::{web, HttpResponse, Result as ActixResult, HttpRequest};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    application::{
+        dtos::goal_dtos::{UpsertGoalRequest, GoalQuery, GoalResponse},
+        use_cases::goals::manage_goals::ManageGoalsUseCase,
+    },
+    domain::{
+        repositories::{
+            book_repository::BookRepository,
+            recommendation_repository::RecommendationRepository,
+            shelf_repository::ShelfRepository,
+            goal_repository::GoalRepository,
+        },
+        services::auth_service::Claims,
+        errors::domain_error::DomainError,
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/api/me/goals",
+    params(
+        ("year" = Option<i32>, Query, description = "Only goals for this year"),
+        ("tz" = Option<String>, Query, description = "IANA time zone the goal periods follow; defaults to UTC")
+    ),
+    responses(
+        (status = 200, description = "The caller's reading goals with progress and pace", body = Vec<GoalResponse>),
+        (status = 400, description = "Unknown time zone"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "Goals",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_my_goals(
+    req: HttpRequest,
+    query: web::Query<GoalQuery>,
+    goals_use_case: web::Data<ManageGoalsUseCase<BookRepository, RecommendationRepository, ShelfRepository, GoalRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match goals_use_case.list(user_id, query.year, query.tz.as_deref()).await {
+        Ok(goals) => Ok(HttpResponse::Ok().json(goals)),
+        Err(e) => match e.downcast_ref::<DomainError>() {
+            Some(DomainError::InvalidInput(message)) => Ok(HttpResponse::BadRequest().json(message)),
+            _ => {
+                tracing::error!("Failed to get goals: {:?}", e);
+                Ok(HttpResponse::InternalServerError().json("Failed to retrieve goals"))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/me/goals",
+    request_body = UpsertGoalRequest,
+    params(
+        ("tz" = Option<String>, Query, description = "IANA time zone the goal period follows; defaults to UTC")
+    ),
+    responses(
+        (status = 200, description = "Goal set, replacing any goal for the same metric and period", body = GoalResponse),
+        (status = 400, description = "Invalid target, year, month or time zone"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "Goals",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn upsert_goal(
+    req: HttpRequest,
+    goal_req: web::Json<UpsertGoalRequest>,
+    query: web::Query<GoalQuery>,
+    goals_use_case: web::Data<ManageGoalsUseCase<BookRepository, RecommendationRepository, ShelfRepository, GoalRepository>>,
+) -> ActixResult<HttpResponse> {
+    if let Err(validation_errors) = goal_req.validate() {
+        return Ok(HttpResponse::BadRequest().json(validation_errors));
+    }
+
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match goals_use_case.upsert(user_id, goal_req.into_inner(), query.tz.as_deref()).await {
+        Ok(goal) => Ok(HttpResponse::Ok().json(goal)),
+        Err(e) => match e.downcast_ref::<DomainError>() {
+            Some(DomainError::InvalidInput(message)) => Ok(HttpResponse::BadRequest().json(message)),
+            _ => {
+                tracing::error!("Failed to set goal: {:?}", e);
+                Ok(HttpResponse::InternalServerError().json("Failed to set goal"))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/me/goals/{goal_id}",
+    params(
+        ("goal_id" = Uuid, Path, description = "Goal ID")
+    ),
+    responses(
+        (status = 204, description = "Goal deleted"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Goal not found")
+    ),
+    tag = "Goals",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_goal(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    goals_use_case: web::Data<ManageGoalsUseCase<BookRepository, RecommendationRepository, ShelfRepository, GoalRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match goals_use_case.delete(user_id, path.into_inner()).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => match e.downcast_ref::<DomainError>() {
+            Some(DomainError::GoalNotFound) => Ok(HttpResponse::NotFound().json("Goal not found")),
+            _ => {
+                tracing::error!("Failed to delete goal: {:?}", e);
+                Ok(HttpResponse::InternalServerError().json("Failed to delete goal"))
+            }
+        },
+    }
+}
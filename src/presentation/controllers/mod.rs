@@ -3,7 +3,33 @@
 pub mod auth_controller;
 pub mod book_controller;
 pub mod recommendation_controller;
+pub mod duplicate_controller;
+pub mod work_controller;
+pub mod author_controller;
+pub mod genre_controller;
+pub mod tag_controller;
+pub mod admin_controller;
+pub mod review_controller;
+pub mod notification_controller;
+pub mod reading_session_controller;
+pub mod shelf_controller;
+pub mod goal_controller;
+pub mod challenge_controller;
+pub mod stats_controller;
 
 pub use auth_controller::*;
 pub use book_controller::*;
-pub use recommendation_controller::*;
\ No newline at end of file
+pub use recommendation_controller::*;
+pub use duplicate_controller::*;
+pub use work_controller::*;
+pub use author_controller::*;
+pub use genre_controller::*;
+pub use tag_controller::*;
+pub use admin_controller::*;
+pub use review_controller::*;
+pub use notification_controller::*;
+pub use reading_session_controller::*;
+pub use shelf_controller::*;
+pub use goal_controller::*;
+pub use challenge_controller::*;
+pub use stats_controller::*;
\ No newline at end of file
@@ -0,0 +1,86 @@
+use actix_web::{web, HttpResponse, Result as ActixResult, HttpRequest};
+use uuid::Uuid;
+
+use crate::{
+    application::{
+        dtos::notification_dtos::NotificationQuery,
+        use_cases::notifications::manage_notifications::ManageNotificationsUseCase,
+    },
+    domain::{
+        repositories::notification_repository::NotificationRepository,
+        services::auth_service::Claims,
+        errors::domain_error::DomainError,
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/api/me/notifications",
+    params(
+        ("unread_only" = Option<bool>, Query, description = "Only notifications not yet read"),
+        ("limit" = Option<usize>, Query, description = "Limit results, at most 100"),
+        ("offset" = Option<usize>, Query, description = "Offset for pagination")
+    ),
+    responses(
+        (status = 200, description = "The caller's notifications, newest first", body = Vec<NotificationResponse>),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "Notifications",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_my_notifications(
+    req: HttpRequest,
+    query: web::Query<NotificationQuery>,
+    notifications_use_case: web::Data<ManageNotificationsUseCase<NotificationRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match notifications_use_case.list(user_id, query.unread_only, query.limit, query.offset).await {
+        Ok(notifications) => Ok(HttpResponse::Ok().json(notifications)),
+        Err(e) => {
+            tracing::error!("Failed to get notifications: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json("Failed to retrieve notifications"))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/me/notifications/{notification_id}/read",
+    params(
+        ("notification_id" = Uuid, Path, description = "Notification ID")
+    ),
+    responses(
+        (status = 200, description = "Notification marked as read", body = NotificationResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Notification not found")
+    ),
+    tag = "Notifications",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn mark_notification_read(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    notifications_use_case: web::Data<ManageNotificationsUseCase<NotificationRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match notifications_use_case.mark_read(user_id, path.into_inner()).await {
+        Ok(notification) => Ok(HttpResponse::Ok().json(notification)),
+        Err(e) => match e.downcast_ref::<DomainError>() {
+            Some(DomainError::NotificationNotFound) => Ok(HttpResponse::NotFound().json("Notification not found")),
+            _ => {
+                tracing::error!("Failed to mark notification read: {:?}", e);
+                Ok(HttpResponse::InternalServerError().json("Failed to update notification"))
+            }
+        },
+    }
+}
@@ -0,0 +1,144 @@
+use actix_web::{web, HttpResponse, Result as ActixResult, HttpRequest};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    application::{
+        dtos::reading_dtos::{StartReadingSessionRequest, EndReadingSessionRequest, ReadingSessionQuery, ReadingSessionResponse},
+        use_cases::reading::manage_reading_sessions::ManageReadingSessionsUseCase,
+    },
+    domain::{
+        repositories::{
+            book_repository::BookRepository,
+            recommendation_repository::RecommendationRepository,
+        },
+        services::auth_service::Claims,
+        errors::domain_error::DomainError,
+    },
+};
+
+#[utoipa::path(
+    post,
+    path = "/api/reading-sessions",
+    request_body = StartReadingSessionRequest,
+    responses(
+        (status = 201, description = "Session started, with the caller's progress through the book", body = ReadingSessionResponse),
+        (status = 400, description = "Invalid start page"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Book not found"),
+        (status = 409, description = "A session on this book is already in progress")
+    ),
+    tag = "Reading",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn start_reading_session(
+    req: HttpRequest,
+    session_req: web::Json<StartReadingSessionRequest>,
+    reading_sessions_use_case: web::Data<ManageReadingSessionsUseCase<BookRepository, RecommendationRepository>>,
+) -> ActixResult<HttpResponse> {
+    if let Err(validation_errors) = session_req.validate() {
+        return Ok(HttpResponse::BadRequest().json(validation_errors));
+    }
+
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match reading_sessions_use_case.start(user_id, session_req.into_inner()).await {
+        Ok(session) => Ok(HttpResponse::Created().json(session)),
+        Err(e) => match e.downcast_ref::<DomainError>() {
+            Some(DomainError::BookNotFound) => Ok(HttpResponse::NotFound().json("Book not found")),
+            Some(DomainError::ReadingSessionInProgress) => Ok(HttpResponse::Conflict().json(e.to_string())),
+            Some(DomainError::InvalidInput(message)) => Ok(HttpResponse::BadRequest().json(message)),
+            _ => {
+                tracing::error!("Failed to start reading session: {:?}", e);
+                Ok(HttpResponse::InternalServerError().json("Failed to start reading session"))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/reading-sessions/{session_id}/end",
+    params(
+        ("session_id" = Uuid, Path, description = "Reading session ID")
+    ),
+    request_body = EndReadingSessionRequest,
+    responses(
+        (status = 200, description = "Session ended, with the caller's progress through the book", body = ReadingSessionResponse),
+        (status = 400, description = "Neither end_page nor pages_read given, or past the last page"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Reading session not found"),
+        (status = 409, description = "Session already ended")
+    ),
+    tag = "Reading",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn end_reading_session(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    session_req: web::Json<EndReadingSessionRequest>,
+    reading_sessions_use_case: web::Data<ManageReadingSessionsUseCase<BookRepository, RecommendationRepository>>,
+) -> ActixResult<HttpResponse> {
+    if let Err(validation_errors) = session_req.validate() {
+        return Ok(HttpResponse::BadRequest().json(validation_errors));
+    }
+
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match reading_sessions_use_case.end(user_id, path.into_inner(), session_req.into_inner()).await {
+        Ok(session) => Ok(HttpResponse::Ok().json(session)),
+        Err(e) => match e.downcast_ref::<DomainError>() {
+            Some(DomainError::ReadingSessionNotFound) | Some(DomainError::BookNotFound) => {
+                Ok(HttpResponse::NotFound().json("Reading session not found"))
+            }
+            Some(DomainError::ReadingSessionAlreadyEnded) => Ok(HttpResponse::Conflict().json(e.to_string())),
+            Some(DomainError::InvalidInput(message)) => Ok(HttpResponse::BadRequest().json(message)),
+            _ => {
+                tracing::error!("Failed to end reading session: {:?}", e);
+                Ok(HttpResponse::InternalServerError().json("Failed to end reading session"))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/reading-sessions",
+    params(
+        ("book_id" = Option<Uuid>, Query, description = "Only sessions on this book"),
+        ("limit" = Option<usize>, Query, description = "Limit results, at most 100 (default 20)")
+    ),
+    responses(
+        (status = 200, description = "The caller's reading sessions, newest first", body = Vec<ReadingSessionResponse>),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "Reading",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_reading_sessions(
+    req: HttpRequest,
+    query: web::Query<ReadingSessionQuery>,
+    reading_sessions_use_case: web::Data<ManageReadingSessionsUseCase<BookRepository, RecommendationRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match reading_sessions_use_case.list(user_id, query.into_inner()).await {
+        Ok(sessions) => Ok(HttpResponse::Ok().json(sessions)),
+        Err(e) => {
+            tracing::error!("Failed to get reading sessions: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json("Failed to retrieve reading sessions"))
+        }
+    }
+}
@@ -5,12 +5,21 @@ use validator::Validate;
 use crate::{
     application::{
         dtos::recommendation_dtos::{GetRecommendationsRequest, UpdatePreferencesRequest},
-        use_cases::recommendations::get_recommendations::GetRecommendationsUseCase,
+        use_cases::recommendations::{
+            get_recommendations::GetRecommendationsUseCase,
+            update_preferences::UpdatePreferencesUseCase,
+        },
     },
     domain::{
-        repositories::{user_repository::UserRepository, book_repository::BookRepository},
+        repositories::{
+            user_repository::UserRepository,
+            book_repository::BookRepository,
+            genre_repository::GenreRepository,
+            shelf_repository::ShelfRepository,
+        },
         services::auth_service::Claims,
     },
+    presentation::controllers::book_controller::preferred_locales,
 };
 
 #[utoipa::path(
@@ -33,7 +42,7 @@ use crate::{
 pub async fn get_recommendations(
     req: HttpRequest,
     query: web::Query<GetRecommendationsRequest>,
-    recommendation_use_case: web::Data<GetRecommendationsUseCase<UserRepository, BookRepository>>,
+    recommendation_use_case: web::Data<GetRecommendationsUseCase<UserRepository, BookRepository, ShelfRepository>>,
 ) -> ActixResult<HttpResponse> {
     // Extract user from JWT claims
     let claims = req.extensions().get::<Claims>().unwrap();
@@ -44,7 +53,7 @@ pub async fn get_recommendations(
         return Ok(HttpResponse::BadRequest().json(validation_errors));
     }
 
-    match recommendation_use_case.execute(user_id, query.limit).await {
+    match recommendation_use_case.execute(user_id, query.limit, &preferred_locales(&req)).await {
         Ok(recommendations) => Ok(HttpResponse::Ok().json(recommendations)),
         Err(e) => {
             tracing::error!("Failed to get recommendations: {:?}", e);
@@ -58,7 +67,7 @@ pub async fn get_recommendations(
     path = "/api/recommendations/preferences",
     request_body = UpdatePreferencesRequest,
     responses(
-        (status = 200, description = "Preferences updated successfully"),
+        (status = 200, description = "Preferences updated, genres mapped to canonical names"),
         (status = 400, description = "Invalid input"),
         (status = 401, description = "Unauthorized")
     ),
@@ -70,7 +79,7 @@ pub async fn get_recommendations(
 pub async fn update_preferences(
     req: HttpRequest,
     preferences_req: web::Json<UpdatePreferencesRequest>,
-    user_repository: web::Data<dyn UserRepository>,
+    update_preferences_use_case: web::Data<UpdatePreferencesUseCase<UserRepository, GenreRepository>>,
 ) -> ActixResult<HttpResponse> {
     // Extract user from JWT claims
     let claims = req.extensions().get::<Claims>().unwrap();
@@ -81,9 +90,10 @@ pub async fn update_preferences(
         return Ok(HttpResponse::BadRequest().json(validation_errors));
     }
 
-    match user_repository.update_preferences(user_id, preferences_req.preferences.clone()).await {
-        Ok(_) => Ok(HttpResponse::Ok().json(serde_json::json!({
-            "message": "Preferences updated successfully"
+    match update_preferences_use_case.execute(user_id, preferences_req.preferences.clone()).await {
+        Ok(preferences) => Ok(HttpResponse::Ok().json(serde_json::json!({
+            "message": "Preferences updated successfully",
+            "preferences": preferences
         }))),
         Err(e) => {
             tracing::error!("Failed to update preferences: {:?}", e);
@@ -0,0 +1,450 @@
+use actix_web::{web, HttpResponse, Result as ActixResult, HttpRequest};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    application::{
+        dtos::{
+            book_dtos::{BookRatingRequest, PaginationQuery, ReviewQuery},
+            review_dtos::{VoteReviewRequest, ReportReviewRequest, RejectReviewRequest},
+        },
+        use_cases::{
+            books::{
+                rate_book::RateBookUseCase,
+                book_reviews::{GetBookReviewsUseCase, GetUserRatingsUseCase},
+            },
+            reviews::{
+                vote_review::VoteReviewUseCase,
+                report_review::ReportReviewUseCase,
+                moderate_reviews::ModerateReviewsUseCase,
+            },
+        },
+    },
+    domain::{
+        repositories::{
+            book_repository::BookRepository,
+            review_repository::ReviewRepository,
+            user_repository::UserRepository,
+            notification_repository::NotificationRepository,
+        },
+        services::auth_service::Claims,
+        errors::domain_error::DomainError,
+    },
+};
+
+fn error_response(e: anyhow::Error, context: &str) -> HttpResponse {
+    match e.downcast_ref::<DomainError>() {
+        Some(DomainError::Unauthorized) => HttpResponse::Forbidden().json("Curator access required"),
+        Some(DomainError::BookNotFound) | Some(DomainError::RatingNotFound) | Some(DomainError::ReviewNotFound) => {
+            HttpResponse::NotFound().json(e.to_string())
+        }
+        Some(DomainError::InvalidInput(message)) => HttpResponse::BadRequest().json(message),
+        _ => {
+            tracing::error!("{}: {:?}", context, e);
+            HttpResponse::InternalServerError().json(context)
+        }
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/books/{book_id}/rate",
+    params(
+        ("book_id" = Uuid, Path, description = "Book ID")
+    ),
+    request_body = BookRatingRequest,
+    responses(
+        (status = 200, description = "Rating and review replaced", body = BookRatingResponse),
+        (status = 400, description = "Invalid input"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Book not found or not rated by the caller")
+    ),
+    tag = "Reviews",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_rating(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    rating_req: web::Json<BookRatingRequest>,
+    rate_book_use_case: web::Data<RateBookUseCase<BookRepository, NotificationRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    // Validate input
+    if let Err(validation_errors) = rating_req.validate() {
+        return Ok(HttpResponse::BadRequest().json(validation_errors));
+    }
+
+    match rate_book_use_case.update(path.into_inner(), user_id, rating_req.into_inner()).await {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => Ok(error_response(e, "Rating update failed")),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/books/{book_id}/rate",
+    params(
+        ("book_id" = Uuid, Path, description = "Book ID")
+    ),
+    responses(
+        (status = 204, description = "Rating and review removed"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Book not found or not rated by the caller")
+    ),
+    tag = "Reviews",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_rating(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    rate_book_use_case: web::Data<RateBookUseCase<BookRepository, NotificationRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match rate_book_use_case.delete(path.into_inner(), user_id).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Ok(error_response(e, "Rating removal failed")),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/books/{book_id}/reviews",
+    params(
+        ("book_id" = Uuid, Path, description = "Book ID"),
+        ("sort" = Option<ReviewSort>, Query, description = "newest (default), oldest, highest_rated, lowest_rated or most_helpful"),
+        ("limit" = Option<usize>, Query, description = "Limit results, at most 100"),
+        ("offset" = Option<usize>, Query, description = "Offset for pagination")
+    ),
+    responses(
+        (status = 200, description = "Ratings of the book that include a written review", body = Vec<BookRatingResponse>),
+        (status = 404, description = "Book not found")
+    ),
+    tag = "Reviews"
+)]
+pub async fn get_book_reviews(
+    path: web::Path<Uuid>,
+    query: web::Query<ReviewQuery>,
+    get_reviews_use_case: web::Data<GetBookReviewsUseCase<BookRepository>>,
+) -> ActixResult<HttpResponse> {
+    let query = query.into_inner();
+
+    match get_reviews_use_case.execute(path.into_inner(), query.sort, query.limit, query.offset).await {
+        Ok(reviews) => Ok(HttpResponse::Ok().json(reviews)),
+        Err(e) => Ok(error_response(e, "Failed to retrieve reviews")),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/me/ratings",
+    params(
+        ("limit" = Option<usize>, Query, description = "Limit results, at most 100"),
+        ("offset" = Option<usize>, Query, description = "Offset for pagination")
+    ),
+    responses(
+        (status = 200, description = "The caller's ratings, most recent first", body = Vec<BookRatingResponse>),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "Reviews",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_my_ratings(
+    req: HttpRequest,
+    query: web::Query<PaginationQuery>,
+    get_ratings_use_case: web::Data<GetUserRatingsUseCase<BookRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match get_ratings_use_case.execute(user_id, query.limit, query.offset).await {
+        Ok(ratings) => Ok(HttpResponse::Ok().json(ratings)),
+        Err(e) => Ok(error_response(e, "Failed to retrieve ratings")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/reviews/{rating_id}/vote",
+    params(
+        ("rating_id" = Uuid, Path, description = "Rating ID of the review")
+    ),
+    request_body = VoteReviewRequest,
+    responses(
+        (status = 200, description = "Vote recorded, review with its updated tally", body = BookRatingResponse),
+        (status = 400, description = "Voting on your own review"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Review not found or not published")
+    ),
+    tag = "Reviews",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn vote_review(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    vote_req: web::Json<VoteReviewRequest>,
+    vote_review_use_case: web::Data<VoteReviewUseCase<BookRepository, ReviewRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match vote_review_use_case.execute(path.into_inner(), user_id, vote_req.into_inner()).await {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => Ok(error_response(e, "Review vote failed")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/reviews/{rating_id}/report",
+    params(
+        ("rating_id" = Uuid, Path, description = "Rating ID of the review")
+    ),
+    request_body = ReportReviewRequest,
+    responses(
+        (status = 201, description = "Report filed", body = ReviewReportResponse),
+        (status = 400, description = "Invalid input, your own review, or already reported"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Review not found or not published")
+    ),
+    tag = "Reviews",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn report_review(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    report_req: web::Json<ReportReviewRequest>,
+    report_review_use_case: web::Data<ReportReviewUseCase<BookRepository, ReviewRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    // Validate input
+    if let Err(validation_errors) = report_req.validate() {
+        return Ok(HttpResponse::BadRequest().json(validation_errors));
+    }
+
+    match report_review_use_case.execute(path.into_inner(), user_id, report_req.into_inner()).await {
+        Ok(response) => Ok(HttpResponse::Created().json(response)),
+        Err(e) => Ok(error_response(e, "Review report failed")),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/reviews/reported",
+    params(
+        ("limit" = Option<usize>, Query, description = "Limit results"),
+        ("offset" = Option<usize>, Query, description = "Offset for pagination")
+    ),
+    responses(
+        (status = 200, description = "Reviews with open reports, most reported first", body = Vec<ReportedReviewResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Curator access required")
+    ),
+    tag = "Reviews",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_reported_reviews(
+    req: HttpRequest,
+    query: web::Query<PaginationQuery>,
+    moderate_use_case: web::Data<ModerateReviewsUseCase<UserRepository, BookRepository, ReviewRepository, NotificationRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match moderate_use_case.list_reported(user_id, query.limit, query.offset).await {
+        Ok(queue) => Ok(HttpResponse::Ok().json(queue)),
+        Err(e) => Ok(error_response(e, "Failed to retrieve reported reviews")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/reviews/{rating_id}/dismiss",
+    params(
+        ("rating_id" = Uuid, Path, description = "Rating ID of the review")
+    ),
+    responses(
+        (status = 200, description = "Reports dismissed and the review published again", body = BookRatingResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Curator access required"),
+        (status = 404, description = "Review not found")
+    ),
+    tag = "Reviews",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn dismiss_review_reports(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    moderate_use_case: web::Data<ModerateReviewsUseCase<UserRepository, BookRepository, ReviewRepository, NotificationRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match moderate_use_case.dismiss(user_id, path.into_inner()).await {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => Ok(error_response(e, "Failed to dismiss reports")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/reviews/{rating_id}/remove",
+    params(
+        ("rating_id" = Uuid, Path, description = "Rating ID of the review")
+    ),
+    responses(
+        (status = 200, description = "Reports upheld and the review taken down; the rating still counts", body = BookRatingResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Curator access required"),
+        (status = 404, description = "Review not found")
+    ),
+    tag = "Reviews",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn remove_review(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    moderate_use_case: web::Data<ModerateReviewsUseCase<UserRepository, BookRepository, ReviewRepository, NotificationRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match moderate_use_case.remove(user_id, path.into_inner()).await {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => Ok(error_response(e, "Failed to remove review")),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/reviews/pending",
+    params(
+        ("limit" = Option<usize>, Query, description = "Limit results"),
+        ("offset" = Option<usize>, Query, description = "Offset for pagination")
+    ),
+    responses(
+        (status = 200, description = "Reviews held by the moderation filters, oldest first, with the filters' reasons", body = Vec<BookRatingResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Curator access required")
+    ),
+    tag = "Reviews",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_pending_reviews(
+    req: HttpRequest,
+    query: web::Query<PaginationQuery>,
+    moderate_use_case: web::Data<ModerateReviewsUseCase<UserRepository, BookRepository, ReviewRepository, NotificationRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match moderate_use_case.list_pending(user_id, query.limit, query.offset).await {
+        Ok(queue) => Ok(HttpResponse::Ok().json(queue)),
+        Err(e) => Ok(error_response(e, "Failed to retrieve held reviews")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/reviews/{rating_id}/approve",
+    params(
+        ("rating_id" = Uuid, Path, description = "Rating ID of the review")
+    ),
+    responses(
+        (status = 200, description = "Held or rejected review published", body = BookRatingResponse),
+        (status = 400, description = "Review is not held or rejected"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Curator access required"),
+        (status = 404, description = "Review not found")
+    ),
+    tag = "Reviews",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn approve_review(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    moderate_use_case: web::Data<ModerateReviewsUseCase<UserRepository, BookRepository, ReviewRepository, NotificationRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match moderate_use_case.approve(user_id, path.into_inner()).await {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => Ok(error_response(e, "Failed to approve review")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/reviews/{rating_id}/reject",
+    params(
+        ("rating_id" = Uuid, Path, description = "Rating ID of the review")
+    ),
+    request_body = RejectReviewRequest,
+    responses(
+        (status = 200, description = "Held review rejected and its author notified", body = BookRatingResponse),
+        (status = 400, description = "Review is not held"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Curator access required"),
+        (status = 404, description = "Review not found")
+    ),
+    tag = "Reviews",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn reject_review(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    reject_req: web::Json<RejectReviewRequest>,
+    moderate_use_case: web::Data<ModerateReviewsUseCase<UserRepository, BookRepository, ReviewRepository, NotificationRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    // Validate input
+    if let Err(validation_errors) = reject_req.validate() {
+        return Ok(HttpResponse::BadRequest().json(validation_errors));
+    }
+
+    match moderate_use_case.reject(user_id, path.into_inner(), reject_req.into_inner()).await {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => Ok(error_response(e, "Failed to reject review")),
+    }
+}
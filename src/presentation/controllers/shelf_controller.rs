@@ -0,0 +1,320 @@
+use actix_web::{web, HttpResponse, Result as ActixResult, HttpRequest};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    application::{
+        dtos::shelf_dtos::{
+            CreateShelfRequest, ShelveBookRequest, ShelfQuery, ShelfExportQuery, ShelfExportFormat,
+            ShelfResponse, ShelfEntryResponse, ShelfExportRow,
+        },
+        use_cases::shelves::{
+            manage_shelves::ManageShelvesUseCase,
+            export_shelves::{ExportShelvesUseCase, shelves_to_csv},
+        },
+    },
+    domain::{
+        entities::shelf::{ShelfStatus, ShelfStatusChange},
+        repositories::{
+            book_repository::BookRepository,
+            shelf_repository::ShelfRepository,
+        },
+        services::auth_service::Claims,
+        errors::domain_error::DomainError,
+    },
+    presentation::controllers::book_controller::preferred_locales,
+};
+
+#[utoipa::path(
+    get,
+    path = "/api/me/shelves",
+    responses(
+        (status = 200, description = "The standard shelves, then the caller's custom shelves, with book counts", body = Vec<ShelfResponse>),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "Shelves",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_my_shelves(
+    req: HttpRequest,
+    shelves_use_case: web::Data<ManageShelvesUseCase<BookRepository, ShelfRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match shelves_use_case.list_shelves(user_id).await {
+        Ok(shelves) => Ok(HttpResponse::Ok().json(shelves)),
+        Err(e) => {
+            tracing::error!("Failed to get shelves: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json("Failed to retrieve shelves"))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/me/shelves",
+    request_body = CreateShelfRequest,
+    responses(
+        (status = 201, description = "Custom shelf created", body = ShelfResponse),
+        (status = 400, description = "Invalid name, already used or a standard shelf"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "Shelves",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_shelf(
+    req: HttpRequest,
+    shelf_req: web::Json<CreateShelfRequest>,
+    shelves_use_case: web::Data<ManageShelvesUseCase<BookRepository, ShelfRepository>>,
+) -> ActixResult<HttpResponse> {
+    if let Err(validation_errors) = shelf_req.validate() {
+        return Ok(HttpResponse::BadRequest().json(validation_errors));
+    }
+
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match shelves_use_case.create_shelf(user_id, &shelf_req.name).await {
+        Ok(shelf) => Ok(HttpResponse::Created().json(shelf)),
+        Err(e) => match e.downcast_ref::<DomainError>() {
+            Some(DomainError::InvalidInput(message)) => Ok(HttpResponse::BadRequest().json(message)),
+            _ => {
+                tracing::error!("Failed to create shelf: {:?}", e);
+                Ok(HttpResponse::InternalServerError().json("Failed to create shelf"))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/me/shelves/{shelf_id}",
+    params(
+        ("shelf_id" = Uuid, Path, description = "Custom shelf ID")
+    ),
+    responses(
+        (status = 204, description = "Shelf deleted; its books stay on their other shelves"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Shelf not found")
+    ),
+    tag = "Shelves",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_shelf(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    shelves_use_case: web::Data<ManageShelvesUseCase<BookRepository, ShelfRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match shelves_use_case.delete_shelf(user_id, path.into_inner()).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => match e.downcast_ref::<DomainError>() {
+            Some(DomainError::ShelfNotFound) => Ok(HttpResponse::NotFound().json("Shelf not found")),
+            _ => {
+                tracing::error!("Failed to delete shelf: {:?}", e);
+                Ok(HttpResponse::InternalServerError().json("Failed to delete shelf"))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/me/shelves/books",
+    params(
+        ("status" = Option<ShelfStatus>, Query, description = "Only books on this standard shelf"),
+        ("shelf_id" = Option<Uuid>, Query, description = "Only books on this custom shelf"),
+        ("limit" = Option<usize>, Query, description = "Limit results, at most 100"),
+        ("offset" = Option<usize>, Query, description = "Offset for pagination"),
+        ("Accept-Language" = Option<String>, Header, description = "Preferred locales for titles and descriptions")
+    ),
+    responses(
+        (status = 200, description = "Shelved books, most recently updated first", body = Vec<ShelfEntryResponse>),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "Shelves",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_shelved_books(
+    req: HttpRequest,
+    query: web::Query<ShelfQuery>,
+    shelves_use_case: web::Data<ManageShelvesUseCase<BookRepository, ShelfRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match shelves_use_case.list_books(user_id, query.into_inner(), &preferred_locales(&req)).await {
+        Ok(books) => Ok(HttpResponse::Ok().json(books)),
+        Err(e) => {
+            tracing::error!("Failed to get shelved books: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json("Failed to retrieve shelved books"))
+        }
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/me/shelves/books/{book_id}",
+    params(
+        ("book_id" = Uuid, Path, description = "Book ID"),
+        ("Accept-Language" = Option<String>, Header, description = "Preferred locales for title and description")
+    ),
+    request_body = ShelveBookRequest,
+    responses(
+        (status = 200, description = "Book shelved", body = ShelfEntryResponse),
+        (status = 400, description = "Dates in the future or finished before started"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Book or custom shelf not found")
+    ),
+    tag = "Shelves",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn shelve_book(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    shelve_req: web::Json<ShelveBookRequest>,
+    shelves_use_case: web::Data<ManageShelvesUseCase<BookRepository, ShelfRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match shelves_use_case.shelve_book(user_id, path.into_inner(), shelve_req.into_inner(), &preferred_locales(&req)).await {
+        Ok(entry) => Ok(HttpResponse::Ok().json(entry)),
+        Err(e) => match e.downcast_ref::<DomainError>() {
+            Some(DomainError::BookNotFound) => Ok(HttpResponse::NotFound().json("Book not found")),
+            Some(DomainError::ShelfNotFound) => Ok(HttpResponse::NotFound().json("Shelf not found")),
+            Some(DomainError::InvalidInput(message)) => Ok(HttpResponse::BadRequest().json(message)),
+            _ => {
+                tracing::error!("Failed to shelve book: {:?}", e);
+                Ok(HttpResponse::InternalServerError().json("Failed to shelve book"))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/me/shelves/books/{book_id}",
+    params(
+        ("book_id" = Uuid, Path, description = "Book ID")
+    ),
+    responses(
+        (status = 204, description = "Book taken off all shelves"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Book is not on the caller's shelves")
+    ),
+    tag = "Shelves",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn unshelve_book(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    shelves_use_case: web::Data<ManageShelvesUseCase<BookRepository, ShelfRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match shelves_use_case.unshelve_book(user_id, path.into_inner()).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => match e.downcast_ref::<DomainError>() {
+            Some(DomainError::ShelfEntryNotFound) => Ok(HttpResponse::NotFound().json(e.to_string())),
+            _ => {
+                tracing::error!("Failed to unshelve book: {:?}", e);
+                Ok(HttpResponse::InternalServerError().json("Failed to unshelve book"))
+            }
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/me/shelves/books/{book_id}/history",
+    params(
+        ("book_id" = Uuid, Path, description = "Book ID")
+    ),
+    responses(
+        (status = 200, description = "The book's moves between the caller's shelves, oldest first", body = Vec<ShelfStatusChange>),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "Shelves",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_shelf_history(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    shelves_use_case: web::Data<ManageShelvesUseCase<BookRepository, ShelfRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match shelves_use_case.history(user_id, path.into_inner()).await {
+        Ok(changes) => Ok(HttpResponse::Ok().json(changes)),
+        Err(e) => {
+            tracing::error!("Failed to get shelf history: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json("Failed to retrieve shelf history"))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/me/shelves/export",
+    params(
+        ("format" = Option<ShelfExportFormat>, Query, description = "csv (default) or json")
+    ),
+    responses(
+        (status = 200, description = "Every shelved book with its shelves, dates and the caller's rating", body = Vec<ShelfExportRow>),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "Shelves",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn export_shelves(
+    req: HttpRequest,
+    query: web::Query<ShelfExportQuery>,
+    export_use_case: web::Data<ExportShelvesUseCase<BookRepository, ShelfRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match export_use_case.execute(user_id).await {
+        Ok(rows) => match query.format {
+            ShelfExportFormat::Json => Ok(HttpResponse::Ok().json(rows)),
+            ShelfExportFormat::Csv => Ok(HttpResponse::Ok()
+                .content_type("text/csv; charset=utf-8")
+                .insert_header(("Content-Disposition", "attachment; filename=\"shelves.csv\""))
+                .body(shelves_to_csv(&rows))),
+        },
+        Err(e) => {
+            tracing::error!("Failed to export shelves: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json("Failed to export shelves"))
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use actix_web::{web, HttpResponse, Result as ActixResult, HttpRequest};
+use uuid::Uuid;
+
+use crate::{
+    application::{
+        dtos::reading_dtos::ReadingStatsQuery,
+        use_cases::reading::get_reading_stats::GetReadingStatsUseCase,
+    },
+    domain::{
+        repositories::{
+            book_repository::BookRepository,
+            recommendation_repository::RecommendationRepository,
+            shelf_repository::ShelfRepository,
+        },
+        services::auth_service::Claims,
+        errors::domain_error::DomainError,
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/api/me/stats",
+    params(
+        ("tz" = Option<String>, Query, description = "IANA time zone used to bucket dates, e.g. Europe/Berlin (default UTC)"),
+        ("year" = Option<i32>, Query, description = "Year of the year in review (default the current year)")
+    ),
+    responses(
+        (status = 200, description = "The caller's reading statistics", body = ReadingStats),
+        (status = 400, description = "Unknown time zone"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "Reading",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_my_stats(
+    req: HttpRequest,
+    query: web::Query<ReadingStatsQuery>,
+    stats_use_case: web::Data<GetReadingStatsUseCase<BookRepository, RecommendationRepository, ShelfRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match stats_use_case.execute(user_id, query.into_inner()).await {
+        Ok(stats) => Ok(HttpResponse::Ok().json(stats)),
+        Err(e) => match e.downcast_ref::<DomainError>() {
+            Some(DomainError::InvalidInput(message)) => Ok(HttpResponse::BadRequest().json(message)),
+            _ => {
+                tracing::error!("Failed to get reading stats: {:?}", e);
+                Ok(HttpResponse::InternalServerError().json("Failed to retrieve reading stats"))
+            }
+        },
+    }
+}
@@ -0,0 +1,342 @@
+use actix_web::{web, HttpResponse, Result as ActixResult, HttpRequest};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    application::{
+        dtos::{
+            book_dtos::PaginationQuery,
+            tag_dtos::{SuggestTagRequest, VoteTagRequest, RejectTagRequest, BlockTagRequest},
+        },
+        use_cases::tags::{
+            suggest_tag::SuggestTagUseCase,
+            vote_tag::VoteTagUseCase,
+            get_book_tags::GetBookTagsUseCase,
+            moderate_tags::ModerateTagsUseCase,
+        },
+    },
+    domain::{
+        repositories::{
+            book_repository::BookRepository,
+            tag_repository::TagRepository,
+            user_repository::UserRepository,
+        },
+        services::auth_service::Claims,
+        errors::domain_error::DomainError,
+    },
+};
+
+fn error_response(e: anyhow::Error, context: &str) -> HttpResponse {
+    match e.downcast_ref::<DomainError>() {
+        Some(DomainError::Unauthorized) => HttpResponse::Forbidden().json("Curator access required"),
+        Some(DomainError::BookNotFound) | Some(DomainError::TagNotFound) => {
+            HttpResponse::NotFound().json(e.to_string())
+        }
+        Some(DomainError::InvalidInput(message)) => HttpResponse::BadRequest().json(message),
+        _ => {
+            tracing::error!("{}: {:?}", context, e);
+            HttpResponse::InternalServerError().json(context)
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/books/{book_id}/tags",
+    params(
+        ("book_id" = Uuid, Path, description = "Book ID")
+    ),
+    responses(
+        (status = 200, description = "Approved tags, best ranked first, with weights", body = Vec<BookTagResponse>),
+        (status = 404, description = "Book not found")
+    ),
+    tag = "Tags"
+)]
+pub async fn get_book_tags(
+    path: web::Path<Uuid>,
+    get_book_tags_use_case: web::Data<GetBookTagsUseCase<BookRepository, TagRepository>>,
+) -> ActixResult<HttpResponse> {
+    match get_book_tags_use_case.execute(path.into_inner()).await {
+        Ok(tags) => Ok(HttpResponse::Ok().json(tags)),
+        Err(e) => Ok(error_response(e, "Failed to retrieve tags")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/books/{book_id}/tags",
+    params(
+        ("book_id" = Uuid, Path, description = "Book ID")
+    ),
+    request_body = SuggestTagRequest,
+    responses(
+        (status = 201, description = "Tag suggested, or upvoted if the book already has it", body = BookTagResponse),
+        (status = 400, description = "Invalid, blocked or rejected tag"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Book not found")
+    ),
+    tag = "Tags",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn suggest_tag(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    tag_req: web::Json<SuggestTagRequest>,
+    suggest_tag_use_case: web::Data<SuggestTagUseCase<BookRepository, TagRepository>>,
+) -> ActixResult<HttpResponse> {
+    let book_id = path.into_inner();
+
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    // Validate input
+    if let Err(validation_errors) = tag_req.validate() {
+        return Ok(HttpResponse::BadRequest().json(validation_errors));
+    }
+
+    match suggest_tag_use_case.execute(book_id, user_id, tag_req.into_inner()).await {
+        Ok(tag) => Ok(HttpResponse::Created().json(tag)),
+        Err(e) => Ok(error_response(e, "Failed to suggest tag")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/books/{book_id}/tags/{tag_id}/vote",
+    params(
+        ("book_id" = Uuid, Path, description = "Book ID"),
+        ("tag_id" = Uuid, Path, description = "Book tag ID")
+    ),
+    request_body = VoteTagRequest,
+    responses(
+        (status = 200, description = "Vote recorded", body = BookTagResponse),
+        (status = 400, description = "Invalid vote or rejected tag"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Tag not found on this book")
+    ),
+    tag = "Tags",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn vote_tag(
+    req: HttpRequest,
+    path: web::Path<(Uuid, Uuid)>,
+    vote_req: web::Json<VoteTagRequest>,
+    vote_tag_use_case: web::Data<VoteTagUseCase<BookRepository, TagRepository>>,
+) -> ActixResult<HttpResponse> {
+    let (book_id, tag_id) = path.into_inner();
+
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    // Validate input
+    if let Err(validation_errors) = vote_req.validate() {
+        return Ok(HttpResponse::BadRequest().json(validation_errors));
+    }
+
+    match vote_tag_use_case.execute(book_id, tag_id, user_id, vote_req.into_inner()).await {
+        Ok(tag) => Ok(HttpResponse::Ok().json(tag)),
+        Err(e) => Ok(error_response(e, "Failed to record vote")),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/tags/pending",
+    params(
+        ("limit" = Option<usize>, Query, description = "Limit results"),
+        ("offset" = Option<usize>, Query, description = "Offset for pagination")
+    ),
+    responses(
+        (status = 200, description = "Suggested tags awaiting review, oldest first", body = Vec<BookTagResponse>),
+        (status = 403, description = "Curator access required")
+    ),
+    tag = "Tags",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_pending_tags(
+    req: HttpRequest,
+    query: web::Query<PaginationQuery>,
+    moderate_tags_use_case: web::Data<ModerateTagsUseCase<UserRepository, BookRepository, TagRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match moderate_tags_use_case.list_pending(user_id, query.limit, query.offset).await {
+        Ok(tags) => Ok(HttpResponse::Ok().json(tags)),
+        Err(e) => Ok(error_response(e, "Failed to retrieve pending tags")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/tags/{tag_id}/approve",
+    params(
+        ("tag_id" = Uuid, Path, description = "Book tag ID")
+    ),
+    responses(
+        (status = 200, description = "Tag approved", body = BookTagResponse),
+        (status = 403, description = "Curator access required"),
+        (status = 404, description = "Tag not found")
+    ),
+    tag = "Tags",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn approve_tag(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    moderate_tags_use_case: web::Data<ModerateTagsUseCase<UserRepository, BookRepository, TagRepository>>,
+) -> ActixResult<HttpResponse> {
+    let tag_id = path.into_inner();
+
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match moderate_tags_use_case.approve(user_id, tag_id).await {
+        Ok(tag) => Ok(HttpResponse::Ok().json(tag)),
+        Err(e) => Ok(error_response(e, "Failed to approve tag")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/tags/{tag_id}/reject",
+    params(
+        ("tag_id" = Uuid, Path, description = "Book tag ID")
+    ),
+    request_body = RejectTagRequest,
+    responses(
+        (status = 200, description = "Tag rejected, and blocklisted if requested", body = BookTagResponse),
+        (status = 403, description = "Curator access required"),
+        (status = 404, description = "Tag not found")
+    ),
+    tag = "Tags",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn reject_tag(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    reject_req: web::Json<RejectTagRequest>,
+    moderate_tags_use_case: web::Data<ModerateTagsUseCase<UserRepository, BookRepository, TagRepository>>,
+) -> ActixResult<HttpResponse> {
+    let tag_id = path.into_inner();
+
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    // Validate input
+    if let Err(validation_errors) = reject_req.validate() {
+        return Ok(HttpResponse::BadRequest().json(validation_errors));
+    }
+
+    match moderate_tags_use_case.reject(user_id, tag_id, reject_req.into_inner()).await {
+        Ok(tag) => Ok(HttpResponse::Ok().json(tag)),
+        Err(e) => Ok(error_response(e, "Failed to reject tag")),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/tags/blocklist",
+    responses(
+        (status = 200, description = "Blocked tags", body = Vec<BlockedTagResponse>),
+        (status = 403, description = "Curator access required")
+    ),
+    tag = "Tags",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_blocked_tags(
+    req: HttpRequest,
+    moderate_tags_use_case: web::Data<ModerateTagsUseCase<UserRepository, BookRepository, TagRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match moderate_tags_use_case.list_blocked(user_id).await {
+        Ok(blocked) => Ok(HttpResponse::Ok().json(blocked)),
+        Err(e) => Ok(error_response(e, "Failed to retrieve blocklist")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/tags/blocklist",
+    request_body = BlockTagRequest,
+    responses(
+        (status = 201, description = "Tag blocked and rejected on every book", body = BlockedTagResponse),
+        (status = 400, description = "Invalid input"),
+        (status = 403, description = "Curator access required")
+    ),
+    tag = "Tags",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn block_tag(
+    req: HttpRequest,
+    block_req: web::Json<BlockTagRequest>,
+    moderate_tags_use_case: web::Data<ModerateTagsUseCase<UserRepository, BookRepository, TagRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    // Validate input
+    if let Err(validation_errors) = block_req.validate() {
+        return Ok(HttpResponse::BadRequest().json(validation_errors));
+    }
+
+    match moderate_tags_use_case.block(user_id, block_req.into_inner()).await {
+        Ok(blocked) => Ok(HttpResponse::Created().json(blocked)),
+        Err(e) => Ok(error_response(e, "Failed to block tag")),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/admin/tags/blocklist/{tag}",
+    params(
+        ("tag" = String, Path, description = "Blocked tag")
+    ),
+    responses(
+        (status = 204, description = "Tag removed from the blocklist"),
+        (status = 403, description = "Curator access required")
+    ),
+    tag = "Tags",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn unblock_tag(
+    req: HttpRequest,
+    path: web::Path<String>,
+    moderate_tags_use_case: web::Data<ModerateTagsUseCase<UserRepository, BookRepository, TagRepository>>,
+) -> ActixResult<HttpResponse> {
+    let tag = path.into_inner();
+
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match moderate_tags_use_case.unblock(user_id, &tag).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Ok(error_response(e, "Failed to unblock tag")),
+    }
+}
@@ -0,0 +1,209 @@
+use actix_web::{web, HttpResponse, Result as ActixResult, HttpRequest};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    application::{
+        dtos::work_dtos::{CreateWorkRequest, AddEditionRequest, CreateSeriesRequest},
+        use_cases::works::{
+            create_work::CreateWorkUseCase,
+            add_edition::AddEditionUseCase,
+            get_work::GetWorkUseCase,
+            series::{CreateSeriesUseCase, GetSeriesUseCase},
+        },
+    },
+    domain::{
+        repositories::{
+            book_repository::BookRepository,
+            user_repository::UserRepository,
+            work_repository::WorkRepository,
+        },
+        services::auth_service::Claims,
+        errors::domain_error::DomainError,
+    },
+};
+
+fn error_response(e: anyhow::Error, context: &str) -> HttpResponse {
+    match e.downcast_ref::<DomainError>() {
+        Some(DomainError::Unauthorized) => HttpResponse::Forbidden().json("Curator access required"),
+        Some(DomainError::WorkNotFound)
+        | Some(DomainError::SeriesNotFound)
+        | Some(DomainError::BookNotFound) => HttpResponse::NotFound().json(e.to_string()),
+        Some(DomainError::InvalidInput(message)) => HttpResponse::BadRequest().json(message),
+        _ => {
+            tracing::error!("{}: {:?}", context, e);
+            HttpResponse::InternalServerError().json(context)
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/works",
+    request_body = CreateWorkRequest,
+    responses(
+        (status = 201, description = "Work created successfully", body = WorkSummary),
+        (status = 400, description = "Invalid input"),
+        (status = 403, description = "Curator access required")
+    ),
+    tag = "Works",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_work(
+    req: HttpRequest,
+    work_req: web::Json<CreateWorkRequest>,
+    create_work_use_case: web::Data<CreateWorkUseCase<UserRepository, WorkRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    // Validate input
+    if let Err(validation_errors) = work_req.validate() {
+        return Ok(HttpResponse::BadRequest().json(validation_errors));
+    }
+
+    match create_work_use_case.execute(work_req.into_inner(), user_id).await {
+        Ok(response) => Ok(HttpResponse::Created().json(response)),
+        Err(e) => Ok(error_response(e, "Work creation failed")),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/works/{work_id}",
+    params(
+        ("work_id" = Uuid, Path, description = "Work ID")
+    ),
+    responses(
+        (status = 200, description = "Work with its editions", body = WorkResponse),
+        (status = 404, description = "Work not found")
+    ),
+    tag = "Works"
+)]
+pub async fn get_work(
+    path: web::Path<Uuid>,
+    get_work_use_case: web::Data<GetWorkUseCase<WorkRepository>>,
+) -> ActixResult<HttpResponse> {
+    match get_work_use_case.execute(path.into_inner()).await {
+        Ok(work) => Ok(HttpResponse::Ok().json(work)),
+        Err(e) => Ok(error_response(e, "Failed to retrieve work")),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/works/{work_id}/next",
+    params(
+        ("work_id" = Uuid, Path, description = "Work ID")
+    ),
+    responses(
+        (status = 200, description = "Next work in the series", body = WorkSummary),
+        (status = 204, description = "Last work in the series, or not part of a series"),
+        (status = 404, description = "Work not found")
+    ),
+    tag = "Works"
+)]
+pub async fn get_next_in_series(
+    path: web::Path<Uuid>,
+    get_work_use_case: web::Data<GetWorkUseCase<WorkRepository>>,
+) -> ActixResult<HttpResponse> {
+    match get_work_use_case.next_in_series(path.into_inner()).await {
+        Ok(Some(next)) => Ok(HttpResponse::Ok().json(next)),
+        Ok(None) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Ok(error_response(e, "Failed to retrieve next work")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/works/{work_id}/editions",
+    params(
+        ("work_id" = Uuid, Path, description = "Work ID")
+    ),
+    request_body = AddEditionRequest,
+    responses(
+        (status = 200, description = "Book attached as an edition", body = BookResponse),
+        (status = 403, description = "Curator access required"),
+        (status = 404, description = "Work or book not found")
+    ),
+    tag = "Works",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn add_edition(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    edition_req: web::Json<AddEditionRequest>,
+    add_edition_use_case: web::Data<AddEditionUseCase<UserRepository, BookRepository, WorkRepository>>,
+) -> ActixResult<HttpResponse> {
+    let work_id = path.into_inner();
+
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    match add_edition_use_case.execute(work_id, user_id, edition_req.into_inner()).await {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => Ok(error_response(e, "Failed to add edition")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/series",
+    request_body = CreateSeriesRequest,
+    responses(
+        (status = 201, description = "Series created successfully", body = SeriesResponse),
+        (status = 400, description = "Invalid input"),
+        (status = 403, description = "Curator access required")
+    ),
+    tag = "Works",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_series(
+    req: HttpRequest,
+    series_req: web::Json<CreateSeriesRequest>,
+    create_series_use_case: web::Data<CreateSeriesUseCase<UserRepository, WorkRepository>>,
+) -> ActixResult<HttpResponse> {
+    // Extract user from JWT claims
+    let claims = req.extensions().get::<Claims>().unwrap();
+    let user_id = Uuid::parse_str(&claims.sub).unwrap();
+
+    // Validate input
+    if let Err(validation_errors) = series_req.validate() {
+        return Ok(HttpResponse::BadRequest().json(validation_errors));
+    }
+
+    match create_series_use_case.execute(series_req.into_inner(), user_id).await {
+        Ok(response) => Ok(HttpResponse::Created().json(response)),
+        Err(e) => Ok(error_response(e, "Series creation failed")),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/series/{series_id}",
+    params(
+        ("series_id" = Uuid, Path, description = "Series ID")
+    ),
+    responses(
+        (status = 200, description = "Series with its works in reading order", body = SeriesResponse),
+        (status = 404, description = "Series not found")
+    ),
+    tag = "Works"
+)]
+pub async fn get_series(
+    path: web::Path<Uuid>,
+    get_series_use_case: web::Data<GetSeriesUseCase<WorkRepository>>,
+) -> ActixResult<HttpResponse> {
+    match get_series_use_case.execute(path.into_inner()).await {
+        Ok(series) => Ok(HttpResponse::Ok().json(series)),
+        Err(e) => Ok(error_response(e, "Failed to retrieve series")),
+    }
+}
@@ -0,0 +1,30 @@
+use actix_web::web;
+
+use crate::presentation::controllers::admin_controller::{
+    list_deleted_books, restore_book, delete_user, list_deleted_users, restore_user,
+    purge_deleted_records
+};
+use crate::presentation::controllers::book_controller::{backfill_cover_placeholders, find_cover_duplicates};
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/admin/books")
+            .route("/deleted", web::get().to(list_deleted_books))
+            .route("/{book_id}/restore", web::post().to(restore_book))
+    )
+    .service(
+        web::scope("/admin/users")
+            .route("/deleted", web::get().to(list_deleted_users))
+            .route("/{user_id}", web::delete().to(delete_user))
+            .route("/{user_id}/restore", web::post().to(restore_user))
+    )
+    .service(
+        web::scope("/admin/purge")
+            .route("", web::post().to(purge_deleted_records))
+    )
+    .service(
+        web::scope("/admin/covers")
+            .route("/duplicates", web::get().to(find_cover_duplicates))
+            .route("/backfill-placeholders", web::post().to(backfill_cover_placeholders))
+    );
+}
@@ -0,0 +1,17 @@
+use actix_web::web;
+
+use crate::presentation::controllers::author_controller::{
+    get_author, update_author, backfill_authors
+};
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/authors")
+            .route("/{author_id}", web::get().to(get_author))
+            .route("/{author_id}", web::put().to(update_author))
+    )
+    .service(
+        web::scope("/admin/authors")
+            .route("/backfill", web::post().to(backfill_authors))
+    );
+}
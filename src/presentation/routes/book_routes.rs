@@ -1,7 +1,13 @@
 use actix_web::web;
 
+use crate::presentation::controllers::admin_controller::delete_book;
+use crate::presentation::controllers::author_controller::add_book_contributor;
+use crate::presentation::controllers::tag_controller::{get_book_tags, suggest_tag, vote_tag};
+use crate::presentation::controllers::review_controller::{update_rating, delete_rating, get_book_reviews};
 use crate::presentation::controllers::book_controller::{
-    create_book, get_books, upload_book_image, rate_book
+    create_book, get_books, upload_book_image, rate_book, ingest_onix_feed, get_book,
+    get_book_revisions, revert_book, upsert_book_translation, delete_book_translation, get_similar_books,
+    get_trending_books
 };
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
@@ -9,7 +15,23 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
         web::scope("/books")
             .route("", web::post().to(create_book))
             .route("", web::get().to(get_books))
+            .route("/onix", web::post().to(ingest_onix_feed))
+            .route("/trending", web::get().to(get_trending_books))
+            .route("/{book_id}", web::get().to(get_book))
+            .route("/{book_id}", web::delete().to(delete_book))
             .route("/{book_id}/upload-image", web::post().to(upload_book_image))
             .route("/{book_id}/rate", web::post().to(rate_book))
+            .route("/{book_id}/rate", web::put().to(update_rating))
+            .route("/{book_id}/rate", web::delete().to(delete_rating))
+            .route("/{book_id}/reviews", web::get().to(get_book_reviews))
+            .route("/{book_id}/similar", web::get().to(get_similar_books))
+            .route("/{book_id}/revisions", web::get().to(get_book_revisions))
+            .route("/{book_id}/revisions/{revision_number}/revert", web::post().to(revert_book))
+            .route("/{book_id}/translations/{locale}", web::put().to(upsert_book_translation))
+            .route("/{book_id}/translations/{locale}", web::delete().to(delete_book_translation))
+            .route("/{book_id}/contributors", web::post().to(add_book_contributor))
+            .route("/{book_id}/tags", web::get().to(get_book_tags))
+            .route("/{book_id}/tags", web::post().to(suggest_tag))
+            .route("/{book_id}/tags/{tag_id}/vote", web::post().to(vote_tag))
     );
 }
\ No newline at end of file
@@ -0,0 +1,22 @@
+use actix_web::web;
+
+use crate::presentation::controllers::challenge_controller::{
+    get_challenges, get_challenge, join_challenge, leave_challenge, get_challenge_leaderboard,
+    create_challenge, delete_challenge
+};
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/challenges")
+            .route("", web::get().to(get_challenges))
+            .route("/{challenge_id}", web::get().to(get_challenge))
+            .route("/{challenge_id}/join", web::post().to(join_challenge))
+            .route("/{challenge_id}/join", web::delete().to(leave_challenge))
+            .route("/{challenge_id}/leaderboard", web::get().to(get_challenge_leaderboard))
+    )
+    .service(
+        web::scope("/admin/challenges")
+            .route("", web::post().to(create_challenge))
+            .route("/{challenge_id}", web::delete().to(delete_challenge))
+    );
+}
@@ -0,0 +1,15 @@
+use actix_web::web;
+
+use crate::presentation::controllers::duplicate_controller::{
+    scan_duplicates, list_duplicates, merge_duplicate, dismiss_duplicate
+};
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/admin/duplicates")
+            .route("", web::get().to(list_duplicates))
+            .route("/scan", web::post().to(scan_duplicates))
+            .route("/{candidate_id}/merge", web::post().to(merge_duplicate))
+            .route("/{candidate_id}/dismiss", web::post().to(dismiss_duplicate))
+    );
+}
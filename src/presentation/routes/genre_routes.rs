@@ -0,0 +1,18 @@
+use actix_web::web;
+
+use crate::presentation::controllers::genre_controller::{
+    get_genre_tree, create_genre, update_genre, delete_genre
+};
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/genres")
+            .route("", web::get().to(get_genre_tree))
+    )
+    .service(
+        web::scope("/admin/genres")
+            .route("", web::post().to(create_genre))
+            .route("/{genre_id}", web::put().to(update_genre))
+            .route("/{genre_id}", web::delete().to(delete_genre))
+    );
+}
@@ -0,0 +1,31 @@
+use actix_web::web;
+
+use crate::presentation::controllers::review_controller::get_my_ratings;
+use crate::presentation::controllers::notification_controller::{get_my_notifications, mark_notification_read};
+use crate::presentation::controllers::shelf_controller::{
+    get_my_shelves, create_shelf, delete_shelf, get_shelved_books, shelve_book, unshelve_book,
+    get_shelf_history, export_shelves
+};
+use crate::presentation::controllers::goal_controller::{get_my_goals, upsert_goal, delete_goal};
+use crate::presentation::controllers::stats_controller::get_my_stats;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/me")
+            .route("/ratings", web::get().to(get_my_ratings))
+            .route("/notifications", web::get().to(get_my_notifications))
+            .route("/notifications/{notification_id}/read", web::post().to(mark_notification_read))
+            .route("/shelves", web::get().to(get_my_shelves))
+            .route("/shelves", web::post().to(create_shelf))
+            .route("/shelves/export", web::get().to(export_shelves))
+            .route("/shelves/books", web::get().to(get_shelved_books))
+            .route("/shelves/books/{book_id}", web::put().to(shelve_book))
+            .route("/shelves/books/{book_id}", web::delete().to(unshelve_book))
+            .route("/shelves/books/{book_id}/history", web::get().to(get_shelf_history))
+            .route("/shelves/{shelf_id}", web::delete().to(delete_shelf))
+            .route("/goals", web::get().to(get_my_goals))
+            .route("/goals", web::put().to(upsert_goal))
+            .route("/goals/{goal_id}", web::delete().to(delete_goal))
+            .route("/stats", web::get().to(get_my_stats))
+    );
+}
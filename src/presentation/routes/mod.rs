@@ -3,15 +3,45 @@
 pub mod auth_routes;
 pub mod book_routes;
 pub mod recommendation_routes;
+pub mod duplicate_routes;
+pub mod work_routes;
+pub mod author_routes;
+pub mod genre_routes;
+pub mod tag_routes;
+pub mod admin_routes;
+pub mod me_routes;
+pub mod review_routes;
+pub mod reading_session_routes;
+pub mod challenge_routes;
 
 pub use auth_routes::*;
 pub use book_routes::*;
 pub use recommendation_routes::*;
+pub use duplicate_routes::*;
+pub use work_routes::*;
+pub use author_routes::*;
+pub use genre_routes::*;
+pub use tag_routes::*;
+pub use admin_routes::*;
+pub use me_routes::*;
+pub use review_routes::*;
+pub use reading_session_routes::*;
+pub use challenge_routes::*;
 
 use actix_web::web;
 
 pub fn configure_routes(cfg: &mut web::ServiceConfig) {
     cfg.configure(auth_routes::configure)
        .configure(book_routes::configure)
-       .configure(recommendation_routes::configure);
+       .configure(recommendation_routes::configure)
+       .configure(duplicate_routes::configure)
+       .configure(work_routes::configure)
+       .configure(author_routes::configure)
+       .configure(genre_routes::configure)
+       .configure(tag_routes::configure)
+       .configure(admin_routes::configure)
+       .configure(me_routes::configure)
+       .configure(review_routes::configure)
+       .configure(reading_session_routes::configure)
+       .configure(challenge_routes::configure);
 }
\ No newline at end of file
@@ -0,0 +1,14 @@
+use actix_web::web;
+
+use crate::presentation::controllers::reading_session_controller::{
+    start_reading_session, end_reading_session, get_reading_sessions
+};
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/reading-sessions")
+            .route("", web::post().to(start_reading_session))
+            .route("", web::get().to(get_reading_sessions))
+            .route("/{session_id}/end", web::post().to(end_reading_session))
+    );
+}
@@ -0,0 +1,23 @@
+use actix_web::web;
+
+use crate::presentation::controllers::review_controller::{
+    vote_review, report_review, list_reported_reviews, dismiss_review_reports, remove_review,
+    list_pending_reviews, approve_review, reject_review
+};
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/reviews")
+            .route("/{rating_id}/vote", web::post().to(vote_review))
+            .route("/{rating_id}/report", web::post().to(report_review))
+    )
+    .service(
+        web::scope("/admin/reviews")
+            .route("/pending", web::get().to(list_pending_reviews))
+            .route("/reported", web::get().to(list_reported_reviews))
+            .route("/{rating_id}/approve", web::post().to(approve_review))
+            .route("/{rating_id}/reject", web::post().to(reject_review))
+            .route("/{rating_id}/dismiss", web::post().to(dismiss_review_reports))
+            .route("/{rating_id}/remove", web::post().to(remove_review))
+    );
+}
@@ -0,0 +1,17 @@
+use actix_web::web;
+
+use crate::presentation::controllers::tag_controller::{
+    list_pending_tags, approve_tag, reject_tag, list_blocked_tags, block_tag, unblock_tag
+};
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/admin/tags")
+            .route("/pending", web::get().to(list_pending_tags))
+            .route("/blocklist", web::get().to(list_blocked_tags))
+            .route("/blocklist", web::post().to(block_tag))
+            .route("/blocklist/{tag}", web::delete().to(unblock_tag))
+            .route("/{tag_id}/approve", web::post().to(approve_tag))
+            .route("/{tag_id}/reject", web::post().to(reject_tag))
+    );
+}
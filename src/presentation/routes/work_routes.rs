@@ -0,0 +1,20 @@
+use actix_web::web;
+
+use crate::presentation::controllers::work_controller::{
+    create_work, get_work, get_next_in_series, add_edition, create_series, get_series
+};
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/works")
+            .route("", web::post().to(create_work))
+            .route("/{work_id}", web::get().to(get_work))
+            .route("/{work_id}/next", web::get().to(get_next_in_series))
+            .route("/{work_id}/editions", web::post().to(add_edition))
+    )
+    .service(
+        web::scope("/series")
+            .route("", web::post().to(create_series))
+            .route("/{series_id}", web::get().to(get_series))
+    );
+}
@@ -35,6 +35,75 @@ async fn setup_test_data(db: &Surreal<surrealdb::engine::local::Db>) {
     db.query(include_str!("../../migrations/001_initial_schema.surql"))
         .await
         .unwrap();
+    db.query(include_str!("../../migrations/002_books_isbn_index.surql"))
+        .await
+        .unwrap();
+    db.query(include_str!("../../migrations/003_user_roles_and_duplicates.surql"))
+        .await
+        .unwrap();
+    db.query(include_str!("../../migrations/004_works_and_series.surql"))
+        .await
+        .unwrap();
+    db.query(include_str!("../../migrations/005_authors_and_publishers.surql"))
+        .await
+        .unwrap();
+    db.query(include_str!("../../migrations/006_genre_taxonomy.surql"))
+        .await
+        .unwrap();
+    db.query(include_str!("../../migrations/007_book_tags.surql"))
+        .await
+        .unwrap();
+    db.query(include_str!("../../migrations/008_book_revisions.surql"))
+        .await
+        .unwrap();
+    db.query(include_str!("../../migrations/009_soft_delete.surql"))
+        .await
+        .unwrap();
+    db.query(include_str!("../../migrations/010_localized_metadata.surql"))
+        .await
+        .unwrap();
+    db.query(include_str!("../../migrations/011_cover_images.surql"))
+        .await
+        .unwrap();
+    db.query(include_str!("../../migrations/012_cover_image_keys.surql"))
+        .await
+        .unwrap();
+    db.query(include_str!("../../migrations/013_cover_placeholders.surql"))
+        .await
+        .unwrap();
+    db.query(include_str!("../../migrations/014_cover_hashes.surql"))
+        .await
+        .unwrap();
+    db.query(include_str!("../../migrations/015_rating_edits.surql"))
+        .await
+        .unwrap();
+    db.query(include_str!("../../migrations/016_review_votes_and_reports.surql"))
+        .await
+        .unwrap();
+    db.query(include_str!("../../migrations/017_review_moderation.surql"))
+        .await
+        .unwrap();
+    db.query(include_str!("../../migrations/018_atomic_rating_stats.surql"))
+        .await
+        .unwrap();
+    db.query(include_str!("../../migrations/019_rating_histogram.surql"))
+        .await
+        .unwrap();
+    db.query(include_str!("../../migrations/020_trending.surql"))
+        .await
+        .unwrap();
+    db.query(include_str!("../../migrations/021_reading_progress.surql"))
+        .await
+        .unwrap();
+    db.query(include_str!("../../migrations/022_shelves.surql"))
+        .await
+        .unwrap();
+    db.query(include_str!("../../migrations/023_goals_and_challenges.surql"))
+        .await
+        .unwrap();
+    db.query(include_str!("../../migrations/024_catalog_tags.surql"))
+        .await
+        .unwrap();
 }
 
 async fn create_test_container(db: Surreal<surrealdb::engine::local::Db>) -> AppModule {
@@ -62,6 +131,7 @@ pub fn create_test_user() -> crate::application::dtos::auth_dtos::RegisterUserRe
 pub fn create_test_book() -> crate::application::dtos::book_dtos::CreateBookRequest {
     crate::application::dtos::book_dtos::CreateBookRequest {
         title: "Test Book".to_string(),
+        subtitle: None,
         author: "Test Author".to_string(),
         isbn: Some("9781234567890".to_string()),
         description: "This is a test book for unit testing purposes.".to_string(),